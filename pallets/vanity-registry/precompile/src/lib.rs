@@ -0,0 +1,158 @@
+//! A Frontier precompile through which EVM contracts and accounts use the vanity registry, much
+//! like an ENS registry contract. It implements this Solidity interface:
+//!
+//! ```solidity
+//! interface VanityRegistry {
+//!     /// The account "name" resolves to, or zero if it is not registered or suspended.
+//!     function resolve(bytes calldata name) external view returns (bytes32);
+//!     /// The owner of "name", or zero if it is not registered.
+//!     function owner(bytes calldata name) external view returns (bytes32);
+//!     /// Commit to a name, see `Pallet::commit`.
+//!     function commit(bytes32 hash) external;
+//!     /// Reveal a committed name, see `Pallet::reveal`.
+//!     function reveal(bytes calldata name) external;
+//! }
+//! ```
+//!
+//! Accounts are returned as the 32 bytes of their encoding. `commit` and `reveal` act for the
+//! account the caller's address maps to through `pallet_evm::Config::AddressMapping`, so the
+//! committed hash is `Pallet::hash_of` that account and the name.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use fp_evm::{Context, ExitError, ExitSucceed, Precompile, PrecompileOutput};
+use frame_support::{
+	dispatch::{GetDispatchInfo, UnfilteredDispatchable},
+	traits::Get,
+	weights::Weight,
+};
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use sp_core::H256;
+use sp_std::{borrow::Cow, marker::PhantomData, vec::Vec};
+use vanity_registry::{Call as RegistryCall, Config, Pallet, Resolution};
+
+/// The precompile to add to the precompile set of a runtime running both pallets.
+pub struct VanityRegistryPrecompile<T>(PhantomData<T>);
+
+impl<T> Precompile for VanityRegistryPrecompile<T>
+where
+	T: Config + pallet_evm::Config,
+{
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &Context,
+	) -> Result<PrecompileOutput, ExitError> {
+		if input.len() < 4 {
+			return Err(error("input too short"))
+		}
+		let (selector, args) = input.split_at(4);
+
+		if selector == &signature(b"resolve(bytes)")[..] {
+			// The normalized name's `Owners` and `SquattingReports` entries.
+			let cost = gas::<T>(T::DbWeight::get().reads(2), target_gas)?;
+			let account = match Pallet::<T>::resolve(&read_name::<T>(args)?) {
+				Some(Resolution::Owner(account)) => Some(account),
+				_ => None,
+			};
+			Ok(succeed(cost, to_word(account).as_bytes().to_vec()))
+		} else if selector == &signature(b"owner(bytes)")[..] {
+			// The normalized name's `Owners` entry.
+			let cost = gas::<T>(T::DbWeight::get().reads(1), target_gas)?;
+			let account = Pallet::<T>::owner_of(&read_name::<T>(args)?);
+			Ok(succeed(cost, to_word(account).as_bytes().to_vec()))
+		} else if selector == &signature(b"commit(bytes32)")[..] {
+			let hash = T::Hash::decode(&mut read_word(args, 0)?.as_bytes())
+				.map_err(|_| error("invalid hash"))?;
+			dispatch::<T>(RegistryCall::<T>::commit { hash }, target_gas, context)
+		} else if selector == &signature(b"reveal(bytes)")[..] {
+			let name = read_name::<T>(args)?;
+			dispatch::<T>(RegistryCall::<T>::reveal { name }, target_gas, context)
+		} else {
+			Err(error("unknown selector"))
+		}
+	}
+}
+
+/// Dispatch "call" for the account mapped from the caller, charging its weight as gas.
+fn dispatch<T>(
+	call: RegistryCall<T>,
+	target_gas: Option<u64>,
+	context: &Context,
+) -> Result<PrecompileOutput, ExitError>
+where
+	T: Config + pallet_evm::Config,
+{
+	let info = call.get_dispatch_info();
+	gas::<T>(info.weight, target_gas)?;
+	let origin = T::AddressMapping::into_account_id(context.caller);
+	let post_info = call
+		.dispatch_bypass_filter(frame_system::RawOrigin::Signed(origin).into())
+		.map_err(|e| error(e.error.into()))?;
+	let cost = T::GasWeightMapping::weight_to_gas(post_info.actual_weight.unwrap_or(info.weight));
+	Ok(succeed(cost, Vec::new()))
+}
+
+/// The gas "weight" costs, unless it exceeds "target_gas".
+fn gas<T: pallet_evm::Config>(weight: Weight, target_gas: Option<u64>) -> Result<u64, ExitError> {
+	let cost = T::GasWeightMapping::weight_to_gas(weight);
+	match target_gas {
+		Some(limit) if cost > limit => Err(ExitError::OutOfGas),
+		_ => Ok(cost),
+	}
+}
+
+/// The Solidity selector of the function "signature".
+fn signature(signature: &[u8]) -> [u8; 4] {
+	let hash = sp_io::hashing::keccak_256(signature);
+	[hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// The 32 byte word at word "index" of the arguments.
+fn read_word(args: &[u8], index: usize) -> Result<H256, ExitError> {
+	args.get(index * 32..(index + 1) * 32)
+		.map(H256::from_slice)
+		.ok_or_else(|| error("input too short"))
+}
+
+/// The ABI encoded `bytes` argument at the front of the arguments, decoded as a name.
+fn read_name<T: Config>(args: &[u8]) -> Result<T::Name, ExitError> {
+	let offset = to_usize(read_word(args, 0)?)?;
+	let len = to_usize(read_word(&args[offset.min(args.len())..], 0)?)?;
+	let start = offset.checked_add(32).ok_or_else(|| error("invalid offset"))?;
+	let bytes = start
+		.checked_add(len)
+		.and_then(|end| args.get(start..end))
+		.ok_or_else(|| error("input too short"))?;
+	// Names are encoded like `Vec<u8>`.
+	T::Name::decode(&mut &bytes.to_vec().encode()[..]).map_err(|_| error("invalid name"))
+}
+
+/// A word holding an offset or length, which must fit into a `u32`.
+fn to_usize(word: H256) -> Result<usize, ExitError> {
+	let bytes = word.as_bytes();
+	if bytes[..28].iter().any(|byte| *byte != 0) {
+		return Err(error("value out of bounds"))
+	}
+	Ok(u32::from_be_bytes([bytes[28], bytes[29], bytes[30], bytes[31]]) as usize)
+}
+
+/// The first 32 bytes of the encoding of "account", or zero for None.
+fn to_word<AccountId: Encode>(account: Option<AccountId>) -> H256 {
+	let mut word = H256::zero();
+	if let Some(account) = account {
+		let encoded = account.encode();
+		let len = encoded.len().min(32);
+		word.as_bytes_mut()[..len].copy_from_slice(&encoded[..len]);
+	}
+	word
+}
+
+fn succeed(cost: u64, output: Vec<u8>) -> PrecompileOutput {
+	PrecompileOutput { exit_status: ExitSucceed::Returned, cost, output, logs: Vec::new() }
+}
+
+fn error(message: &'static str) -> ExitError {
+	ExitError::Other(Cow::Borrowed(message))
+}
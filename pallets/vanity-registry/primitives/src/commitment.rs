@@ -0,0 +1,56 @@
+//! The format of the commitments made to names before revealing them. A commitment is the hash
+//! of its preimage under the hashing algorithm of the runtime, whose tag is part of the preimage.
+
+use codec::Encode;
+use sp_std::vec::Vec;
+
+/// The tag every commitment preimage starts with, so a commitment can never be mistaken for a
+/// hash produced by another pallet. It is followed by the tag of the hashing algorithm, see
+/// `commit_domain`.
+pub const COMMIT_DOMAIN: &[u8] = b"vanity-registry/commit";
+
+/// The version of the commitment format, following the commit domain in the preimage.
+pub const COMMIT_VERSION: u8 = 1;
+
+/// The version of the salted commitment format, whose preimage ends with the salt, see
+/// `salted_commit_preimage`.
+pub const SALTED_COMMIT_VERSION: u8 = 2;
+
+/// The domain every commitment preimage starts with, i.e. `COMMIT_DOMAIN`, a slash and the tag
+/// of the hashing "algorithm", e.g. `vanity-registry/commit/blake2-256`.
+pub fn commit_domain(algorithm: &[u8]) -> Vec<u8> {
+	let mut domain = COMMIT_DOMAIN.to_vec();
+	domain.push(b'/');
+	domain.extend_from_slice(algorithm);
+	domain
+}
+
+/// The preimage of the commitment of "id" to "name" hashed with "algorithm", i.e. the commit
+/// domain, `COMMIT_VERSION`, "id" and "name" concatenated.
+pub fn commit_preimage<AccountId: Encode, Name: Encode>(
+	algorithm: &[u8],
+	id: &AccountId,
+	name: &Name,
+) -> Vec<u8> {
+	let mut preimage = commit_domain(algorithm);
+	preimage.push(COMMIT_VERSION);
+	preimage.extend_from_slice(&id.encode());
+	preimage.extend_from_slice(&name.encode());
+	preimage
+}
+
+/// The preimage of the salted commitment of "id" to "name" hashed with "algorithm", i.e. the
+/// commit domain, `SALTED_COMMIT_VERSION`, "id", "name" and "salt" concatenated.
+pub fn salted_commit_preimage<AccountId: Encode, Name: Encode, Salt: Encode>(
+	algorithm: &[u8],
+	id: &AccountId,
+	name: &Name,
+	salt: &Salt,
+) -> Vec<u8> {
+	let mut preimage = commit_domain(algorithm);
+	preimage.push(SALTED_COMMIT_VERSION);
+	preimage.extend_from_slice(&id.encode());
+	preimage.extend_from_slice(&name.encode());
+	preimage.extend_from_slice(&salt.encode());
+	preimage
+}
@@ -0,0 +1,14 @@
+//! The types and encodings shared by the vanity registry pallet and the tools built around it,
+//! e.g. front ends compiled to wasm or off-chain services. They depend on neither the pallet nor
+//! a runtime, so they make the same commitments and decode the same query results and events as
+//! the pallet does.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use commitment::*;
+pub use types::*;
+pub use vanity_name::{lowercase, InvalidName, VanityName};
+
+mod commitment;
+mod types;
+pub mod vanity_name;
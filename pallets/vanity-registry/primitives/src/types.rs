@@ -0,0 +1,258 @@
+//! The types the vanity registry pallet returns from its queries and runtime API, and carries in
+//! its events.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_arithmetic::traits::Saturating;
+use sp_std::vec::Vec;
+
+/// The period during which a fund for a commit will be locked
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub struct LockPeriod<BlockNumber> {
+	/// The block at which the commit was made.
+	pub begin: BlockNumber,
+	/// The block at which the commit expires.
+	pub end: BlockNumber,
+}
+
+impl<BlockNumber: Copy + Saturating> LockPeriod<BlockNumber> {
+	/// The number of blocks left from "now" until the period ends, zero once it has.
+	pub fn blocks_left(&self, now: BlockNumber) -> BlockNumber {
+		self.end.saturating_sub(now)
+	}
+}
+
+/// How often a name has changed hands and been renewed since it was registered, see
+/// `Pallet::name_history`.
+#[derive(Decode, Encode, Clone, Copy, Default, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct NameHistory {
+	/// The number of times the name has passed to another account, not counting the moves of
+	/// `Pallet::migrate_account`.
+	pub transfers: u32,
+	/// The number of times the name has been renewed, by any of its owners.
+	pub renewals: u32,
+}
+
+/// Why a name was freed, see `Event::NameFreed`.
+#[derive(Decode, Encode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum FreeReason {
+	/// Its register period ran out, along with any grace period.
+	#[codec(index = 0)]
+	Expired,
+	/// Released by its owner, see `Pallet::unregister`.
+	#[codec(index = 1)]
+	Unregistered,
+	/// Revoked by governance after being granted permanently, see `Pallet::revoke_permanent`.
+	#[codec(index = 2)]
+	Revoked,
+	/// Taken down by governance, see `Pallet::force_unregister`.
+	#[codec(index = 3)]
+	TakenDown,
+	/// Taken from its owner upon an upheld squatting report, see `Pallet::resolve_report`.
+	#[codec(index = 4)]
+	Squatting,
+}
+
+/// How the owner of a name came by it.
+#[derive(Decode, Encode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum Acquisition {
+	/// Revealed from a commit.
+	#[codec(index = 0)]
+	Reveal,
+	/// Registered without a commit, see `Pallet::register`.
+	#[codec(index = 1)]
+	Register,
+	/// Transferred from its previous owner.
+	#[codec(index = 2)]
+	Transfer,
+	/// Won at an auction.
+	#[codec(index = 3)]
+	Auction,
+	/// Granted by governance, e.g. when a takedown is reversed on appeal.
+	#[codec(index = 4)]
+	Governance,
+	/// Moved to a new account of its owner by their guardians, see `Pallet::vote_recovery`.
+	#[codec(index = 5)]
+	Recovery,
+	/// Claimed by the heir of its inactive owner, see `Pallet::set_heir`.
+	#[codec(index = 6)]
+	Inheritance,
+	/// Registered fee-free with a voucher, see `Pallet::redeem_voucher`.
+	#[codec(index = 7)]
+	Voucher,
+	/// Revealed by another account as a gift, see `Pallet::reveal_gift`.
+	#[codec(index = 8)]
+	Gift,
+}
+
+impl Default for Acquisition {
+	fn default() -> Self {
+		Acquisition::Reveal
+	}
+}
+
+/// The stage of its life cycle a registered name is in, which decides the calls it is open to.
+#[derive(Decode, Encode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum NameStatus {
+	/// Registered and open to its owner.
+	#[codec(index = 0)]
+	Active,
+	/// Past its register period for `Config::GracePeriod`, during which its owner may only renew
+	/// or unregister it before it is freed.
+	#[codec(index = 1)]
+	GracePeriod,
+	/// Reported as squatting and closed to its owner until the report is resolved.
+	#[codec(index = 2)]
+	Disputed,
+	/// Frozen by governance, closed to its owner and kept from expiring until unfrozen.
+	#[codec(index = 3)]
+	Frozen,
+	/// About to be transferred, see `Pallet::schedule_transfer`. Its owner may still manage it
+	/// but not hand it over otherwise.
+	#[codec(index = 4)]
+	PendingTransfer,
+}
+
+/// The maximum number of items returned in a single page of an enumeration.
+pub const MAX_PAGE_SIZE: u32 = 1_000;
+
+/// A bounded page of an enumeration, along with the cursor to continue from if there is more.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+pub struct Page<Item> {
+	/// The items of this page.
+	pub items: Vec<Item>,
+	/// The cursor to pass in to get the next page, None if this page is the last one.
+	pub next: Option<Vec<u8>>,
+}
+
+/// What registering a name costs in the chain's currency, see `Pallet::quote`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+pub struct Quote<Balance> {
+	/// Held from the committer until the commit is revealed or expires.
+	pub commit_deposit: Balance,
+	/// Held from the owner for as long as the name is registered.
+	pub ownership_deposit: Balance,
+	/// Held from the owner along with the ownership deposit for each byte of the name.
+	pub name_byte_deposit: Balance,
+	/// Charged when a name is registered.
+	pub registration_fee: Balance,
+	/// Charged when a name is renewed.
+	pub renewal_fee: Balance,
+}
+
+/// Everything about a registered name at a glance, see `Pallet::registration_details`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+pub struct RegistrationDetails<AccountId, Name, BlockNumber, Balance> {
+	/// The account owning the name.
+	pub owner: AccountId,
+	/// The name in the casing it was revealed in.
+	pub display_name: Name,
+	/// The period from the commit of the name until it expires.
+	pub lock_period: LockPeriod<BlockNumber>,
+	/// The ownership deposit held for the name.
+	pub deposit: Balance,
+	/// How the owner came by the name.
+	pub acquisition: Acquisition,
+	/// The block at which the owner came by the name.
+	pub acquired_at: BlockNumber,
+	/// The number of times the owner has renewed the name.
+	pub renewals: u32,
+	/// The account the ownership deposit is held from, the owner unless it is delegated.
+	pub deposit_holder: AccountId,
+	/// The contributions left in the renewal pool of the name.
+	pub renewal_pool: Balance,
+	/// Whether the name is owned jointly, see `Pallet::set_joint_owners`.
+	pub jointly_owned: bool,
+	/// Whether the ownership of the name is split into shares, see `Pallet::fractionalize`.
+	pub fractionalized: bool,
+	/// Whether the name is under dispute, see `Resolution::Suspended`.
+	pub disputed: bool,
+}
+
+/// The details of a registered name along with its status, records and pending transfer, so
+/// clients can show a name in one query, see `Pallet::registration`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+pub struct RegistrationInfo<AccountId, Name, BlockNumber, Balance> {
+	/// The owner, deposit, lock period and acquisition of the name.
+	pub details: RegistrationDetails<AccountId, Name, BlockNumber, Balance>,
+	/// The stage of its life cycle the name is in.
+	pub status: NameStatus,
+	/// The block at which the name is freed unless renewed, past any grace period, or None if it
+	/// is permanent or frozen.
+	pub expires_at: Option<BlockNumber>,
+	/// The number of roles set under the name, see `Pallet::set_role`.
+	pub records: u32,
+	/// The transfer of the name waiting to be handed over, see `Pallet::schedule_transfer`.
+	pub pending_transfer: Option<ScheduledTransfer<AccountId, BlockNumber>>,
+	/// How often the name has changed hands and been renewed.
+	pub history: NameHistory,
+}
+
+/// What a registered name resolves to, see `Pallet::resolve`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+pub enum Resolution<AccountId> {
+	/// The name resolves to its owner.
+	#[codec(index = 0)]
+	Owner(AccountId),
+	/// The name is under dispute and must not be resolved until the dispute is settled.
+	#[codec(index = 1)]
+	Suspended,
+}
+
+/// The records of a registered name laid out like those of an ENS resolver, so ENS tooling and
+/// marketplaces can display the name with little adaptation, see `Pallet::ens_metadata`. The
+/// registry keeps no text records or content hash, only the accounts filling the roles of a name.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+pub struct EnsMetadata<AccountId, Name, BlockNumber> {
+	/// The name in the casing it was revealed in.
+	pub name: Name,
+	/// The owner of the name, as the ENS registrant.
+	pub owner: AccountId,
+	/// The block at which the name is freed unless renewed, or None if it never is.
+	pub expiry: Option<BlockNumber>,
+	/// The account the name resolves to, as its ENS `addr`, or None while it is under dispute
+	/// or past its lock period.
+	pub addr: Option<AccountId>,
+	/// The accounts filling the roles of the name keyed by role, as its ENS addresses.
+	pub addresses: Vec<(Vec<u8>, AccountId)>,
+}
+
+/// An action on a jointly owned name which needs the approval of its owners.
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum JointAction<AccountId, Role, BlockNumber> {
+	/// Unregister the name.
+	#[codec(index = 0)]
+	Unregister,
+	/// End the joint ownership, leaving the name to its registered owner alone.
+	#[codec(index = 1)]
+	Dissolve,
+	/// Transfer the name to an account at a given block, see `Pallet::schedule_transfer`.
+	#[codec(index = 2)]
+	ScheduleTransfer { to: AccountId, at: BlockNumber },
+	/// Have an account fill a role under the name, see `Pallet::set_role`.
+	#[codec(index = 3)]
+	SetRole { role: Role, account: AccountId },
+	/// Remove a role from the name, see `Pallet::clear_role`.
+	#[codec(index = 4)]
+	ClearRole { role: Role },
+}
+
+/// A transfer of a name held in escrow until a given block, see `Pallet::schedule_transfer`.
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ScheduledTransfer<AccountId, BlockNumber> {
+	/// The account the name is handed over to.
+	pub to: AccountId,
+	/// The block at which the name is handed over.
+	pub at: BlockNumber,
+}
+
+/// An action the owner of a name may schedule for a later block, see `Pallet::schedule_action`.
+#[derive(Decode, Encode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum ScheduledAction {
+	/// Renew the name, charging its owner the renewal fee at the time.
+	#[codec(index = 0)]
+	Renew,
+	/// Unregister the name.
+	#[codec(index = 1)]
+	Unregister,
+}
@@ -0,0 +1,108 @@
+//! `VanityName`, a validated name runtimes may use as `Config::Name` instead of raw bytes.
+
+use codec::{Decode, Encode, Error as CodecError, Input, MaxEncodedLen};
+use frame_support::{traits::Get, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound};
+use scale_info::TypeInfo;
+use sp_std::{convert::TryFrom, fmt, vec::Vec};
+
+/// Why some bytes are not a valid `VanityName`.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum InvalidName {
+	/// A name must not be empty.
+	Empty,
+	/// The name is longer than the bound of the name type.
+	TooLong,
+	/// The name is not valid UTF-8.
+	NotUtf8,
+	/// The name contains whitespace or a control character.
+	ForbiddenChar,
+}
+
+/// A non-empty UTF-8 name of at most `MaxLen` bytes without whitespace or control characters.
+///
+/// It keeps the casing it was created in and is encoded exactly like a `Vec<u8>`, so the pallet
+/// can tell the normalized form of a name apart from the casing it was revealed in. Decoding
+/// fails for invalid names, so a call can never carry one.
+#[derive(Encode, CloneNoBound, PartialEqNoBound, EqNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxLen))]
+pub struct VanityName<MaxLen: Get<u32>>(BoundedVec<u8, MaxLen>);
+
+impl<MaxLen: Get<u32>> VanityName<MaxLen> {
+	/// The name as text.
+	pub fn as_str(&self) -> &str {
+		// Only valid UTF-8 is ever stored.
+		sp_std::str::from_utf8(&self.0).unwrap_or_default()
+	}
+
+	/// The name as bytes.
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.0
+	}
+
+	/// The lowercase form of the name, which the registry registers it under. A name whose
+	/// lowercase form no longer fits `MaxLen` is returned unchanged.
+	pub fn normalized(&self) -> Self {
+		Self::try_from(lowercase(&self.0)).unwrap_or_else(|_| self.clone())
+	}
+
+	/// Whether both names have the same normalized form.
+	pub fn eq_ignore_case(&self, other: &Self) -> bool {
+		lowercase(&self.0) == lowercase(&other.0)
+	}
+
+	/// Check the rules every name must follow.
+	fn validate(bytes: &[u8]) -> Result<(), InvalidName> {
+		let text = sp_std::str::from_utf8(bytes).map_err(|_| InvalidName::NotUtf8)?;
+		if text.is_empty() {
+			return Err(InvalidName::Empty)
+		}
+		if text.chars().any(|c| c.is_whitespace() || c.is_control()) {
+			return Err(InvalidName::ForbiddenChar)
+		}
+		Ok(())
+	}
+}
+
+impl<MaxLen: Get<u32>> TryFrom<Vec<u8>> for VanityName<MaxLen> {
+	type Error = InvalidName;
+
+	fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+		Self::validate(&bytes)?;
+		BoundedVec::try_from(bytes).map(Self).map_err(|_| InvalidName::TooLong)
+	}
+}
+
+impl<MaxLen: Get<u32>> Decode for VanityName<MaxLen> {
+	fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+		let bytes = BoundedVec::<u8, MaxLen>::decode(input)?;
+		Self::validate(&bytes).map_err(|_| "Invalid vanity name")?;
+		Ok(Self(bytes))
+	}
+}
+
+impl<MaxLen: Get<u32>> fmt::Debug for VanityName<MaxLen> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{:?}", self.as_str())
+	}
+}
+
+impl<MaxLen: Get<u32>> From<VanityName<MaxLen>> for Vec<u8> {
+	fn from(name: VanityName<MaxLen>) -> Vec<u8> {
+		name.0.into_inner()
+	}
+}
+
+/// The lowercase form of "bytes", see `Pallet::normalize`.
+pub fn lowercase(bytes: &[u8]) -> Vec<u8> {
+	match sp_std::str::from_utf8(bytes) {
+		Ok(text) => {
+			let mut buf = [0u8; 4];
+			let mut lowercase = Vec::with_capacity(bytes.len());
+			for c in text.chars().flat_map(char::to_lowercase) {
+				lowercase.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+			}
+			lowercase
+		},
+		Err(_) => bytes.to_ascii_lowercase(),
+	}
+}
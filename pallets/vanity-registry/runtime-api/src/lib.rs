@@ -0,0 +1,85 @@
+//! Runtime API definition for the vanity registry pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use vanity_registry::{
+	EnsMetadata, LockPeriod, NameHistory, NameStatus, Page, Quote, RegistrationDetails,
+	RegistrationInfo, Resolution, ScheduledTransfer,
+};
+
+sp_api::decl_runtime_apis! {
+	pub trait VanityRegistryApi<AccountId, Name, Hash, BlockNumber, Balance>
+	where
+		AccountId: Codec,
+		Name: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// A page of at most "limit" registered names with their owners, starting after the
+		/// cursor returned by the previous page (or from the beginning when None).
+		fn list_names(start_key: Option<Vec<u8>>, limit: u32) -> Page<(Name, AccountId)>;
+
+		/// A page of at most "limit" names owned by "account", starting after the cursor returned
+		/// by the previous page (or from the beginning when None).
+		fn list_names_of(account: AccountId, start: Option<Vec<u8>>, limit: u32) -> Page<Name>;
+
+		/// The names owned by "account", up to a single page of them.
+		fn names_of(account: AccountId) -> Vec<Name>;
+
+		/// A page of at most "limit" pending commits with their accounts and lock periods,
+		/// starting after the cursor returned by the previous page (or from the beginning when
+		/// None).
+		fn list_commits(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<(AccountId, Hash, LockPeriod<BlockNumber>)>;
+
+		/// The owner of "name", if registered.
+		fn owner_of(name: Name) -> Option<AccountId>;
+
+		/// The owner of the registered name whose canonical hash is "hash".
+		fn owner_of_hash(hash: Hash) -> Option<AccountId>;
+
+		/// What "name" in any casing resolves to, `Suspended` while it is under dispute. Use this
+		/// rather than `owner_of` before e.g. sending a payment to a name.
+		fn resolve(name: Name) -> Option<Resolution<AccountId>>;
+
+		/// The owner, deposit, lock period and status of "name" in any casing, if registered.
+		fn registration_details(
+			name: Name,
+		) -> Option<RegistrationDetails<AccountId, Name, BlockNumber, Balance>>;
+
+		/// The details of "name" in any casing along with its status, number of records, expiry
+		/// and pending transfer, if registered.
+		fn registration(
+			name: Name,
+		) -> Option<RegistrationInfo<AccountId, Name, BlockNumber, Balance>>;
+
+		/// The records of "name" in any casing laid out like those of an ENS resolver, so ENS
+		/// tooling can display the name, if registered.
+		fn ens_metadata(name: Name) -> Option<EnsMetadata<AccountId, Name, BlockNumber>>;
+
+		/// Whether "name" in any casing is free to be registered.
+		fn is_available(name: Name) -> bool;
+
+		/// The current deposits and fees of registering and renewing a name, or None if no price
+		/// is known to convert the fees at.
+		fn quote() -> Option<Quote<Balance>>;
+
+		/// The fee of registering "name" for "periods" register periods at once, with every
+		/// discount the chain would apply, or None if it cannot be registered for as long.
+		fn quote_register(name: Name, periods: u32) -> Option<Balance>;
+
+		/// The fee of renewing "name" "periods" times for its current owner, with every discount
+		/// the chain would apply, or None if it is not registered.
+		fn quote_renew(name: Name, periods: u32) -> Option<Balance>;
+
+		/// At most "limit" registered names starting with "prefix" in any casing, for
+		/// autocompletion. Empty unless the runtime indexes name prefixes.
+		fn names_with_prefix(prefix: Vec<u8>, limit: u32) -> Vec<Name>;
+	}
+}
@@ -18,7 +18,10 @@
 
 use super::*;
 use frame_benchmarking::{account, benchmarks, vec, whitelisted_caller};
+use frame_support::{traits::EnsureOrigin, BoundedVec};
 use frame_system::RawOrigin as SystemOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::convert::TryFrom;
 
 use crate::Pallet as VanityRegistry;
 use frame_system::Pallet as System;
@@ -30,6 +33,77 @@ fn create_name<T: Config>(len: u32) -> T::Name {
 	Decode::decode(&mut encoded.as_slice()).unwrap()
 }
 
+/// A name of four lowercase letters, distinct for each "index" below 26^4.
+fn create_indexed_name<T: Config>(index: u32) -> T::Name {
+	let raw: Vec<u8> = (0..4).map(|i| b'a' + (index / 26u32.pow(i) % 26) as u8).collect();
+	let encoded = raw.encode();
+	Decode::decode(&mut encoded.as_slice()).unwrap()
+}
+
+/// A role of "MaxRoleLen" bytes, distinct for each "index".
+fn create_role<T: Config>(index: u32) -> RoleOf<T> {
+	let mut raw = vec![b'r'; T::MaxRoleLen::get() as usize];
+	let digits = index.to_le_bytes();
+	let at = raw.len().saturating_sub(digits.len());
+	raw[at..].copy_from_slice(&digits[..raw.len() - at]);
+	BoundedVec::try_from(raw).expect("the role is exactly MaxRoleLen bytes; qed")
+}
+
+fn fund_account<T: Config>(who: &T::AccountId) {
+	FallbackPrice::<T>::put(BalanceOf::<T>::from(1u32));
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+fn register<T: Config>(who: &T::AccountId, name: T::Name) {
+	fund_account::<T>(who);
+	let c = VanityRegistry::<T>::hash_of(who.clone(), name.clone());
+	let _ = VanityRegistry::<T>::commit(SystemOrigin::Signed(who.clone()).into(), c);
+	let _ = VanityRegistry::<T>::reveal(SystemOrigin::Signed(who.clone()).into(), name);
+}
+
+/// Have "MaxGuardians" guardians recover "name" of "owner" to "new_owner", all but one of them
+/// voting for it, and return the guardian left to vote.
+fn vote_recovery_but_one<T: Config>(
+	owner: &T::AccountId,
+	name: T::Name,
+	new_owner: &T::AccountId,
+) -> T::AccountId {
+	let count = T::MaxGuardians::get();
+	let guardians: Vec<T::AccountId> = (0..count).map(|i| account("guardian", i, 0)).collect();
+	let _ = VanityRegistry::<T>::set_guardians(
+		SystemOrigin::Signed(owner.clone()).into(),
+		name.clone(),
+		GuardiansOf::<T>::try_from(guardians.clone()).unwrap(),
+		count,
+	);
+	for guardian in &guardians[1..] {
+		let _ = VanityRegistry::<T>::vote_recovery(
+			SystemOrigin::Signed(guardian.clone()).into(),
+			name.clone(),
+			new_owner.clone(),
+		);
+	}
+	guardians[0].clone()
+}
+
+/// Fractionalize "name" of "owner", who keeps "kept" shares, and hand one share to each of
+/// "holders" other accounts.
+fn fractionalize<T: Config>(owner: &T::AccountId, name: T::Name, kept: u32, holders: u32) {
+	let _ = VanityRegistry::<T>::fractionalize(
+		SystemOrigin::Signed(owner.clone()).into(),
+		name.clone(),
+		kept + holders,
+	);
+	for i in 0..holders {
+		let _ = VanityRegistry::<T>::transfer_shares(
+			SystemOrigin::Signed(owner.clone()).into(),
+			name.clone(),
+			account("holder", i, 0),
+			1,
+		);
+	}
+}
+
 benchmarks! {
 
 	commit {
@@ -48,11 +122,13 @@ benchmarks! {
 	}
 
 	reveal {
-		let l in 0..T::NameMaxLen::get();
+		let l in T::ShortNameLen::get() .. T::NameMaxLen::get();
 		let alice_id: T::AccountId = whitelisted_caller();
 		let bob_id: T::AccountId = account("bob", 0, 0);
 		let alice_name = create_name::<T>(l as u32);
 		let alice_commit = VanityRegistry::<T>::hash_of(alice_id.clone(), alice_name.clone());
+		fund_account::<T>(&alice_id);
+		fund_account::<T>(&bob_id);
 
 		System::<T>::set_block_number((1u32).into());
 		let _ = VanityRegistry::<T>::commit(
@@ -71,16 +147,28 @@ benchmarks! {
 		let _ = VanityRegistry::<T>::reveal(SystemOrigin::Signed(bob_id).into(), alice_name.clone());
 	}: reveal(SystemOrigin::Signed(alice_id.clone()), alice_name.clone())
 	verify {
+		let alice_name = VanityRegistry::<T>::normalize(&alice_name);
 		let owner = VanityRegistry::<T>::owners(alice_name).unwrap();
 		assert_eq!(owner.commit, alice_commit);
 		assert_eq!(owner.id, alice_id);
 	}
 
+	register {
+		let l in (T::InstantRegistrationLen::get() + 1) .. T::NameMaxLen::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(l);
+		fund_account::<T>(&caller);
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(caller));
+	}
+
 	renew {
 		let id: T::AccountId = whitelisted_caller();
 		const ABCDE: [u8; 5] = [04, 66, 67, 68, 69];
 		let name: T::Name = Decode::decode(&mut &ABCDE[..]).unwrap();
 		let c = VanityRegistry::<T>::hash_of(id.clone(), name.clone());
+		fund_account::<T>(&id);
 
 		System::<T>::set_block_number((7u32).into());
 		let _ = VanityRegistry::<T>::commit(SystemOrigin::Signed(id.clone()).into(), c.clone());
@@ -91,8 +179,8 @@ benchmarks! {
 		System::<T>::set_block_number((9u32).into());
 	}: renew(SystemOrigin::Signed(id.clone()), name.clone())
 	verify {
-		let lock_period = VanityRegistry::<T>::owners(name).unwrap().lock_period;
-		assert_eq!(lock_period.end, T::BlockNumber::from(9u32) + T::RegisterPeriod::get());
+		let owner = VanityRegistry::<T>::owners(VanityRegistry::<T>::normalize(&name)).unwrap();
+		assert_eq!(owner.lock_period.end, T::BlockNumber::from(9u32) + T::RegisterPeriod::get());
 	}
 
 	unregister {
@@ -100,6 +188,7 @@ benchmarks! {
 		const ABCDE: [u8; 5] = [04, 66, 67, 68, 69];
 		let name: T::Name = Decode::decode(&mut &ABCDE[..]).unwrap();
 		let c = VanityRegistry::<T>::hash_of(id.clone(), name.clone());
+		fund_account::<T>(&id);
 
 		System::<T>::set_block_number((7u32).into());
 		let _ = VanityRegistry::<T>::commit(SystemOrigin::Signed(id.clone()).into(), c.clone());
@@ -107,13 +196,881 @@ benchmarks! {
 		System::<T>::set_block_number((8u32).into());
 		let _ = VanityRegistry::<T>::reveal(SystemOrigin::Signed(id.clone()).into(), name.clone());
 
-		assert!(Owners::<T>::contains_key(name.clone()));
+		assert!(VanityRegistry::<T>::owner_of(&name).is_some());
+		let _ = VanityRegistry::<T>::set_deposit_beneficiary(
+			SystemOrigin::Signed(id.clone()).into(),
+			name.clone(),
+			account("registrar", 0, 0),
+		);
 
 		System::<T>::set_block_number((9u32).into());
 	}: unregister(SystemOrigin::Signed(id.clone()), name.clone())
 	verify {
 		assert!(!LockPeriods::<T>::contains_key(id, c));
-		assert!(!Owners::<T>::contains_key(name));
+		assert!(VanityRegistry::<T>::owner_of(&name).is_none());
+	}
+
+	force_unregister {
+		let id: T::AccountId = account("mallory", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		let _ = VanityRegistry::<T>::set_deposit_beneficiary(
+			SystemOrigin::Signed(id).into(),
+			name.clone(),
+			account("registrar", 0, 0),
+		);
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, name.clone())
+	verify {
+		assert!(VanityRegistry::<T>::owner_of(&name).is_none());
+	}
+
+	set_deposit_beneficiary {
+		let id: T::AccountId = whitelisted_caller();
+		let registrar: T::AccountId = account("registrar", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+	}: _(SystemOrigin::Signed(id), name.clone(), registrar.clone())
+	verify {
+		assert_eq!(DepositBeneficiaries::<T>::get(name), Some(registrar));
+	}
+
+	offer_deposit {
+		let depositor: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&account("alice", 0, 0), name.clone());
+	}: _(SystemOrigin::Signed(depositor.clone()), name.clone())
+	verify {
+		assert_eq!(DepositOffers::<T>::get(name), Some(depositor));
+	}
+
+	accept_deposit_offer {
+		let id: T::AccountId = whitelisted_caller();
+		let depositor: T::AccountId = account("depositor", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		fund_account::<T>(&depositor);
+		let _ = VanityRegistry::<T>::offer_deposit(
+			SystemOrigin::Signed(depositor.clone()).into(),
+			name.clone(),
+		);
+	}: _(SystemOrigin::Signed(id), name.clone())
+	verify {
+		assert_eq!(DepositDelegations::<T>::get(name), Some(depositor));
+	}
+
+	end_deposit_delegation {
+		let id: T::AccountId = whitelisted_caller();
+		let depositor: T::AccountId = account("depositor", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		fund_account::<T>(&depositor);
+		let _ = VanityRegistry::<T>::offer_deposit(
+			SystemOrigin::Signed(depositor.clone()).into(),
+			name.clone(),
+		);
+		let _ = VanityRegistry::<T>::accept_deposit_offer(
+			SystemOrigin::Signed(id).into(),
+			name.clone(),
+		);
+	}: _(SystemOrigin::Signed(depositor), name.clone())
+	verify {
+		assert!(!DepositDelegations::<T>::contains_key(VanityRegistry::<T>::normalize(&name)));
+	}
+
+	attest_ownership {
+		let c in 0 .. T::MaxChallengeLen::get();
+		let id: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		let challenge = BoundedVec::try_from(vec![7u8; c as usize]).unwrap();
+	}: _(SystemOrigin::Signed(id), name, challenge)
+
+	set_joint_owners {
+		let m in 1 .. T::MaxJointOwners::get();
+		let id: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		let members: Vec<T::AccountId> = (0..m).map(|i| account("member", i, 0)).collect();
+		let members = BoundedVec::try_from(members).unwrap();
+	}: _(SystemOrigin::Signed(id), name.clone(), members, m)
+	verify {
+		assert!(JointOwners::<T>::contains_key(VanityRegistry::<T>::normalize(&name)));
+	}
+
+	fractionalize {
+		let id: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+	}: _(SystemOrigin::Signed(id), name.clone(), 100)
+	verify {
+		assert!(NameShares::<T>::contains_key(VanityRegistry::<T>::normalize(&name)));
+	}
+
+	transfer_shares {
+		let id: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		fractionalize::<T>(&id, name.clone(), 2, T::MaxShareholders::get() - 2);
+	}: _(SystemOrigin::Signed(id), name.clone(), account("dest", 0, 0), 1)
+	verify {
+		let holdings = VanityRegistry::<T>::name_shares(VanityRegistry::<T>::normalize(&name));
+		assert_eq!(holdings.unwrap().len() as u32, T::MaxShareholders::get());
+	}
+
+	distribute_revenue {
+		let h in 1 .. T::MaxShareholders::get();
+		let id: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		fractionalize::<T>(&id, name.clone(), 1, h - 1);
+		let payer: T::AccountId = account("payer", 0, 0);
+		fund_account::<T>(&payer);
+		let amount = T::Currency::minimum_balance() * 1_000u32.into();
+	}: _(SystemOrigin::Signed(payer), name, amount)
+
+	vote_renewal {
+		let h in 1 .. T::MaxShareholders::get();
+		let id: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		fractionalize::<T>(&id, name.clone(), h, h - 1);
+		for i in 0..h - 1 {
+			let _ = VanityRegistry::<T>::vote_renewal(
+				SystemOrigin::Signed(account("holder", i, 0)).into(),
+				name.clone(),
+			);
+		}
+		System::<T>::set_block_number((9u32).into());
+	}: _(SystemOrigin::Signed(id), name.clone())
+	verify {
+		let owner = VanityRegistry::<T>::owners(VanityRegistry::<T>::normalize(&name)).unwrap();
+		assert_eq!(owner.lock_period.end, T::BlockNumber::from(9u32) + T::RegisterPeriod::get());
+	}
+
+	approve_action {
+		let id: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		let _ = VanityRegistry::<T>::set_deposit_beneficiary(
+			SystemOrigin::Signed(id.clone()).into(),
+			name.clone(),
+			account("registrar", 0, 0),
+		);
+		let m = T::MaxJointOwners::get();
+		let members: Vec<T::AccountId> = (0..m).map(|i| account("member", i, 0)).collect();
+		let _ = VanityRegistry::<T>::set_joint_owners(
+			SystemOrigin::Signed(id).into(),
+			name.clone(),
+			BoundedVec::try_from(members.clone()).unwrap(),
+			m,
+		);
+		for member in members.iter().skip(1) {
+			let _ = VanityRegistry::<T>::approve_action(
+				SystemOrigin::Signed(member.clone()).into(),
+				name.clone(),
+				JointAction::Unregister,
+			);
+		}
+	}: _(SystemOrigin::Signed(members[0].clone()), name.clone(), JointAction::Unregister)
+	verify {
+		assert!(VanityRegistry::<T>::owner_of(&name).is_none());
+	}
+
+	reconcile_lock {
+		let caller: T::AccountId = whitelisted_caller();
+		let id: T::AccountId = account("alice", 0, 0);
+		register::<T>(&id, create_name::<T>(T::NameMaxLen::get()));
+		let c = VanityRegistry::<T>::hash_of(id.clone(), create_name::<T>(1));
+		let _ = VanityRegistry::<T>::commit(SystemOrigin::Signed(id.clone()).into(), c.clone());
+		CommitDeposits::<T>::insert(&id, c.clone(), T::FundToLock::get() / 2u32.into());
+	}: _(SystemOrigin::Signed(caller), id.clone())
+	verify {
+		assert!(CommitDeposits::<T>::contains_key(id, c));
+	}
+
+	appeal_takedown {
+		let id: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		let _ = VanityRegistry::<T>::force_unregister(
+			T::AdminOrigin::successful_origin(),
+			name.clone(),
+		);
+	}: _(SystemOrigin::Signed(id), name.clone())
+	verify {
+		let takedown = VanityRegistry::<T>::takedowns(VanityRegistry::<T>::normalize(&name));
+		assert!(takedown.unwrap().appeal_bond.is_some());
+	}
+
+	resolve_appeal {
+		let id: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&id, name.clone());
+		let _ = VanityRegistry::<T>::force_unregister(
+			T::AdminOrigin::successful_origin(),
+			name.clone(),
+		);
+		let _ = VanityRegistry::<T>::appeal_takedown(
+			SystemOrigin::Signed(id.clone()).into(),
+			name.clone(),
+		);
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, name.clone(), true)
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(id));
+	}
+
+	process_expiries {
+		// Handing names over on their scheduled transfer is the worst case.
+		let e in 1 .. T::MaxExpiriesPerBlock::get();
+		let at = System::<T>::block_number() + One::one();
+		for i in 0..e {
+			let owner: T::AccountId = account("owner", i, 0);
+			let name = create_indexed_name::<T>(i);
+			register::<T>(&owner, name.clone());
+			RevealCount::<T>::kill();
+			let to: T::AccountId = account("recipient", i, 0);
+			fund_account::<T>(&to);
+			let _ = VanityRegistry::<T>::schedule_transfer(
+				SystemOrigin::Signed(owner).into(),
+				name,
+				to,
+				at,
+			);
+		}
+		ExpiryCursor::<T>::put(at);
+	}: { VanityRegistry::<T>::process_expiries(at) }
+	verify {
+		assert_eq!(ScheduledTransfers::<T>::iter().count(), 0);
+		assert_eq!(ExpiryQueue::<T>::iter_prefix(at).count(), 0);
+	}
+
+	set_fallback_price {
+		let origin = T::AdminOrigin::successful_origin();
+		let price = Some(BalanceOf::<T>::from(7u32));
+	}: _<T::Origin>(origin, price)
+	verify {
+		assert_eq!(FallbackPrice::<T>::get(), price);
+	}
+
+	set_renewal_fee_ceiling {
+		let origin = T::AdminOrigin::successful_origin();
+		let ceiling = Some(BalanceOf::<T>::from(7u32));
+	}: _<T::Origin>(origin, ceiling)
+	verify {
+		assert_eq!(RenewalFeeCeiling::<T>::get(), ceiling);
+	}
+
+	set_name_denied {
+		let origin = T::AdminOrigin::successful_origin();
+		let hash = VanityRegistry::<T>::name_hash(&create_name::<T>(T::NameMaxLen::get()));
+	}: _<T::Origin>(origin, hash, true)
+	verify {
+		assert!(DeniedNames::<T>::contains_key(hash));
+	}
+
+	report_squatting {
+		let reporter: T::AccountId = whitelisted_caller();
+		let original = create_name::<T>(T::NameMaxLen::get());
+		let suspect = create_name::<T>(T::NameMaxLen::get() - 1);
+		register::<T>(&account("alice", 0, 0), original.clone());
+		register::<T>(&account("mallory", 0, 0), suspect.clone());
+		fund_account::<T>(&reporter);
+	}: _(SystemOrigin::Signed(reporter), suspect.clone(), original)
+	verify {
+		assert!(SquattingReports::<T>::contains_key(VanityRegistry::<T>::normalize(&suspect)));
+	}
+
+	resolve_report {
+		let reporter: T::AccountId = whitelisted_caller();
+		let original = create_name::<T>(T::NameMaxLen::get());
+		let suspect = create_name::<T>(T::NameMaxLen::get() - 1);
+		register::<T>(&account("alice", 0, 0), original.clone());
+		register::<T>(&account("mallory", 0, 0), suspect.clone());
+		fund_account::<T>(&reporter);
+		let _ = VanityRegistry::<T>::report_squatting(
+			SystemOrigin::Signed(reporter).into(),
+			suspect.clone(),
+			original
+		);
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, suspect.clone(), true)
+	verify {
+		assert!(VanityRegistry::<T>::owner_of(&suspect).is_none());
+	}
+
+	schedule_action {
+		// Replacing an action scheduled before is the worst case.
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		let when = System::<T>::block_number() + 10u32.into();
+		let _ = VanityRegistry::<T>::schedule_action(
+			SystemOrigin::Signed(caller.clone()).into(),
+			name.clone(),
+			ScheduledAction::Renew,
+			when,
+		);
+	}: _(SystemOrigin::Signed(caller), name.clone(), ScheduledAction::Renew, when + One::one())
+	verify {
+		assert_eq!(
+			VanityRegistry::<T>::scheduled_actions(&name, ScheduledAction::Renew),
+			Some(when + One::one())
+		);
+	}
+
+	cancel_scheduled_action {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		let when = System::<T>::block_number() + 10u32.into();
+		let _ = VanityRegistry::<T>::schedule_action(
+			SystemOrigin::Signed(caller.clone()).into(),
+			name.clone(),
+			ScheduledAction::Unregister,
+			when,
+		);
+	}: _(SystemOrigin::Signed(caller), name.clone(), ScheduledAction::Unregister)
+	verify {
+		let scheduled = VanityRegistry::<T>::scheduled_actions(&name, ScheduledAction::Unregister);
+		assert!(scheduled.is_none());
+	}
+
+	contribute_to_renewal {
+		// Joining a pool with one spot left is the worst case.
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&account("alice", 0, 0), name.clone());
+		for i in 1..T::MaxPoolContributors::get() {
+			let contributor: T::AccountId = account("contributor", i, 0);
+			fund_account::<T>(&contributor);
+			let _ = VanityRegistry::<T>::contribute_to_renewal(
+				SystemOrigin::Signed(contributor).into(),
+				name.clone(),
+				T::OwnershipDeposit::get(),
+			);
+		}
+		fund_account::<T>(&caller);
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone(), T::OwnershipDeposit::get())
+	verify {
+		let pool = VanityRegistry::<T>::renewal_pools(&name).unwrap_or_default();
+		assert!(pool.iter().any(|(contributor, _)| *contributor == caller));
+	}
+
+	set_role {
+		// Adding a role to a name with one spot left is the worst case.
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		for i in 1..T::MaxRoles::get() {
+			let _ = VanityRegistry::<T>::set_role(
+				SystemOrigin::Signed(caller.clone()).into(),
+				name.clone(),
+				create_role::<T>(i),
+				account("filler", i, 0),
+			);
+		}
+		let filler: T::AccountId = account("filler", 0, 0);
+	}: _(SystemOrigin::Signed(caller), name.clone(), create_role::<T>(0), filler.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::role_of(&name, &create_role::<T>(0)), Some(filler));
+	}
+
+	clear_role {
+		// Clearing the last of the roles of a full name is the worst case.
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		for i in 0..T::MaxRoles::get() {
+			let _ = VanityRegistry::<T>::set_role(
+				SystemOrigin::Signed(caller.clone()).into(),
+				name.clone(),
+				create_role::<T>(i),
+				account("filler", i, 0),
+			);
+		}
+		let role = create_role::<T>(T::MaxRoles::get() - 1);
+	}: _(SystemOrigin::Signed(caller), name.clone(), role.clone())
+	verify {
+		assert!(VanityRegistry::<T>::role_of(&name, &role).is_none());
+	}
+
+	set_guardians {
+		let g in 1 .. T::MaxGuardians::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		let guardians: Vec<T::AccountId> = (0..g).map(|i| account("guardian", i, 0)).collect();
+		let guardians = GuardiansOf::<T>::try_from(guardians).unwrap();
+	}: _(SystemOrigin::Signed(caller), name.clone(), guardians, g)
+	verify {
+		assert_eq!(VanityRegistry::<T>::guardians(&name).map(|g| g.threshold), Some(g));
+	}
+
+	vote_recovery {
+		// The vote approving the recovery is the worst case.
+		let owner: T::AccountId = account("alice", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&owner, name.clone());
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let caller = vote_recovery_but_one::<T>(&owner, name.clone(), &new_owner);
+	}: _(SystemOrigin::Signed(caller), name.clone(), new_owner)
+	verify {
+		let recovery = VanityRegistry::<T>::recoveries(&name).unwrap();
+		assert!(recovery.executable_at.is_some());
+	}
+
+	veto_recovery {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let guardian = vote_recovery_but_one::<T>(&caller, name.clone(), &new_owner);
+		let _ = VanityRegistry::<T>::vote_recovery(
+			SystemOrigin::Signed(guardian).into(),
+			name.clone(),
+			new_owner,
+		);
+	}: _(SystemOrigin::Signed(caller), name.clone())
+	verify {
+		assert!(VanityRegistry::<T>::recoveries(&name).is_none());
+	}
+
+	execute_recovery {
+		let owner: T::AccountId = account("alice", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&owner, name.clone());
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		fund_account::<T>(&new_owner);
+		let guardian = vote_recovery_but_one::<T>(&owner, name.clone(), &new_owner);
+		let _ = VanityRegistry::<T>::vote_recovery(
+			SystemOrigin::Signed(guardian).into(),
+			name.clone(),
+			new_owner.clone(),
+		);
+		let executable_at = System::<T>::block_number() + T::RecoveryDelay::get();
+		System::<T>::set_block_number(executable_at);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(SystemOrigin::Signed(caller), name.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(new_owner));
+	}
+
+	set_heir {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		let heir: T::AccountId = account("heir", 0, 0);
+		let inactivity_blocks = T::MinInactivityPeriod::get();
+	}: _(SystemOrigin::Signed(caller), name.clone(), heir.clone(), inactivity_blocks)
+	verify {
+		assert_eq!(VanityRegistry::<T>::heirs(&name).map(|h| h.account), Some(heir));
+	}
+
+	remove_heir {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		let _ = VanityRegistry::<T>::set_heir(
+			SystemOrigin::Signed(caller.clone()).into(),
+			name.clone(),
+			account("heir", 0, 0),
+			T::MinInactivityPeriod::get(),
+		);
+	}: _(SystemOrigin::Signed(caller), name.clone())
+	verify {
+		assert!(VanityRegistry::<T>::heirs(&name).is_none());
+	}
+
+	claim_inheritance {
+		let owner: T::AccountId = account("alice", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&owner, name.clone());
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let _ = VanityRegistry::<T>::set_heir(
+			SystemOrigin::Signed(owner).into(),
+			name.clone(),
+			caller.clone(),
+			T::MinInactivityPeriod::get(),
+		);
+		let claimable_at = System::<T>::block_number() + T::MinInactivityPeriod::get();
+		System::<T>::set_block_number(claimable_at);
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(caller));
+	}
+
+	schedule_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		let to: T::AccountId = account("recipient", 0, 0);
+		let at = System::<T>::block_number() + One::one();
+	}: _(SystemOrigin::Signed(caller), name.clone(), to, at)
+	verify {
+		assert!(VanityRegistry::<T>::scheduled_transfers(&name).is_some());
+	}
+
+	cancel_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		let _ = VanityRegistry::<T>::schedule_transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			name.clone(),
+			account("recipient", 0, 0),
+			System::<T>::block_number() + One::one(),
+		);
+	}: _(SystemOrigin::Signed(caller), name.clone())
+	verify {
+		assert!(VanityRegistry::<T>::scheduled_transfers(&name).is_none());
+	}
+
+	reveal_bundle {
+		let n in 1 .. T::MaxBundleLen::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let names: Vec<T::Name> = (0..n).map(create_indexed_name::<T>).collect();
+		for name in &names {
+			let _ = VanityRegistry::<T>::commit(
+				SystemOrigin::Signed(caller.clone()).into(),
+				VanityRegistry::<T>::hash_of(caller.clone(), name.clone()),
+			);
+		}
+		let names = BoundedVec::<_, T::MaxBundleLen>::try_from(names).unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), names)
+	verify {
+		assert_eq!(VanityRegistry::<T>::names_of(&caller).len() as u32, n);
+	}
+
+	set_lock_backing {
+		// Opting out of an account holding deposits is the worst case, as the locked balance may
+		// not cover them to opt in.
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, create_name::<T>(T::NameMaxLen::get()));
+		LockBackedAccounts::<T>::insert(&caller, ());
+	}: _(SystemOrigin::Signed(caller.clone()), false)
+	verify {
+		assert!(!VanityRegistry::<T>::is_lock_backed(&caller));
+	}
+
+	mint_voucher {
+		let origin = T::AdminOrigin::successful_origin();
+		let hash = T::Hashing::hash(b"voucher");
+		let name = create_name::<T>(T::NameMaxLen::get());
+	}: _<T::Origin>(origin, hash, Some(name))
+	verify {
+		assert!(Vouchers::<T>::contains_key(hash));
+	}
+
+	revoke_voucher {
+		let hash = T::Hashing::hash(b"voucher");
+		let name = create_name::<T>(T::NameMaxLen::get());
+		let _ = VanityRegistry::<T>::mint_voucher(
+			T::AdminOrigin::successful_origin(),
+			hash,
+			Some(name.clone()),
+		);
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, hash)
+	verify {
+		assert!(!VoucherReservations::<T>::contains_key(VanityRegistry::<T>::normalize(&name)));
+	}
+
+	redeem_voucher {
+		let l in 1 .. T::NameMaxLen::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(l);
+		fund_account::<T>(&caller);
+		let code =
+			VoucherCodeOf::<T>::try_from(vec![7u8; T::MaxVoucherCodeLen::get() as usize]).unwrap();
+		let _ = VanityRegistry::<T>::mint_voucher(
+			T::AdminOrigin::successful_origin(),
+			T::Hashing::hash(&code[..]),
+			Some(name.clone()),
+		);
+	}: _(SystemOrigin::Signed(caller.clone()), code, name.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(caller));
+	}
+
+	reveal_gift {
+		let l in 1 .. T::NameMaxLen::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(l);
+		fund_account::<T>(&caller);
+		let c = VanityRegistry::<T>::hash_of(caller.clone(), name.clone());
+		let _ = VanityRegistry::<T>::commit(SystemOrigin::Signed(caller.clone()).into(), c);
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone(), beneficiary.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(beneficiary));
+	}
+
+	reject_gift {
+		let giver: T::AccountId = account("giver", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		fund_account::<T>(&giver);
+		let c = VanityRegistry::<T>::hash_of(giver.clone(), name.clone());
+		let _ = VanityRegistry::<T>::commit(SystemOrigin::Signed(giver.clone()).into(), c);
+		let caller: T::AccountId = whitelisted_caller();
+		let _ = VanityRegistry::<T>::reveal_gift(
+			SystemOrigin::Signed(giver.clone()).into(),
+			name.clone(),
+			caller.clone(),
+		);
+	}: _(SystemOrigin::Signed(caller), name.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(giver));
+	}
+
+	buy_credits {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let amount = T::OwnershipDeposit::get();
+	}: _(SystemOrigin::Signed(caller.clone()), amount)
+	verify {
+		assert_eq!(VanityRegistry::<T>::renewal_credits(&caller), amount);
+	}
+
+	withdraw_credits {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let amount = T::OwnershipDeposit::get();
+		let _ =
+			VanityRegistry::<T>::buy_credits(SystemOrigin::Signed(caller.clone()).into(), amount);
+	}: _(SystemOrigin::Signed(caller.clone()), amount)
+	verify {
+		assert!(VanityRegistry::<T>::renewal_credits(&caller).is_zero());
+	}
+
+	reveal_for_periods {
+		let l in 1 .. T::NameMaxLen::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(l);
+		fund_account::<T>(&caller);
+		let c = VanityRegistry::<T>::hash_of(caller.clone(), name.clone());
+		let _ = VanityRegistry::<T>::commit(SystemOrigin::Signed(caller.clone()).into(), c);
+		let periods = T::MaxRegistrationPeriods::get();
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone(), periods)
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(caller));
+	}
+
+	watch {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let hash = VanityRegistry::<T>::name_hash(&create_name::<T>(T::NameMaxLen::get()));
+		for i in 1 .. T::MaxWatchers::get() {
+			let watcher: T::AccountId = account("watcher", i, 0);
+			fund_account::<T>(&watcher);
+			let _ = VanityRegistry::<T>::watch(SystemOrigin::Signed(watcher).into(), hash);
+		}
+	}: _(SystemOrigin::Signed(caller.clone()), hash)
+	verify {
+		assert!(VanityRegistry::<T>::watchers(hash).iter().any(|(watcher, _)| *watcher == caller));
+	}
+
+	unwatch {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let hash = VanityRegistry::<T>::name_hash(&create_name::<T>(T::NameMaxLen::get()));
+		let _ = VanityRegistry::<T>::watch(SystemOrigin::Signed(caller.clone()).into(), hash);
+	}: _(SystemOrigin::Signed(caller), hash)
+	verify {
+		assert!(VanityRegistry::<T>::watchers(hash).is_empty());
+	}
+
+	grant_permanent {
+		let origin = T::AdminOrigin::successful_origin();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+	}: _<T::Origin>(origin, name.clone(), owner.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(owner));
+	}
+
+	revoke_permanent {
+		let origin = T::AdminOrigin::successful_origin();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		let _ = VanityRegistry::<T>::grant_permanent(origin.clone(), name.clone(), owner);
+	}: _<T::Origin>(origin, name.clone())
+	verify {
+		assert!(VanityRegistry::<T>::owner_of(&name).is_none());
+	}
+
+	consent_to_migration {
+		let caller: T::AccountId = whitelisted_caller();
+		let from: T::AccountId = account("from", 0, 0);
+	}: _(SystemOrigin::Signed(caller.clone()), Some(from.clone()))
+	verify {
+		assert_eq!(VanityRegistry::<T>::migration_consents(&caller), Some(from));
+	}
+
+	migrate_account {
+		let n in 0 .. 100;
+		let caller: T::AccountId = whitelisted_caller();
+		let new_account: T::AccountId = account("new", 0, 0);
+		fund_account::<T>(&caller);
+		fund_account::<T>(&new_account);
+		for i in 0 .. n {
+			register::<T>(&caller, create_indexed_name::<T>(i));
+			RevealCount::<T>::kill();
+		}
+		let _ = VanityRegistry::<T>::consent_to_migration(
+			SystemOrigin::Signed(new_account.clone()).into(),
+			Some(caller.clone()),
+		);
+	}: _(SystemOrigin::Signed(caller.clone()), new_account.clone(), n)
+	verify {
+		assert_eq!(VanityRegistry::<T>::names_of(&new_account).len(), n as usize);
+	}
+
+	offer_payment {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&owner, name.clone());
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::payment_offers(&name), Some(caller));
+	}
+
+	accept_payment_offer {
+		let caller: T::AccountId = whitelisted_caller();
+		let payer: T::AccountId = account("payer", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+		let _ = VanityRegistry::<T>::offer_payment(
+			SystemOrigin::Signed(payer.clone()).into(),
+			name.clone(),
+		);
+	}: _(SystemOrigin::Signed(caller), name.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::payers(&name), Some(payer));
+	}
+
+	end_payment {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&owner, name.clone());
+		let _ = VanityRegistry::<T>::offer_payment(
+			SystemOrigin::Signed(caller.clone()).into(),
+			name.clone(),
+		);
+		let _ = VanityRegistry::<T>::accept_payment_offer(
+			SystemOrigin::Signed(owner).into(),
+			name.clone(),
+		);
+	}: _(SystemOrigin::Signed(caller), name.clone())
+	verify {
+		assert!(VanityRegistry::<T>::payers(&name).is_none());
+	}
+
+	rekey_owner {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_account: T::AccountId = account("new_account", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&caller, name.clone());
+	}: _(SystemOrigin::Signed(caller), name.clone(), new_account.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::pending_rekeys(&name), Some(new_account));
+	}
+
+	accept_rekey {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&owner, name.clone());
+		let _ = VanityRegistry::<T>::rekey_owner(
+			SystemOrigin::Signed(owner).into(),
+			name.clone(),
+			caller.clone(),
+		);
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(caller));
+	}
+
+	freeze_name {
+		let origin = T::AdminOrigin::successful_origin();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&owner, name.clone());
+		let to: T::AccountId = account("recipient", 0, 0);
+		let at = System::<T>::block_number() + One::one();
+		let _ = VanityRegistry::<T>::schedule_transfer(
+			SystemOrigin::Signed(owner).into(),
+			name.clone(),
+			to,
+			at,
+		);
+	}: _<T::Origin>(origin, name.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::name_status(&name), Some(NameStatus::Frozen));
+	}
+
+	unfreeze_name {
+		let origin = T::AdminOrigin::successful_origin();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let name = create_name::<T>(T::NameMaxLen::get());
+		register::<T>(&owner, name.clone());
+		let _ = VanityRegistry::<T>::freeze_name(origin.clone(), name.clone());
+	}: _<T::Origin>(origin, name.clone())
+	verify {
+		assert_eq!(VanityRegistry::<T>::name_status(&name), Some(NameStatus::Active));
+	}
+
+	reveal_salted {
+		let l in 1 .. T::NameMaxLen::get();
+		let s in 1 .. T::MaxCommitSalts::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(l);
+		fund_account::<T>(&caller);
+		let salts: Vec<T::Hash> = (0 .. s).map(|i| T::Hashing::hash_of(&i)).collect();
+		// Only the last salt was committed to, so every one of them is tried.
+		let salt = salts[s as usize - 1];
+		let c = VanityRegistry::<T>::salted_hash_of(caller.clone(), name.clone(), salt);
+		let _ = VanityRegistry::<T>::commit(SystemOrigin::Signed(caller.clone()).into(), c);
+		let salts = BoundedVec::<_, T::MaxCommitSalts>::try_from(salts).unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone(), salts)
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(caller));
+	}
+
+	reveal_batched {
+		let l in 1 .. T::NameMaxLen::get();
+		let p in 0 .. T::MaxProofLen::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(l);
+		fund_account::<T>(&caller);
+		let salt = T::Hashing::hash_of(&l);
+		let leaf = VanityRegistry::<T>::salted_hash_of(caller.clone(), name.clone(), salt);
+		let proof: Vec<T::Hash> = (0 .. p).map(|i| T::Hashing::hash_of(&i)).collect();
+		let root = VanityRegistry::<T>::merkle_root(leaf, &proof);
+		let _ = VanityRegistry::<T>::commit(SystemOrigin::Signed(caller.clone()).into(), root);
+		let proof = BoundedVec::<_, T::MaxProofLen>::try_from(proof).unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone(), salt, root, proof)
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(caller));
+	}
+
+	// The verifier is weighed by `Config::RevealVerifier::weight`, so it is given no proof here.
+	reveal_with_proof {
+		let l in 1 .. T::NameMaxLen::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let name = create_name::<T>(l);
+		fund_account::<T>(&caller);
+		let c = VanityRegistry::<T>::hash_of(caller.clone(), name.clone());
+		let _ = VanityRegistry::<T>::commit(SystemOrigin::Signed(caller.clone()).into(), c);
+	}: _(SystemOrigin::Signed(caller.clone()), name.clone(), Default::default())
+	verify {
+		assert_eq!(VanityRegistry::<T>::owner_of(&name), Some(caller));
 	}
 }
 
@@ -128,8 +1085,70 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			assert_ok!(test_benchmark_commit::<Test>());
 			assert_ok!(test_benchmark_reveal::<Test>());
+			assert_ok!(test_benchmark_register::<Test>());
 			assert_ok!(test_benchmark_renew::<Test>());
 			assert_ok!(test_benchmark_unregister::<Test>());
+			assert_ok!(test_benchmark_force_unregister::<Test>());
+			assert_ok!(test_benchmark_set_deposit_beneficiary::<Test>());
+			assert_ok!(test_benchmark_offer_deposit::<Test>());
+			assert_ok!(test_benchmark_accept_deposit_offer::<Test>());
+			assert_ok!(test_benchmark_end_deposit_delegation::<Test>());
+			assert_ok!(test_benchmark_attest_ownership::<Test>());
+			assert_ok!(test_benchmark_set_joint_owners::<Test>());
+			assert_ok!(test_benchmark_approve_action::<Test>());
+			assert_ok!(test_benchmark_fractionalize::<Test>());
+			assert_ok!(test_benchmark_transfer_shares::<Test>());
+			assert_ok!(test_benchmark_distribute_revenue::<Test>());
+			assert_ok!(test_benchmark_vote_renewal::<Test>());
+			assert_ok!(test_benchmark_reconcile_lock::<Test>());
+			assert_ok!(test_benchmark_appeal_takedown::<Test>());
+			assert_ok!(test_benchmark_resolve_appeal::<Test>());
+			assert_ok!(test_benchmark_process_expiries::<Test>());
+			assert_ok!(test_benchmark_set_fallback_price::<Test>());
+			assert_ok!(test_benchmark_set_name_denied::<Test>());
+			assert_ok!(test_benchmark_report_squatting::<Test>());
+			assert_ok!(test_benchmark_resolve_report::<Test>());
+			assert_ok!(test_benchmark_schedule_action::<Test>());
+			assert_ok!(test_benchmark_cancel_scheduled_action::<Test>());
+			assert_ok!(test_benchmark_contribute_to_renewal::<Test>());
+			assert_ok!(test_benchmark_set_role::<Test>());
+			assert_ok!(test_benchmark_clear_role::<Test>());
+			assert_ok!(test_benchmark_set_guardians::<Test>());
+			assert_ok!(test_benchmark_vote_recovery::<Test>());
+			assert_ok!(test_benchmark_veto_recovery::<Test>());
+			assert_ok!(test_benchmark_execute_recovery::<Test>());
+			assert_ok!(test_benchmark_set_heir::<Test>());
+			assert_ok!(test_benchmark_remove_heir::<Test>());
+			assert_ok!(test_benchmark_claim_inheritance::<Test>());
+			assert_ok!(test_benchmark_schedule_transfer::<Test>());
+			assert_ok!(test_benchmark_cancel_transfer::<Test>());
+			assert_ok!(test_benchmark_reveal_bundle::<Test>());
+			assert_ok!(test_benchmark_set_lock_backing::<Test>());
+			assert_ok!(test_benchmark_mint_voucher::<Test>());
+			assert_ok!(test_benchmark_revoke_voucher::<Test>());
+			assert_ok!(test_benchmark_redeem_voucher::<Test>());
+			assert_ok!(test_benchmark_reveal_gift::<Test>());
+			assert_ok!(test_benchmark_reject_gift::<Test>());
+			assert_ok!(test_benchmark_buy_credits::<Test>());
+			assert_ok!(test_benchmark_withdraw_credits::<Test>());
+			assert_ok!(test_benchmark_reveal_for_periods::<Test>());
+			assert_ok!(test_benchmark_watch::<Test>());
+			assert_ok!(test_benchmark_unwatch::<Test>());
+			assert_ok!(test_benchmark_grant_permanent::<Test>());
+			assert_ok!(test_benchmark_revoke_permanent::<Test>());
+			assert_ok!(test_benchmark_consent_to_migration::<Test>());
+			assert_ok!(test_benchmark_migrate_account::<Test>());
+			assert_ok!(test_benchmark_offer_payment::<Test>());
+			assert_ok!(test_benchmark_accept_payment_offer::<Test>());
+			assert_ok!(test_benchmark_end_payment::<Test>());
+			assert_ok!(test_benchmark_freeze_name::<Test>());
+			assert_ok!(test_benchmark_unfreeze_name::<Test>());
+			assert_ok!(test_benchmark_reveal_salted::<Test>());
+			assert_ok!(test_benchmark_reveal_batched::<Test>());
+			assert_ok!(test_benchmark_reveal_with_proof::<Test>());
+			assert_ok!(test_benchmark_set_renewal_fee_ceiling::<Test>());
+			assert_ok!(test_benchmark_rekey_owner::<Test>());
+			assert_ok!(test_benchmark_accept_rekey::<Test>());
 		});
 	}
 }
@@ -0,0 +1,1653 @@
+//! The implementation of the dispatchables. The dispatchables themselves are declared in the
+//! `pallet` module, as the pallet macro requires, and only check their origin before handing over
+//! to the `do_*` functions here.
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+	/// The call dispatched when "action" scheduled on "name" is due.
+	pub fn scheduled_call(name: &T::Name, action: ScheduledAction) -> T::RegistryCall {
+		let call = match action {
+			ScheduledAction::Renew => Call::<T>::renew { name: name.clone() },
+			ScheduledAction::Unregister => Call::<T>::unregister { name: name.clone() },
+		};
+		call.into()
+	}
+
+	/// The id "action" on "name" is scheduled under, unique across the pallets of the runtime.
+	pub(crate) fn schedule_id(name: &T::Name, action: ScheduledAction) -> Vec<u8> {
+		(T::ModuleId::get(), name, action).encode()
+	}
+
+	/// Cancel the actions scheduled on "name", e.g. since it changes hands.
+	pub(crate) fn cancel_scheduled_actions(name: &T::Name) {
+		for (action, _) in ScheduledActions::<T>::drain_prefix(name) {
+			let _ = T::Scheduler::cancel_named(Self::schedule_id(name, action));
+		}
+	}
+
+	/// Schedule the actions on "name" again, to be dispatched on behalf of "to" rather than its
+	/// previous owner, e.g. since the owner rekeyed it. Those which are due have been dispatched
+	/// already and are forgotten.
+	pub(crate) fn reschedule_actions(name: &T::Name, to: &T::AccountId) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let actions: Vec<_> = ScheduledActions::<T>::drain_prefix(name).collect();
+		for (action, when) in actions {
+			let id = Self::schedule_id(name, action);
+			let _ = T::Scheduler::cancel_named(id.clone());
+			if when <= now {
+				continue
+			}
+			let rescheduled = T::Scheduler::schedule_named(
+				id,
+				DispatchTime::At(when),
+				None,
+				LOWEST_PRIORITY,
+				frame_system::RawOrigin::Signed(to.clone()).into(),
+				Self::scheduled_call(name, action),
+			);
+			if rescheduled.is_ok() {
+				ScheduledActions::<T>::insert(name, action, when);
+			}
+		}
+	}
+
+	/// Extend the register period of "name" owned by "owner", charging "payer" the renewal fee
+	/// and sharing it with the depositor of the name if any. The ownership deposit is topped up
+	/// to the current `ownership_deposit_of` the name and held again, failing if its holder can
+	/// no longer cover it. A name in its grace period becomes active again.
+	pub(crate) fn do_renew(
+		name: &T::Name,
+		mut owner: OwnerOf<T>,
+		by: &T::AccountId,
+		payer: &T::AccountId,
+	) -> DispatchResult {
+		ensure!(!PermanentNames::<T>::contains_key(name), Error::<T>::PermanentName);
+		let now = <frame_system::Pallet<T>>::block_number();
+		// The name would have been freed already if its expiry had been processed.
+		let old_end = owner.lock_period.end;
+		ensure!(
+			Self::lapses_at(name, old_end).map_or(true, |at| now < at),
+			Error::<T>::RegistrationLapsed
+		);
+		let end = Self::period_end(now, T::RegisterPeriod::get())?;
+		let fee = Self::withdraw_renewal_fee(name, &owner, payer)?;
+		match Self::deposit_delegations(name) {
+			Some(depositor) => {
+				let share = T::DelegatorFeeShare::get() * fee.peek();
+				let (reward, rest) = fee.split(share);
+				T::Currency::resolve_creating(&depositor, reward);
+				T::FeeHandler::on_unbalanced(rest);
+				if !share.is_zero() {
+					Self::deposit_name_event(
+						name,
+						Event::DelegatorRewarded(name.clone(), depositor, share),
+					);
+				}
+			},
+			None => T::FeeHandler::on_unbalanced(fee),
+		}
+
+		// `insert_owner` accounts for the topped up deposit of the same owner.
+		let holder = Self::deposit_holder(name, &owner.id);
+		owner.deposit = owner.deposit.max(Self::ownership_deposit_of(name));
+		owner.lock_period.end = end;
+		owner.renewals = owner.renewals.saturating_add(1);
+		Self::insert_owner(name, owner);
+		ensure!(
+			Self::can_hold(&holder, Self::held_deposits(&holder)),
+			Error::<T>::InsufficientBalance
+		);
+		Self::update_locked_fund(&holder)?;
+		NameHistories::<T>::mutate(name, |history| {
+			history.renewals = history.renewals.saturating_add(1)
+		});
+		if Self::name_status(name) == Some(NameStatus::GracePeriod) {
+			Self::activate(name);
+		}
+
+		Self::deposit_name_event(name, Event::NameRenewed(name.clone(), by.clone(), old_end, end));
+
+		Ok(())
+	}
+
+	/// Check that "who" may claim "name" in this block, counting the claim towards
+	/// `MaxRevealsPerBlock`, and return the normalized name.
+	pub(crate) fn check_claim(
+		who: &T::AccountId,
+		name: &T::Name,
+	) -> Result<T::Name, DispatchError> {
+		let reveals = RevealCount::<T>::get();
+		ensure!(reveals < T::MaxRevealsPerBlock::get(), Error::<T>::TooManyReveals);
+		RevealCount::<T>::put(reveals + 1);
+
+		<Self as NameValidator<_, _>>::validate(who, name)
+	}
+
+	/// Claim "revealed" for "who" with their oldest commit to it, unsalted or under one of
+	/// "salts", for "periods" register periods, see `Pallet::reveal`. Returns whether the name
+	/// was granted, rather than held by an earlier commit which discredits the claim.
+	pub(crate) fn do_reveal(
+		who: &T::AccountId,
+		revealed: T::Name,
+		salts: &[T::Hash],
+		periods: u32,
+	) -> Result<bool, DispatchError> {
+		let commit = Self::oldest_commit(who, &revealed, salts);
+		let name = Self::check_claim(who, &revealed)?;
+
+		let commit = commit.ok_or(Error::<T>::CommitNotFound)?;
+		let lock_period = Self::take_commit(who, commit).ok_or(Error::<T>::CommitNotFound)?;
+		Self::claim(who, revealed, &name, commit, lock_period, periods)
+	}
+
+	/// Claim "revealed" for "who" with the leaf of their batch commit "root" under "salt", proven
+	/// by "proof", see `Pallet::reveal_batched`. The commit is kept for the other names in the
+	/// batch, but the leaf may only be revealed once. Returns whether the name was granted.
+	pub(crate) fn do_reveal_batched(
+		who: &T::AccountId,
+		revealed: T::Name,
+		salt: T::Hash,
+		root: T::Hash,
+		proof: &[T::Hash],
+	) -> Result<bool, DispatchError> {
+		let name = Self::check_claim(who, &revealed)?;
+
+		let lock_period = Self::lock_periods(who, root).ok_or(Error::<T>::CommitNotFound)?;
+		let leaf = Self::salted_hash_of(who.clone(), revealed.clone(), salt);
+		ensure!(Self::merkle_root(leaf, proof) == root, Error::<T>::InvalidProof);
+		let key = (who.clone(), root);
+		ensure!(!BatchReveals::<T>::contains_key(&key, leaf), Error::<T>::AlreadyRevealed);
+		BatchReveals::<T>::insert(&key, leaf, ());
+		Self::claim(who, revealed, &name, root, lock_period, 1)
+	}
+
+	/// Claim "revealed" for "who" with the commit that "proof" shows to be to it, see
+	/// `Pallet::reveal_with_proof`. Returns whether the name was granted.
+	pub(crate) fn do_reveal_with_proof(
+		who: &T::AccountId,
+		revealed: T::Name,
+		proof: &[u8],
+	) -> Result<bool, DispatchError> {
+		let hashed = Self::hash_of(who.clone(), revealed.clone());
+		let commit = T::RevealVerifier::commit_of(who, &revealed, hashed, proof);
+		let name = Self::check_claim(who, &revealed)?;
+
+		let commit = commit.ok_or(Error::<T>::InvalidProof)?;
+		let lock_period = Self::take_commit(who, commit).ok_or(Error::<T>::CommitNotFound)?;
+		Self::claim(who, revealed, &name, commit, lock_period, 1)
+	}
+
+	/// Grant "name", claimed as "revealed" by "who" with "commit" held for "lock_period", for
+	/// "periods" register periods unless an earlier commit holds it already, discrediting the
+	/// claim. A commit of the same age holding it is displaced only if the claim wins the tie,
+	/// so the order of the reveals does not decide. Returns whether the name was granted.
+	fn claim(
+		who: &T::AccountId,
+		revealed: T::Name,
+		name: &T::Name,
+		commit: T::Hash,
+		mut new_claim_lock_period: LockPeriod<T::BlockNumber>,
+		periods: u32,
+	) -> Result<bool, DispatchError> {
+		if let Some(current_owner) = Owners::<T>::get(name) {
+			let (begin, new_begin) = (current_owner.lock_period.begin, new_claim_lock_period.begin);
+			let tie_lost =
+				begin == new_begin && !Self::wins_tie(name, begin, commit, current_owner.commit);
+			if begin < new_begin || tie_lost || PermanentNames::<T>::contains_key(name) {
+				Self::update_locked_fund(who)?;
+				Self::deposit_name_event(name, Event::RevealDiscredited(name.clone(), who.clone()));
+				// The reveal originator has successfully discredited their own reveal!
+				return Ok(false)
+			};
+		}
+
+		let extension = T::RegisterPeriod::get()
+			.checked_mul(&periods.saturating_sub(1).into())
+			.ok_or(Error::<T>::ArithmeticOverflow)?;
+		new_claim_lock_period.end = Self::period_end(new_claim_lock_period.end, extension)?;
+		let owner = Owner {
+			id: who.clone(),
+			commit,
+			lock_period: new_claim_lock_period,
+			deposit: Self::ownership_deposit_of(name),
+			acquisition: Acquisition::Reveal,
+			acquired_at: <frame_system::Pallet<T>>::block_number(),
+			renewals: 0,
+		};
+		Self::grant(name, revealed, owner, periods)?;
+
+		Ok(true)
+	}
+
+	/// Charge the registration fee of "periods" register periods from the claimer of "name" and
+	/// make them its "owner", refunding the previous owner if any. "revealed" is the casing the
+	/// name was claimed in.
+	pub(crate) fn grant(
+		name: &T::Name,
+		revealed: T::Name,
+		owner: OwnerOf<T>,
+		periods: u32,
+	) -> DispatchResult {
+		Self::charge_fee(&owner.id, Self::registration_fee(periods)?)?;
+		Self::assign(name, revealed, owner)
+	}
+
+	/// Make "owner" the owner of "name" without charging any fee, see `Pallet::grant`.
+	pub(crate) fn assign(name: &T::Name, revealed: T::Name, owner: OwnerOf<T>) -> DispatchResult {
+		Self::check_priority(name, &owner.id, owner.lock_period.begin)?;
+		let who = owner.id.clone();
+		if let Some(previous) = Self::insert_owner(name, owner) {
+			Self::update_locked_fund(&previous.id)?;
+			Self::refund_deposit(name, &previous.id, previous.deposit);
+		}
+		Self::update_locked_fund(&who)?;
+		if revealed == *name {
+			NameCasings::<T>::remove(name);
+		} else {
+			NameCasings::<T>::insert(name, revealed);
+		}
+
+		Self::deposit_name_event(name, Event::NameOwned(name.clone(), who));
+
+		Ok(())
+	}
+
+	/// Draw the renewal fee of "name" held by "owner" from its renewal pool, then from the
+	/// renewal credits of "payer", charging "payer" whatever neither can cover.
+	pub(crate) fn withdraw_renewal_fee(
+		name: &T::Name,
+		owner: &OwnerOf<T>,
+		payer: &T::AccountId,
+	) -> Result<NegativeImbalanceOf<T>, DispatchError> {
+		let amount = Self::renewal_fee(owner)?;
+		let mut fee = NegativeImbalanceOf::<T>::zero();
+		let mut rest = amount;
+		if let Some(mut pool) = Self::renewal_pools(name) {
+			for (contributor, contribution) in pool.iter_mut() {
+				if rest.is_zero() {
+					break
+				}
+				let draw = rest.min(*contribution);
+				let (drawn, _) =
+					T::Currency::slash_reserved_named(&T::ModuleId::get(), contributor, draw);
+				*contribution = contribution.saturating_sub(draw);
+				rest = rest.saturating_sub(drawn.peek());
+				fee.subsume(drawn);
+			}
+			pool.retain(|(_, contribution)| !contribution.is_zero());
+			if pool.is_empty() {
+				RenewalPools::<T>::remove(name);
+			} else {
+				RenewalPools::<T>::insert(name, pool);
+			}
+			let drawn = amount.saturating_sub(rest);
+			if !drawn.is_zero() {
+				Self::deposit_name_event(name, Event::RenewalPoolDrawn(name.clone(), drawn));
+			}
+		}
+		let credits = Self::renewal_credits(payer);
+		if !rest.is_zero() && !credits.is_zero() {
+			let draw = rest.min(credits);
+			let (drawn, _) = T::Currency::slash_reserved_named(&T::ModuleId::get(), payer, draw);
+			let consumed = drawn.peek();
+			rest = rest.saturating_sub(consumed);
+			fee.subsume(drawn);
+			Self::set_renewal_credits(payer, credits.saturating_sub(draw));
+			Self::deposit_event(Event::CreditsConsumed(payer.clone(), consumed));
+		}
+		fee.subsume(Self::withdraw_amount(payer, rest)?);
+		Ok(fee)
+	}
+
+	/// Record "credits" as the renewal credits of "who", dropping the entry when zero.
+	pub(crate) fn set_renewal_credits(who: &T::AccountId, credits: BalanceOf<T>) {
+		if credits.is_zero() {
+			RenewalCredits::<T>::remove(who);
+		} else {
+			RenewalCredits::<T>::insert(who, credits);
+		}
+	}
+
+	/// Unreserve what is left of the renewal pool of "name" to its contributors.
+	pub(crate) fn refund_renewal_pool(name: &T::Name) {
+		if let Some(pool) = RenewalPools::<T>::take(name) {
+			for (contributor, contribution) in pool {
+				T::Currency::unreserve_named(&T::ModuleId::get(), &contributor, contribution);
+			}
+			Self::deposit_name_event(name, Event::RenewalPoolRefunded(name.clone()));
+		}
+	}
+
+	/// Release "name" from "owner" for "reason", refunding their deposit.
+	pub(crate) fn do_unregister(
+		name: &T::Name,
+		owner: OwnerOf<T>,
+		reason: FreeReason,
+	) -> DispatchResult {
+		Self::take_owner(name);
+
+		Self::update_locked_fund(&owner.id)?;
+		Self::refund_deposit(name, &owner.id, owner.deposit);
+
+		Self::deposit_freed_event(name, reason);
+
+		Ok(())
+	}
+
+	/// Forget the joint owners of "name" along with the approvals collected for its actions.
+	pub(crate) fn end_joint_ownership(name: &T::Name) {
+		JointOwners::<T>::remove(name);
+		let _ = PendingActions::<T>::remove_prefix(name, None);
+	}
+
+	/// The number of shares of a fractionalized name held by "account".
+	pub(crate) fn shares_of(holdings: &SharesOf<T>, account: &T::AccountId) -> u32 {
+		holdings
+			.iter()
+			.find(|(holder, _)| holder == account)
+			.map_or(0, |(_, shares)| *shares)
+	}
+
+	/// The number of shares a fractionalized name is split into.
+	pub(crate) fn total_shares(holdings: &SharesOf<T>) -> u32 {
+		holdings.iter().fold(0u32, |total, (_, shares)| total.saturating_add(*shares))
+	}
+
+	/// Forget the shareholders of "name" along with their votes.
+	pub(crate) fn end_fractional_ownership(name: &T::Name) {
+		NameShares::<T>::remove(name);
+		RenewalVotes::<T>::remove(name);
+	}
+
+	/// Ensure origin is the owner of the "name" and when successful return the ownership details.
+	pub(crate) fn ensure_owner(
+		who: &T::AccountId,
+		name: &T::Name,
+	) -> Result<OwnerOf<T>, DispatchError> {
+		let owner = Owners::<T>::get(name).ok_or(Error::<T>::NameNotFound)?;
+		ensure!(owner.id == *who, Error::<T>::NameNotOwned);
+		Ok(owner)
+	}
+
+	/// Ensure origin is the owner of the "name" as `ensure_owner` does, noting that the owner has
+	/// acted on the name, which restarts the inactivity period after which their heir may claim it.
+	pub(crate) fn ensure_acting_owner(
+		who: &T::AccountId,
+		name: &T::Name,
+	) -> Result<OwnerOf<T>, DispatchError> {
+		let owner = Self::ensure_owner(who, name)?;
+		if let Some(mut heir) = Heirs::<T>::get(name) {
+			heir.last_active = <frame_system::Pallet<T>>::block_number();
+			Heirs::<T>::insert(name, heir);
+		}
+		Ok(owner)
+	}
+
+	/// Transfer "name" of "owner" to "to" at block "at", see `Pallet::schedule_transfer`.
+	pub(crate) fn do_schedule_transfer(
+		name: &T::Name,
+		owner: &OwnerOf<T>,
+		to: T::AccountId,
+		at: T::BlockNumber,
+	) -> DispatchResult {
+		Self::ensure_status(name, &[NameStatus::Active])?;
+		ensure!(!NameShares::<T>::contains_key(name), Error::<T>::Fractionalized);
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(at > now, Error::<T>::ScheduleInPast);
+		ensure!(at < owner.lock_period.end, Error::<T>::TransferAfterExpiry);
+
+		ScheduledTransfers::<T>::insert(name, ScheduledTransfer { to: to.clone(), at });
+		NameStatuses::<T>::insert(name, NameStatus::PendingTransfer);
+		Self::schedule_expiry(at, Expiry::Transfer(name.clone()));
+
+		Self::deposit_name_event(name, Event::TransferScheduled(name.clone(), to, at));
+
+		Ok(())
+	}
+
+	/// Have "account" fill "role" under "name", see `Pallet::set_role`.
+	pub(crate) fn do_set_role(
+		name: &T::Name,
+		role: RoleOf<T>,
+		account: T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_status(name, &[NameStatus::Active, NameStatus::PendingTransfer])?;
+		NameRoles::<T>::try_mutate(name, |roles| {
+			match roles.iter_mut().find(|(filled, _)| *filled == role) {
+				Some((_, filler)) => *filler = account.clone(),
+				None => roles
+					.try_push((role.clone(), account.clone()))
+					.map_err(|_| Error::<T>::TooManyRoles)?,
+			}
+			Ok::<_, Error<T>>(())
+		})?;
+
+		Self::deposit_name_event(name, Event::RoleSet(name.clone(), role, account));
+
+		Ok(())
+	}
+
+	/// Remove "role" from "name", see `Pallet::clear_role`.
+	pub(crate) fn do_clear_role(name: &T::Name, role: RoleOf<T>) -> DispatchResult {
+		NameRoles::<T>::try_mutate_exists(name, |roles| {
+			let entries = roles.as_mut().ok_or(Error::<T>::RoleNotFound)?;
+			let index = entries
+				.iter()
+				.position(|(filled, _)| *filled == role)
+				.ok_or(Error::<T>::RoleNotFound)?;
+			entries.remove(index);
+			if entries.is_empty() {
+				*roles = None;
+			}
+			Ok::<_, Error<T>>(())
+		})?;
+
+		Self::deposit_name_event(name, Event::RoleCleared(name.clone(), role));
+
+		Ok(())
+	}
+
+	/// Move "name" from its "previous" owner to "new_owner", who takes over the ownership deposit.
+	/// The name keeps its expiry, everything else is unwound as with any change of owner. Only
+	/// an active name, or one about to be transferred, is handed over.
+	pub(crate) fn hand_over(
+		name: &T::Name,
+		previous: OwnerOf<T>,
+		new_owner: &T::AccountId,
+		acquisition: Acquisition,
+	) -> DispatchResult {
+		Self::ensure_status(name, &[NameStatus::Active, NameStatus::PendingTransfer])?;
+		let owner = Owner {
+			id: new_owner.clone(),
+			commit: previous.commit,
+			lock_period: previous.lock_period.clone(),
+			deposit: Self::ownership_deposit_of(name),
+			acquisition,
+			acquired_at: <frame_system::Pallet<T>>::block_number(),
+			renewals: previous.renewals,
+		};
+		Self::insert_owner(name, owner);
+		Self::release_held_fund(&previous.id);
+		Self::refund_deposit(name, &previous.id, previous.deposit);
+		Self::update_locked_fund(new_owner)?;
+
+		Self::deposit_name_event(name, Event::NameOwned(name.clone(), new_owner.clone()));
+
+		Ok(())
+	}
+
+	/// Commit "hash" for "who", see `Pallet::commit`.
+	pub(crate) fn do_commit(who: T::AccountId, hash: T::Hash) -> DispatchResult {
+		if Self::is_lock_backed(&who) {
+			let held = Self::held_deposits(&who).saturating_add(T::FundToLock::get());
+			ensure!(Self::can_hold(&who, held), Error::<T>::InsufficientLockedBalance);
+		}
+		let begin = <frame_system::Pallet<T>>::block_number();
+		let end = Self::period_end(begin, T::RegisterPeriod::get())?;
+		let lock_period: LockPeriod<T::BlockNumber> = LockPeriod { begin, end };
+		Self::insert_commit(&who, hash, lock_period);
+		Self::update_locked_fund(&who)
+	}
+
+	/// Set the fallback "price" of one reference unit, see `Pallet::set_fallback_price`.
+	pub(crate) fn do_set_fallback_price(price: Option<BalanceOf<T>>) -> DispatchResult {
+		FallbackPrice::<T>::set(price);
+
+		Self::deposit_event(Event::FallbackPriceSet(price));
+
+		Ok(())
+	}
+
+	/// Report "suspect" as a clone of "original" for "who", see `Pallet::report_squatting`.
+	pub(crate) fn do_report_squatting(
+		who: T::AccountId,
+		suspect: T::Name,
+		original: T::Name,
+	) -> DispatchResult {
+		let suspect = Self::normalize(&suspect);
+		let original = Self::normalize(&original);
+
+		ensure!(suspect != original, Error::<T>::SelfReport);
+		ensure!(Owners::<T>::contains_key(suspect.clone()), Error::<T>::NameNotFound);
+		ensure!(Owners::<T>::contains_key(original.clone()), Error::<T>::NameNotFound);
+		ensure!(!SquattingReports::<T>::contains_key(suspect.clone()), Error::<T>::AlreadyReported);
+		Self::ensure_status(&suspect, &[NameStatus::Active, NameStatus::PendingTransfer])?;
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		if let Some(last) = Self::last_report_at(who.clone()) {
+			let cooldown_end = last.saturating_add(T::ReportCooldown::get());
+			ensure!(now >= cooldown_end, Error::<T>::ReportTooSoon);
+		}
+
+		let bond = T::ReportBond::get();
+		T::Currency::reserve_named(&T::ModuleId::get(), &who, bond)?;
+
+		LastReportAt::<T>::insert(who.clone(), now);
+		Self::drop_scheduled_transfer(&suspect);
+		NameStatuses::<T>::insert(&suspect, NameStatus::Disputed);
+		SquattingReports::<T>::insert(
+			suspect.clone(),
+			SquattingReport {
+				reporter: who.clone(),
+				original: original.clone(),
+				bond,
+				filed_at: now,
+			},
+		);
+
+		Self::deposit_name_event(
+			&suspect,
+			Event::SquattingReported(suspect.clone(), original, who),
+		);
+
+		Ok(())
+	}
+
+	/// Resolve the squatting report on "suspect", see `Pallet::resolve_report`.
+	pub(crate) fn do_resolve_report(suspect: T::Name, upheld: bool) -> DispatchResult {
+		let suspect = Self::normalize(&suspect);
+
+		let report =
+			SquattingReports::<T>::take(suspect.clone()).ok_or(Error::<T>::ReportNotFound)?;
+
+		if upheld {
+			T::Currency::unreserve_named(&T::ModuleId::get(), &report.reporter, report.bond);
+			if let Some(owner) = Self::take_owner(&suspect) {
+				Self::update_locked_fund(&owner.id)?;
+				Self::refund_deposit(&suspect, &owner.id, owner.deposit);
+				Self::deposit_freed_event(&suspect, FreeReason::Squatting);
+			}
+		} else {
+			Self::slash_reserved(&report.reporter, report.bond);
+			if Owners::<T>::contains_key(&suspect) {
+				NameStatuses::<T>::insert(&suspect, NameStatus::Active);
+			}
+		}
+
+		Self::deposit_name_event(&suspect, Event::SquattingReportResolved(suspect.clone(), upheld));
+
+		Ok(())
+	}
+
+	/// Have the ownership deposit of "name", owned by "who", refunded to "beneficiary", see
+	/// `Pallet::set_deposit_beneficiary`.
+	pub(crate) fn do_set_deposit_beneficiary(
+		who: T::AccountId,
+		name: T::Name,
+		beneficiary: T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+		Self::ensure_status(&name, &[NameStatus::Active, NameStatus::PendingTransfer])?;
+		ensure!(!DepositDelegations::<T>::contains_key(&name), Error::<T>::AlreadyDelegated);
+
+		DepositBeneficiaries::<T>::insert(name.clone(), beneficiary.clone());
+
+		Self::deposit_name_event(&name, Event::DepositBeneficiarySet(name.clone(), beneficiary));
+
+		Ok(())
+	}
+
+	/// Unregister "name" regardless of its owner, see `Pallet::force_unregister`.
+	pub(crate) fn do_force_unregister(name: T::Name) -> DispatchResult {
+		let owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		let deadline = Self::period_end(now, T::AppealWindow::get())?;
+		let holder = Self::deposit_holder(&name, &owner.id);
+		Self::take_owner(&name);
+		Self::update_locked_fund(&owner.id)?;
+
+		let penalty = T::ForcedRemovalSlash::get() * owner.deposit;
+		let (imbalance, _) = T::Currency::slash(&holder, penalty);
+		let slashed = imbalance.peek();
+		T::FeeHandler::on_unbalanced(imbalance);
+		let refunded = owner.deposit.saturating_sub(slashed);
+		Self::refund_deposit(&name, &holder, refunded);
+
+		TakedownDeadlines::<T>::insert(deadline, &name, ());
+		Takedowns::<T>::insert(
+			&name,
+			Takedown { owner: owner.clone(), taken_at: now, deadline, appeal_bond: None },
+		);
+
+		Self::deposit_name_event(
+			&name,
+			Event::NameForceUnregistered(name.clone(), owner.id, slashed, refunded),
+		);
+		Self::deposit_freed_event(&name, FreeReason::TakenDown);
+
+		Ok(())
+	}
+
+	/// Offer to back the ownership deposit of "name" from "who", see `Pallet::offer_deposit`.
+	pub(crate) fn do_offer_deposit(who: T::AccountId, name: T::Name) -> DispatchResult {
+		let owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+		Self::ensure_status(&name, &[NameStatus::Active, NameStatus::PendingTransfer])?;
+		ensure!(owner.id != who, Error::<T>::SelfDelegation);
+		ensure!(!DepositDelegations::<T>::contains_key(&name), Error::<T>::AlreadyDelegated);
+
+		DepositOffers::<T>::insert(name.clone(), who.clone());
+
+		Self::deposit_name_event(&name, Event::DepositOffered(name.clone(), who));
+
+		Ok(())
+	}
+
+	/// Accept the offer to back the ownership deposit of "name", owned by "who", see
+	/// `Pallet::accept_deposit_offer`.
+	pub(crate) fn do_accept_deposit_offer(who: T::AccountId, name: T::Name) -> DispatchResult {
+		let owner = Self::ensure_acting_owner(&who, &name)?;
+		Self::ensure_status(&name, &[NameStatus::Active, NameStatus::PendingTransfer])?;
+		ensure!(!DepositDelegations::<T>::contains_key(&name), Error::<T>::AlreadyDelegated);
+		let depositor = DepositOffers::<T>::take(&name).ok_or(Error::<T>::OfferNotFound)?;
+
+		Self::sub_ownership_deposit(&who, owner.deposit);
+		Self::add_ownership_deposit(&depositor, owner.deposit);
+		DepositDelegations::<T>::insert(name.clone(), depositor.clone());
+		DepositBeneficiaries::<T>::remove(&name);
+		Self::update_locked_fund(&who)?;
+		Self::update_locked_fund(&depositor)?;
+
+		Self::deposit_name_event(&name, Event::DepositDelegated(name.clone(), depositor));
+
+		Ok(())
+	}
+
+	/// End the delegation of the ownership deposit of "name" on behalf of "who", see
+	/// `Pallet::end_deposit_delegation`.
+	pub(crate) fn do_end_deposit_delegation(who: T::AccountId, name: T::Name) -> DispatchResult {
+		let owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+		let depositor = Self::deposit_delegations(&name).ok_or(Error::<T>::NotDelegated)?;
+		ensure!(who == owner.id || who == depositor, Error::<T>::NotDelegationParty);
+
+		Self::release_ownership_deposit(&name, &owner);
+		Self::add_ownership_deposit(&owner.id, owner.deposit);
+		Self::update_locked_fund(&owner.id)?;
+
+		Ok(())
+	}
+
+	/// Attest that "who" owns "name" by echoing "challenge", see `Pallet::attest_ownership`.
+	pub(crate) fn do_attest_ownership(
+		who: T::AccountId,
+		name: T::Name,
+		challenge: BoundedVec<u8, T::MaxChallengeLen>,
+	) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+
+		Self::deposit_name_event(&name, Event::OwnershipAttested(name.clone(), who, challenge));
+
+		Ok(())
+	}
+
+	/// Have "name", owned by "who", owned jointly by "members", see `Pallet::set_joint_owners`.
+	pub(crate) fn do_set_joint_owners(
+		who: T::AccountId,
+		name: T::Name,
+		members: MembersOf<T>,
+		threshold: u32,
+	) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+		Self::ensure_status(&name, &[NameStatus::Active])?;
+		ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+		ensure!(!NameShares::<T>::contains_key(&name), Error::<T>::Fractionalized);
+
+		let mut members = members.into_inner();
+		members.sort();
+		members.dedup();
+		ensure!(threshold > 0 && threshold as usize <= members.len(), Error::<T>::InvalidThreshold);
+		let members =
+			MembersOf::<T>::try_from(members).map_err(|_| Error::<T>::InvalidThreshold)?;
+
+		JointOwners::<T>::insert(&name, JointOwnership { members: members.clone(), threshold });
+
+		Self::deposit_name_event(&name, Event::JointOwnersSet(name.clone(), members, threshold));
+
+		Ok(())
+	}
+
+	/// Approve "action" on the jointly owned "name" as "who", see `Pallet::approve_action`.
+	pub(crate) fn do_approve_action(
+		who: T::AccountId,
+		name: T::Name,
+		action: JointActionOf<T>,
+	) -> DispatchResult {
+		let joint = Self::joint_owners(&name).ok_or(Error::<T>::NotJointlyOwned)?;
+		Self::ensure_status(&name, &[NameStatus::Active, NameStatus::GracePeriod])?;
+		ensure!(joint.members.contains(&who), Error::<T>::NotJointOwner);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut pending = match Self::active_pending_action(&name, &action) {
+			Some(pending) => pending,
+			None => PendingAction {
+				approvals: Default::default(),
+				expires_at: Self::period_end(now, T::ApprovalPeriod::get())?,
+			},
+		};
+		ensure!(!pending.approvals.contains(&who), Error::<T>::AlreadyApproved);
+		pending.approvals.try_push(who.clone()).map_err(|_| Error::<T>::NotJointOwner)?;
+
+		let event = Event::ActionApproved(name.clone(), action.clone(), who);
+		Self::deposit_name_event(&name, event);
+
+		if (pending.approvals.len() as u32) < joint.threshold {
+			PendingActions::<T>::insert(&name, &action, pending);
+			return Ok(())
+		}
+
+		PendingActions::<T>::remove(&name, &action);
+		Self::deposit_name_event(&name, Event::ActionExecuted(name.clone(), action.clone()));
+		match action {
+			JointAction::Unregister => {
+				let owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+				Self::do_unregister(&name, owner, FreeReason::Unregistered)
+			},
+			JointAction::Dissolve => {
+				Self::end_joint_ownership(&name);
+				Ok(())
+			},
+			JointAction::ScheduleTransfer { to, at } => {
+				let owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+				Self::do_schedule_transfer(&name, &owner, to, at)
+			},
+			JointAction::SetRole { role, account } => Self::do_set_role(&name, role, account),
+			JointAction::ClearRole { role } => Self::do_clear_role(&name, role),
+		}
+	}
+
+	/// Split the ownership of "name", owned by "who", into "shares" shares, see
+	/// `Pallet::fractionalize`.
+	pub(crate) fn do_fractionalize(
+		who: T::AccountId,
+		name: T::Name,
+		shares: u32,
+	) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+		Self::ensure_status(&name, &[NameStatus::Active])?;
+		ensure!(!NameShares::<T>::contains_key(&name), Error::<T>::Fractionalized);
+		ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+		ensure!(shares > 0, Error::<T>::NoShares);
+
+		let holdings = SharesOf::<T>::try_from(sp_std::vec![(who.clone(), shares)])
+			.map_err(|_| Error::<T>::TooManyShareholders)?;
+		NameShares::<T>::insert(&name, holdings);
+
+		Self::deposit_name_event(&name, Event::NameFractionalized(name.clone(), who, shares));
+
+		Ok(())
+	}
+
+	/// Transfer "amount" shares of "name" from "who" to "dest", see `Pallet::transfer_shares`.
+	pub(crate) fn do_transfer_shares(
+		who: T::AccountId,
+		name: T::Name,
+		dest: T::AccountId,
+		amount: u32,
+	) -> DispatchResult {
+		let mut holdings = Self::name_shares(&name).ok_or(Error::<T>::NotFractionalized)?;
+		Self::ensure_status(&name, &[NameStatus::Active])?;
+		let from = holdings
+			.iter()
+			.position(|(holder, _)| *holder == who)
+			.ok_or(Error::<T>::InsufficientShares)?;
+		let remaining =
+			holdings[from].1.checked_sub(amount).ok_or(Error::<T>::InsufficientShares)?;
+
+		let mut updated = holdings.into_inner();
+		updated[from].1 = remaining;
+		match updated.iter_mut().find(|(holder, _)| *holder == dest) {
+			Some((_, shares)) => *shares = shares.saturating_add(amount),
+			None => updated.push((dest.clone(), amount)),
+		}
+		updated.retain(|(_, shares)| *shares > 0);
+		holdings = SharesOf::<T>::try_from(updated).map_err(|_| Error::<T>::TooManyShareholders)?;
+		NameShares::<T>::insert(&name, holdings);
+
+		Self::deposit_name_event(&name, Event::SharesTransferred(name.clone(), who, dest, amount));
+
+		Ok(())
+	}
+
+	/// Pay "amount" from "who" to the shareholders of "name", see `Pallet::distribute_revenue`.
+	pub(crate) fn do_distribute_revenue(
+		who: T::AccountId,
+		name: T::Name,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let holdings = Self::name_shares(&name).ok_or(Error::<T>::NotFractionalized)?;
+		Self::ensure_status(&name, &[NameStatus::Active])?;
+		let total = Self::total_shares(&holdings);
+		for (holder, shares) in holdings.iter() {
+			let payout = Perbill::from_rational(*shares, total) * amount;
+			T::Currency::transfer(&who, holder, payout, ExistenceRequirement::KeepAlive)?;
+		}
+
+		Self::deposit_name_event(&name, Event::RevenueDistributed(name.clone(), who, amount));
+
+		Ok(())
+	}
+
+	/// Vote for the renewal of the fractionalized "name" as "who", see `Pallet::vote_renewal`.
+	pub(crate) fn do_vote_renewal(who: T::AccountId, name: T::Name) -> DispatchResult {
+		let holdings = Self::name_shares(&name).ok_or(Error::<T>::NotFractionalized)?;
+		ensure!(Self::shares_of(&holdings, &who) > 0, Error::<T>::InsufficientShares);
+		let mut voters = Self::renewal_votes(&name);
+		ensure!(!voters.contains(&who), Error::<T>::AlreadyVoted);
+		voters.try_push(who.clone()).map_err(|_| Error::<T>::TooManyShareholders)?;
+
+		Self::deposit_name_event(&name, Event::RenewalVoted(name.clone(), who.clone()));
+
+		let votes = voters
+			.iter()
+			.fold(0u32, |votes, voter| votes.saturating_add(Self::shares_of(&holdings, voter)));
+		if votes.saturating_mul(2) <= Self::total_shares(&holdings) {
+			RenewalVotes::<T>::insert(&name, voters);
+			return Ok(())
+		}
+
+		RenewalVotes::<T>::remove(&name);
+		let owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+		Self::do_renew(&name, owner, &who, &who)
+	}
+
+	/// Appeal the takedown of "name" on behalf of its former owner "who", see
+	/// `Pallet::appeal_takedown`.
+	pub(crate) fn do_appeal_takedown(who: T::AccountId, name: T::Name) -> DispatchResult {
+		let mut takedown = Self::takedowns(&name).ok_or(Error::<T>::TakedownNotFound)?;
+		ensure!(takedown.owner.id == who, Error::<T>::NotFormerOwner);
+		ensure!(takedown.appeal_bond.is_none(), Error::<T>::AlreadyAppealed);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let deadline = Self::period_end(now, T::AppealWindow::get())?;
+		let bond = T::AppealBond::get();
+		T::Currency::reserve_named(&T::ModuleId::get(), &who, bond)?;
+
+		TakedownDeadlines::<T>::remove(takedown.deadline, &name);
+		takedown.deadline = deadline;
+		takedown.appeal_bond = Some(bond);
+		TakedownDeadlines::<T>::insert(takedown.deadline, &name, ());
+		Takedowns::<T>::insert(&name, takedown);
+
+		Self::deposit_name_event(&name, Event::TakedownAppealed(name.clone(), who));
+
+		Ok(())
+	}
+
+	/// Decide the appeal against the takedown of "name", see `Pallet::resolve_appeal`.
+	pub(crate) fn do_resolve_appeal(name: T::Name, reversed: bool) -> DispatchResult {
+		let takedown = Self::takedowns(&name).ok_or(Error::<T>::TakedownNotFound)?;
+		let bond = takedown.appeal_bond.ok_or(Error::<T>::AppealNotFound)?;
+		Takedowns::<T>::remove(&name);
+		TakedownDeadlines::<T>::remove(takedown.deadline, &name);
+
+		let former = takedown.owner;
+		if reversed {
+			T::Currency::unreserve_named(&T::ModuleId::get(), &former.id, bond);
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut lock_period = former.lock_period;
+			lock_period.end = lock_period.end.saturating_add(now - takedown.taken_at);
+			let owner = Owner {
+				id: former.id.clone(),
+				commit: former.commit,
+				lock_period,
+				deposit: Self::ownership_deposit_of(&name),
+				acquisition: Acquisition::Governance,
+				acquired_at: now,
+				renewals: former.renewals,
+			};
+			Self::insert_owner(&name, owner);
+			Self::update_locked_fund(&former.id)?;
+			Self::deposit_name_event(&name, Event::NameOwned(name.clone(), former.id));
+		} else {
+			Self::slash_reserved(&former.id, bond);
+		}
+
+		Self::deposit_name_event(&name, Event::AppealResolved(name.clone(), reversed));
+
+		Ok(())
+	}
+
+	/// Put the name hashed to "hash" on the deny list or take it off, see
+	/// `Pallet::set_name_denied`.
+	pub(crate) fn do_set_name_denied(hash: T::Hash, denied: bool) -> DispatchResult {
+		if denied {
+			DeniedNames::<T>::insert(hash, ());
+		} else {
+			DeniedNames::<T>::remove(hash);
+		}
+
+		Self::deposit_event(Event::NameDenied(hash, denied));
+
+		Ok(())
+	}
+
+	/// Schedule "action" on "name", owned by "who", at block "when", see `Pallet::schedule_action`.
+	pub(crate) fn do_schedule_action(
+		who: T::AccountId,
+		name: T::Name,
+		action: ScheduledAction,
+		when: T::BlockNumber,
+	) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(when > now, Error::<T>::ScheduleInPast);
+
+		let id = Self::schedule_id(&name, action);
+		if ScheduledActions::<T>::contains_key(&name, action) {
+			// The previous action may have been dispatched already.
+			let _ = T::Scheduler::cancel_named(id.clone());
+		}
+		T::Scheduler::schedule_named(
+			id,
+			DispatchTime::At(when),
+			None,
+			LOWEST_PRIORITY,
+			frame_system::RawOrigin::Signed(who).into(),
+			Self::scheduled_call(&name, action),
+		)
+		.map_err(|_| Error::<T>::ScheduleFailed)?;
+		ScheduledActions::<T>::insert(&name, action, when);
+
+		Self::deposit_name_event(&name, Event::ActionScheduled(name.clone(), action, when));
+
+		Ok(())
+	}
+
+	/// Cancel "action" scheduled on "name", owned by "who", see `Pallet::cancel_scheduled_action`.
+	pub(crate) fn do_cancel_scheduled_action(
+		who: T::AccountId,
+		name: T::Name,
+		action: ScheduledAction,
+	) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+		ensure!(ScheduledActions::<T>::take(&name, action).is_some(), Error::<T>::NotScheduled);
+		let _ = T::Scheduler::cancel_named(Self::schedule_id(&name, action));
+
+		Self::deposit_name_event(&name, Event::ScheduledActionCancelled(name.clone(), action));
+
+		Ok(())
+	}
+
+	/// Reserve "amount" from "who" towards the renewals of "name", see
+	/// `Pallet::contribute_to_renewal`.
+	pub(crate) fn do_contribute_to_renewal(
+		who: T::AccountId,
+		name: T::Name,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(Owners::<T>::contains_key(&name), Error::<T>::NameNotFound);
+		ensure!(!PermanentNames::<T>::contains_key(&name), Error::<T>::PermanentName);
+		ensure!(!amount.is_zero(), Error::<T>::ZeroContribution);
+		let mut pool = Self::renewal_pools(&name).unwrap_or_default();
+		match pool.iter_mut().find(|(contributor, _)| *contributor == who) {
+			Some((_, contribution)) => *contribution = contribution.saturating_add(amount),
+			None => pool
+				.try_push((who.clone(), amount))
+				.map_err(|_| Error::<T>::TooManyContributors)?,
+		}
+		T::Currency::reserve_named(&T::ModuleId::get(), &who, amount)?;
+		RenewalPools::<T>::insert(&name, pool);
+
+		Self::deposit_name_event(&name, Event::RenewalContributed(name.clone(), who, amount));
+
+		Ok(())
+	}
+
+	/// Trust "guardians" to recover "name", owned by "who", see `Pallet::set_guardians`.
+	pub(crate) fn do_set_guardians(
+		who: T::AccountId,
+		name: T::Name,
+		guardians: GuardiansOf<T>,
+		threshold: u32,
+	) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+		Self::ensure_status(&name, &[NameStatus::Active, NameStatus::PendingTransfer])?;
+		ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+		ensure!(!NameShares::<T>::contains_key(&name), Error::<T>::Fractionalized);
+
+		let mut guardians = guardians.into_inner();
+		guardians.sort();
+		guardians.dedup();
+		ensure!(
+			guardians.is_empty() || (threshold > 0 && threshold as usize <= guardians.len()),
+			Error::<T>::InvalidThreshold
+		);
+		let guardians =
+			GuardiansOf::<T>::try_from(guardians).map_err(|_| Error::<T>::InvalidThreshold)?;
+
+		Recoveries::<T>::remove(&name);
+		if guardians.is_empty() {
+			Guardians::<T>::remove(&name);
+		} else {
+			let guardianship = Guardianship { guardians: guardians.clone(), threshold };
+			Guardians::<T>::insert(&name, guardianship);
+		}
+
+		Self::deposit_name_event(&name, Event::GuardiansSet(name.clone(), guardians, threshold));
+
+		Ok(())
+	}
+
+	/// Vote as the guardian "who" to recover "name" to "new_owner", see `Pallet::vote_recovery`.
+	pub(crate) fn do_vote_recovery(
+		who: T::AccountId,
+		name: T::Name,
+		new_owner: T::AccountId,
+	) -> DispatchResult {
+		let guardianship = Self::guardians(&name).ok_or(Error::<T>::NotGuardian)?;
+		Self::ensure_status(&name, &[NameStatus::Active])?;
+		ensure!(guardianship.guardians.contains(&who), Error::<T>::NotGuardian);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut recovery = match Self::active_recovery(&name) {
+			Some(recovery) => recovery,
+			None => Recovery {
+				new_owner: new_owner.clone(),
+				votes: Default::default(),
+				expires_at: Self::period_end(now, T::ApprovalPeriod::get())?,
+				executable_at: None,
+			},
+		};
+		ensure!(recovery.new_owner == new_owner, Error::<T>::RecoveryMismatch);
+		ensure!(!recovery.votes.contains(&who), Error::<T>::AlreadyVoted);
+		recovery.votes.try_push(who.clone()).map_err(|_| Error::<T>::NotGuardian)?;
+
+		Self::deposit_name_event(&name, Event::RecoveryVoted(name.clone(), who, new_owner.clone()));
+
+		if recovery.executable_at.is_none() && recovery.votes.len() as u32 >= guardianship.threshold
+		{
+			let executable_at = Self::period_end(now, T::RecoveryDelay::get())?;
+			recovery.executable_at = Some(executable_at);
+			Self::deposit_name_event(
+				&name,
+				Event::RecoveryApproved(name.clone(), new_owner, executable_at),
+			);
+		}
+		Recoveries::<T>::insert(&name, recovery);
+
+		Ok(())
+	}
+
+	/// Veto the recovery of "name", owned by "who", see `Pallet::veto_recovery`.
+	pub(crate) fn do_veto_recovery(who: T::AccountId, name: T::Name) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+		Recoveries::<T>::take(&name).ok_or(Error::<T>::RecoveryNotFound)?;
+
+		Self::deposit_name_event(&name, Event::RecoveryVetoed(name.clone()));
+
+		Ok(())
+	}
+
+	/// Move "name" to the new owner its guardians approved, see `Pallet::execute_recovery`.
+	pub(crate) fn do_execute_recovery(name: T::Name) -> DispatchResult {
+		let recovery = Self::recoveries(&name).ok_or(Error::<T>::RecoveryNotFound)?;
+		Self::ensure_status(&name, &[NameStatus::Active])?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(
+			recovery.executable_at.map_or(false, |executable_at| executable_at <= now),
+			Error::<T>::RecoveryNotDue
+		);
+		ensure!(!ScheduledTransfers::<T>::contains_key(&name), Error::<T>::TransferPending);
+		// The guardians may have been trusted before the name was shared.
+		ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+		ensure!(!NameShares::<T>::contains_key(&name), Error::<T>::Fractionalized);
+		let previous = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+		let guardianship = Self::guardians(&name);
+
+		let new_owner = recovery.new_owner;
+		Self::deposit_name_event(&name, Event::NameRecovered(name.clone(), new_owner.clone()));
+		Self::hand_over(&name, previous, &new_owner, Acquisition::Recovery)?;
+		if let Some(guardianship) = guardianship {
+			Guardians::<T>::insert(&name, guardianship);
+		}
+
+		Ok(())
+	}
+
+	/// Designate "heir" to inherit "name", owned by "who", see `Pallet::set_heir`.
+	pub(crate) fn do_set_heir(
+		who: T::AccountId,
+		name: T::Name,
+		heir: T::AccountId,
+		inactivity_blocks: T::BlockNumber,
+	) -> DispatchResult {
+		Self::ensure_owner(&who, &name)?;
+		Self::ensure_status(&name, &[NameStatus::Active, NameStatus::PendingTransfer])?;
+		ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+		ensure!(!NameShares::<T>::contains_key(&name), Error::<T>::Fractionalized);
+		ensure!(
+			inactivity_blocks >= T::MinInactivityPeriod::get(),
+			Error::<T>::InactivityPeriodTooShort
+		);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		Heirs::<T>::insert(
+			&name,
+			Heir { account: heir.clone(), inactivity_blocks, last_active: now },
+		);
+
+		Self::deposit_name_event(&name, Event::HeirSet(name.clone(), heir, inactivity_blocks));
+
+		Ok(())
+	}
+
+	/// Remove the heir of "name", owned by "who", see `Pallet::remove_heir`.
+	pub(crate) fn do_remove_heir(who: T::AccountId, name: T::Name) -> DispatchResult {
+		Self::ensure_owner(&who, &name)?;
+		Heirs::<T>::take(&name).ok_or(Error::<T>::HeirNotFound)?;
+
+		Self::deposit_name_event(&name, Event::HeirRemoved(name.clone()));
+
+		Ok(())
+	}
+
+	/// Claim "name" for its heir "who", see `Pallet::claim_inheritance`.
+	pub(crate) fn do_claim_inheritance(who: T::AccountId, name: T::Name) -> DispatchResult {
+		let heir = Self::heirs(&name).ok_or(Error::<T>::HeirNotFound)?;
+		ensure!(heir.account == who, Error::<T>::NotHeir);
+		Self::ensure_status(&name, &[NameStatus::Active])?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(heir.claimable_at() <= now, Error::<T>::OwnerStillActive);
+		ensure!(!ScheduledTransfers::<T>::contains_key(&name), Error::<T>::TransferPending);
+		// The heir may have been designated before the name was shared.
+		ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+		ensure!(!NameShares::<T>::contains_key(&name), Error::<T>::Fractionalized);
+		let previous = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+
+		Self::deposit_name_event(&name, Event::InheritanceClaimed(name.clone(), who.clone()));
+		Self::hand_over(&name, previous, &who, Acquisition::Inheritance)
+	}
+
+	/// Cancel the scheduled transfer of "name", owned by "who", see `Pallet::cancel_transfer`.
+	pub(crate) fn do_cancel_transfer(who: T::AccountId, name: T::Name) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+		ScheduledTransfers::<T>::take(&name).ok_or(Error::<T>::TransferNotScheduled)?;
+		NameStatuses::<T>::insert(&name, NameStatus::Active);
+
+		Self::deposit_name_event(&name, Event::TransferCancelled(name.clone()));
+
+		Ok(())
+	}
+
+	/// Reveal all of "names" committed to by "who", or none of them, see `Pallet::reveal_bundle`.
+	pub(crate) fn do_reveal_bundle(
+		who: T::AccountId,
+		names: BoundedVec<T::Name, T::MaxBundleLen>,
+	) -> DispatchResult {
+		ensure!(!names.is_empty(), Error::<T>::EmptyBundle);
+
+		let claimed = with_transaction(|| {
+			for name in names.iter() {
+				let error = match Self::do_reveal(&who, name.clone(), &[], 1) {
+					Ok(true) => continue,
+					Ok(false) => Error::<T>::BundleClaimLost.into(),
+					Err(error) => error,
+				};
+				return TransactionOutcome::Rollback(Err(error))
+			}
+			TransactionOutcome::Commit(Ok(()))
+		});
+
+		match claimed {
+			Ok(()) => Self::deposit_event(Event::BundleRevealed(who, names.len() as u32)),
+			Err(error) => {
+				for name in names {
+					Self::take_commit(&who, Self::hash_of(who.clone(), name));
+				}
+				Self::release_held_fund(&who);
+				Self::deposit_event(Event::BundleFailed(who, error));
+			},
+		}
+
+		Ok(())
+	}
+
+	/// Opt "who" in or out of backing its deposits with its locked balance, see
+	/// `Pallet::set_lock_backing`.
+	pub(crate) fn do_set_lock_backing(who: T::AccountId, enabled: bool) -> DispatchResult {
+		if enabled {
+			ensure!(
+				T::LockedBalances::locked_balance(&who) >= Self::held_deposits(&who),
+				Error::<T>::InsufficientLockedBalance
+			);
+			Self::set_reserved(&who, Zero::zero())?;
+			LockBackedAccounts::<T>::insert(&who, ());
+		} else {
+			LockBackedAccounts::<T>::remove(&who);
+			T::Currency::remove_lock(T::ModuleId::get(), &who);
+		}
+		Self::update_locked_fund(&who)?;
+
+		Self::deposit_event(Event::LockBackingSet(who, enabled));
+
+		Ok(())
+	}
+
+	/// Mint a voucher under "hash" for "name" or any name, see `Pallet::mint_voucher`.
+	pub(crate) fn do_mint_voucher(hash: T::Hash, name: Option<T::Name>) -> DispatchResult {
+		ensure!(!Vouchers::<T>::contains_key(&hash), Error::<T>::VoucherExists);
+
+		let voucher = match name {
+			Some(name) => {
+				let name = Self::normalize(&name);
+				ensure!(!VoucherReservations::<T>::contains_key(&name), Error::<T>::NameReserved);
+				VoucherReservations::<T>::insert(&name, hash);
+				Voucher::Reserved(name)
+			},
+			None => Voucher::AnyName,
+		};
+		Vouchers::<T>::insert(hash, voucher);
+
+		Self::deposit_event(Event::VoucherMinted(hash));
+
+		Ok(())
+	}
+
+	/// Revoke the voucher minted under "hash", see `Pallet::revoke_voucher`.
+	pub(crate) fn do_revoke_voucher(hash: T::Hash) -> DispatchResult {
+		let voucher = Vouchers::<T>::take(hash).ok_or(Error::<T>::VoucherNotFound)?;
+		if let Voucher::Reserved(name) = voucher {
+			VoucherReservations::<T>::remove(name);
+		}
+
+		Self::deposit_event(Event::VoucherRevoked(hash));
+
+		Ok(())
+	}
+
+	/// Redeem the voucher whose code is "code" by registering "name" for "who", see
+	/// `Pallet::redeem_voucher`.
+	pub(crate) fn do_redeem_voucher(
+		who: T::AccountId,
+		code: VoucherCodeOf<T>,
+		name: T::Name,
+	) -> DispatchResult {
+		let hash = T::Hashing::hash(&code[..]);
+		let voucher = Vouchers::<T>::take(hash).ok_or(Error::<T>::VoucherNotFound)?;
+		if let Voucher::Reserved(reserved) = voucher {
+			ensure!(Self::normalize(&name) == reserved, Error::<T>::VoucherMismatch);
+			VoucherReservations::<T>::remove(reserved);
+		}
+
+		let commit = Self::hash_of(who.clone(), name.clone());
+		let revealed = name;
+		let name = Self::check_claim(&who, &revealed)?;
+		ensure!(!Owners::<T>::contains_key(&name), Error::<T>::NameAlreadyRegistered);
+
+		let begin = <frame_system::Pallet<T>>::block_number();
+		let end = Self::period_end(begin, T::RegisterPeriod::get())?;
+		let owner = Owner {
+			id: who.clone(),
+			commit,
+			lock_period: LockPeriod { begin, end },
+			deposit: Self::ownership_deposit_of(&name),
+			acquisition: Acquisition::Voucher,
+			acquired_at: begin,
+			renewals: 0,
+		};
+		Self::assign(&name, revealed, owner)?;
+
+		Self::deposit_event(Event::VoucherRedeemed(hash, name, who));
+
+		Ok(())
+	}
+
+	/// Reveal "name" from the commit of "who" on behalf of "beneficiary", see
+	/// `Pallet::reveal_gift`.
+	pub(crate) fn do_reveal_gift(
+		who: T::AccountId,
+		name: T::Name,
+		beneficiary: T::AccountId,
+	) -> DispatchResult {
+		ensure!(beneficiary != who, Error::<T>::GiftToSelf);
+
+		let normalized = Self::normalize(&name);
+		if !Self::do_reveal(&who, name, &[], 1)? {
+			return Ok(())
+		}
+		let name = normalized;
+
+		let revealed = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+		let deposit = revealed.deposit;
+		let gifted = Owner { id: beneficiary.clone(), acquisition: Acquisition::Gift, ..revealed };
+		Self::insert_owner(&name, gifted);
+		Self::sub_ownership_deposit(&beneficiary, deposit);
+		Self::add_ownership_deposit(&who, deposit);
+		DepositDelegations::<T>::insert(&name, who.clone());
+		let rejectable_until = Self::period_end(
+			<frame_system::Pallet<T>>::block_number(),
+			T::GiftRejectionPeriod::get(),
+		)?;
+		Gifts::<T>::insert(&name, Gift { giver: who.clone(), rejectable_until });
+
+		Self::deposit_name_event(&name, Event::NameGifted(name.clone(), who, beneficiary.clone()));
+		Self::deposit_name_event(&name, Event::NameOwned(name.clone(), beneficiary));
+
+		Ok(())
+	}
+
+	/// Hand "name", received by "who" as a gift, back to its giver, see `Pallet::reject_gift`.
+	pub(crate) fn do_reject_gift(who: T::AccountId, name: T::Name) -> DispatchResult {
+		let owner = Self::ensure_owner(&who, &name)?;
+		let gift = Self::pending_gift(&name).ok_or(Error::<T>::GiftNotFound)?;
+
+		Self::deposit_name_event(&name, Event::GiftRejected(name.clone(), who));
+		Self::hand_over(&name, owner, &gift.giver, Acquisition::Reveal)
+	}
+
+	/// Reserve "amount" from "who" as renewal credits, see `Pallet::buy_credits`.
+	pub(crate) fn do_buy_credits(who: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::ZeroContribution);
+		T::Currency::reserve_named(&T::ModuleId::get(), &who, amount)?;
+		Self::set_renewal_credits(&who, Self::renewal_credits(&who).saturating_add(amount));
+
+		Self::deposit_event(Event::CreditsBought(who, amount));
+
+		Ok(())
+	}
+
+	/// Unreserve "amount" of the renewal credits of "who", see `Pallet::withdraw_credits`.
+	pub(crate) fn do_withdraw_credits(who: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::ZeroContribution);
+		let credits = Self::renewal_credits(&who);
+		ensure!(credits >= amount, Error::<T>::InsufficientCredits);
+		T::Currency::unreserve_named(&T::ModuleId::get(), &who, amount);
+		Self::set_renewal_credits(&who, credits - amount);
+
+		Self::deposit_event(Event::CreditsWithdrawn(who, amount));
+
+		Ok(())
+	}
+
+	/// Watch the name hashed to "hash" on behalf of "who", see `Pallet::watch`.
+	pub(crate) fn do_watch(who: T::AccountId, hash: T::Hash) -> DispatchResult {
+		Watchers::<T>::try_mutate(hash, |watchers| -> DispatchResult {
+			ensure!(
+				!watchers.iter().any(|(watcher, _)| *watcher == who),
+				Error::<T>::AlreadyWatching
+			);
+			let deposit = T::WatchDeposit::get();
+			watchers
+				.try_push((who.clone(), deposit))
+				.map_err(|_| Error::<T>::TooManyWatchers)?;
+			T::Currency::reserve_named(&T::ModuleId::get(), &who, deposit)
+		})?;
+
+		Self::deposit_event(Event::NameWatched(hash, who));
+
+		Ok(())
+	}
+
+	/// Stop "who" watching the name hashed to "hash", see `Pallet::unwatch`.
+	pub(crate) fn do_unwatch(who: T::AccountId, hash: T::Hash) -> DispatchResult {
+		let mut watchers = Self::watchers(hash);
+		let index = watchers
+			.iter()
+			.position(|(watcher, _)| *watcher == who)
+			.ok_or(Error::<T>::NotWatching)?;
+		let (_, deposit) = watchers.remove(index);
+		T::Currency::unreserve_named(&T::ModuleId::get(), &who, deposit);
+		if watchers.is_empty() {
+			Watchers::<T>::remove(hash);
+		} else {
+			Watchers::<T>::insert(hash, watchers);
+		}
+
+		Self::deposit_event(Event::NameUnwatched(hash, who));
+
+		Ok(())
+	}
+
+	/// Grant "name" to "owner" for good, see `Pallet::grant_permanent`.
+	pub(crate) fn do_grant_permanent(name: T::Name, owner: T::AccountId) -> DispatchResult {
+		let revealed = name;
+		let name = Self::normalize(&revealed);
+		ensure!(!Owners::<T>::contains_key(&name), Error::<T>::NameAlreadyRegistered);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		PermanentNames::<T>::insert(&name, ());
+		Self::insert_owner(
+			&name,
+			Owner {
+				id: owner.clone(),
+				commit: Self::hash_of(owner.clone(), revealed.clone()),
+				lock_period: LockPeriod { begin: now, end: T::BlockNumber::max_value() },
+				deposit: Zero::zero(),
+				acquisition: Acquisition::Governance,
+				acquired_at: now,
+				renewals: 0,
+			},
+		);
+		if revealed != name {
+			NameCasings::<T>::insert(&name, revealed);
+		}
+
+		Self::deposit_name_event(&name, Event::PermanentNameGranted(name.clone(), owner));
+
+		Ok(())
+	}
+
+	/// Free the permanent "name", see `Pallet::revoke_permanent`.
+	pub(crate) fn do_revoke_permanent(name: T::Name) -> DispatchResult {
+		ensure!(PermanentNames::<T>::contains_key(&name), Error::<T>::NotPermanent);
+		let owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+
+		Self::deposit_name_event(&name, Event::PermanentNameRevoked(name.clone()));
+		Self::do_unregister(&name, owner, FreeReason::Revoked)
+	}
+
+	/// Have "who" agree to take over the registry state of "from", see
+	/// `Pallet::consent_to_migration`.
+	pub(crate) fn do_consent_to_migration(
+		who: T::AccountId,
+		from: Option<T::AccountId>,
+	) -> DispatchResult {
+		match &from {
+			Some(from) => MigrationConsents::<T>::insert(&who, from),
+			None => MigrationConsents::<T>::remove(&who),
+		}
+
+		Self::deposit_event(Event::MigrationConsentSet(who, from));
+
+		Ok(())
+	}
+
+	/// Move at most "names" names of "who" to "new_account", see `Pallet::migrate_account`.
+	pub(crate) fn do_migrate_account(
+		who: T::AccountId,
+		new_account: T::AccountId,
+		names: u32,
+	) -> DispatchResult {
+		ensure!(
+			Self::migration_consents(&new_account).as_ref() == Some(&who),
+			Error::<T>::MigrationNotConsented
+		);
+		ensure!(LockPeriods::<T>::iter_prefix(&who).next().is_none(), Error::<T>::PendingCommits);
+		let owned = Self::list_names_of(&who, None, names.saturating_add(1));
+		ensure!(
+			owned.next.is_none() && owned.items.len() <= names as usize,
+			Error::<T>::TooManyNames
+		);
+		MigrationConsents::<T>::remove(&new_account);
+
+		let mut deposits = BalanceOf::<T>::zero();
+		for name in &owned.items {
+			ensure!(!JointOwners::<T>::contains_key(name), Error::<T>::JointlyOwned);
+			ensure!(!NameShares::<T>::contains_key(name), Error::<T>::Fractionalized);
+			let owner = Self::owners(name).ok_or(Error::<T>::NameNotFound)?;
+			if Self::deposit_holder(name, &who) == who {
+				deposits = deposits.saturating_add(owner.deposit);
+			}
+			Self::move_owner(name, owner, &new_account);
+		}
+		Self::sub_ownership_deposit(&who, deposits);
+		Self::add_ownership_deposit(&new_account, deposits);
+		ensure!(
+			Self::can_hold(&new_account, Self::held_deposits(&new_account)),
+			Error::<T>::InsufficientBalance
+		);
+		Self::release_held_fund(&who);
+		Self::update_locked_fund(&new_account)?;
+
+		let credits = Self::renewal_credits(&who);
+		if !credits.is_zero() {
+			T::Currency::repatriate_reserved_named(
+				&T::ModuleId::get(),
+				&who,
+				&new_account,
+				credits,
+				BalanceStatus::Reserved,
+			)?;
+			Self::set_renewal_credits(&who, Zero::zero());
+			let total = Self::renewal_credits(&new_account).saturating_add(credits);
+			Self::set_renewal_credits(&new_account, total);
+		}
+
+		Self::deposit_event(Event::AccountMigrated(who, new_account, owned.items.len() as u32));
+
+		Ok(())
+	}
+
+	/// Offer to pay the renewal fees of "name" from "who", see `Pallet::offer_payment`.
+	pub(crate) fn do_offer_payment(who: T::AccountId, name: T::Name) -> DispatchResult {
+		let owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+		ensure!(owner.id != who, Error::<T>::SelfPayment);
+
+		PaymentOffers::<T>::insert(name.clone(), who.clone());
+
+		Self::deposit_name_event(&name, Event::PaymentOffered(name.clone(), who));
+
+		Ok(())
+	}
+
+	/// Accept the offer to pay the renewal fees of "name", owned by "who", see
+	/// `Pallet::accept_payment_offer`.
+	pub(crate) fn do_accept_payment_offer(who: T::AccountId, name: T::Name) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+		let payer = PaymentOffers::<T>::take(&name).ok_or(Error::<T>::OfferNotFound)?;
+		if let Some(previous) = Payers::<T>::mutate(&name, |p| p.replace(payer.clone())) {
+			Self::deposit_name_event(&name, Event::PaymentEnded(name.clone(), previous));
+		}
+
+		Self::deposit_name_event(&name, Event::PayerSet(name.clone(), payer));
+
+		Ok(())
+	}
+
+	/// End the payment of the renewal fees of "name" on behalf of "who", see `Pallet::end_payment`.
+	pub(crate) fn do_end_payment(who: T::AccountId, name: T::Name) -> DispatchResult {
+		let owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+		let payer = Self::payers(&name).ok_or(Error::<T>::NoPayer)?;
+		ensure!(who == owner.id || who == payer, Error::<T>::NotPaymentParty);
+		Payers::<T>::remove(&name);
+
+		Self::deposit_name_event(&name, Event::PaymentEnded(name.clone(), payer));
+
+		Ok(())
+	}
+
+	/// Freeze "name", see `Pallet::freeze_name`.
+	pub(crate) fn do_freeze_name(name: T::Name) -> DispatchResult {
+		Self::ensure_status(&name, &[NameStatus::Active, NameStatus::PendingTransfer])?;
+		Self::drop_scheduled_transfer(&name);
+		NameStatuses::<T>::insert(&name, NameStatus::Frozen);
+
+		Self::deposit_name_event(&name, Event::NameFrozen(name.clone()));
+
+		Ok(())
+	}
+
+	/// Unfreeze "name", see `Pallet::unfreeze_name`.
+	pub(crate) fn do_unfreeze_name(name: T::Name) -> DispatchResult {
+		Self::ensure_status(&name, &[NameStatus::Frozen])?;
+		let mut owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		owner.lock_period.end = owner.lock_period.end.max(now);
+		NameStatuses::<T>::insert(&name, NameStatus::Active);
+		Self::insert_owner(&name, owner);
+
+		Self::deposit_name_event(&name, Event::NameUnfrozen(name.clone()));
+
+		Ok(())
+	}
+
+	/// Cap every renewal fee at "ceiling", see `Pallet::set_renewal_fee_ceiling`.
+	pub(crate) fn do_set_renewal_fee_ceiling(ceiling: Option<BalanceOf<T>>) -> DispatchResult {
+		RenewalFeeCeiling::<T>::set(ceiling);
+
+		Self::deposit_event(Event::RenewalFeeCeilingSet(ceiling));
+
+		Ok(())
+	}
+
+	/// Propose to rekey "name", owned by "who", to "new_account", see `Pallet::rekey_owner`.
+	pub(crate) fn do_rekey_owner(
+		who: T::AccountId,
+		name: T::Name,
+		new_account: T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_acting_owner(&who, &name)?;
+		ensure!(new_account != who, Error::<T>::RekeyToSelf);
+		Self::ensure_status(
+			&name,
+			&[NameStatus::Active, NameStatus::PendingTransfer, NameStatus::GracePeriod],
+		)?;
+		ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+		ensure!(!NameShares::<T>::contains_key(&name), Error::<T>::Fractionalized);
+		PendingRekeys::<T>::insert(&name, &new_account);
+
+		Self::deposit_name_event(&name, Event::RekeyProposed(name.clone(), new_account));
+
+		Ok(())
+	}
+
+	/// Accept the rekey of "name" to "who", see `Pallet::accept_rekey`.
+	pub(crate) fn do_accept_rekey(who: T::AccountId, name: T::Name) -> DispatchResult {
+		ensure!(Self::pending_rekeys(&name).as_ref() == Some(&who), Error::<T>::RekeyNotFound);
+		Self::ensure_status(
+			&name,
+			&[NameStatus::Active, NameStatus::PendingTransfer, NameStatus::GracePeriod],
+		)?;
+		let owner = Self::owners(&name).ok_or(Error::<T>::NameNotFound)?;
+		let from = owner.id.clone();
+		let deposit = owner.deposit;
+		let held_by_owner = Self::deposit_holder(&name, &from) == from;
+		Self::rekey(&name, owner, &who);
+		if held_by_owner {
+			Self::sub_ownership_deposit(&from, deposit);
+			Self::add_ownership_deposit(&who, deposit);
+			ensure!(
+				Self::can_hold(&who, Self::held_deposits(&who)),
+				Error::<T>::InsufficientBalance
+			);
+			Self::release_held_fund(&from);
+			Self::update_locked_fund(&who)?;
+		}
+
+		Self::deposit_name_event(&name, Event::OwnerRekeyed(name.clone(), from, who));
+
+		Ok(())
+	}
+
+	/// Register "name" for "who" right away, see `Pallet::register`.
+	pub(crate) fn do_register(who: T::AccountId, name: T::Name) -> DispatchResult {
+		ensure!(Self::name_len(&name) > T::InstantRegistrationLen::get(), Error::<T>::NameTooShort);
+		let commit = Self::hash_of(who.clone(), name.clone());
+		let revealed = name;
+		let name = Self::check_claim(&who, &revealed)?;
+		ensure!(!Owners::<T>::contains_key(&name), Error::<T>::NameAlreadyRegistered);
+
+		let begin = <frame_system::Pallet<T>>::block_number();
+		let end = Self::period_end(begin, T::RegisterPeriod::get())?;
+		let owner = Owner {
+			id: who,
+			commit,
+			lock_period: LockPeriod { begin, end },
+			deposit: Self::ownership_deposit_of(&name),
+			acquisition: Acquisition::Register,
+			acquired_at: begin,
+			renewals: 0,
+		};
+		Self::grant(&name, revealed, owner, 1)
+	}
+}
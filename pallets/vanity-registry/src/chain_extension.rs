@@ -0,0 +1,60 @@
+//! A `pallet-contracts` chain extension through which ink! contracts use the registry, enabled
+//! with the `contracts` feature.
+//!
+//! Every function reads its SCALE encoded input from the contract's buffer:
+//! - `RESOLVE` takes a name and writes back the `Option<Resolution<AccountId>>` it resolves to, see
+//!   `Pallet::resolve`.
+//! - `IS_AVAILABLE` takes a name and writes back whether it is free, see `Pallet::is_available`.
+//! - `REGISTER_FOR` takes a name and registers it for the calling contract's own account, as
+//!   `Pallet::register` would for a signed origin. The contract pays the registration fee and holds
+//!   the deposit. A failed registration traps the contract with the error.
+
+use super::*;
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, RetVal, SysConfig, UncheckedFrom,
+};
+
+/// The id of the function resolving a name.
+pub const RESOLVE: u32 = 0x0001;
+/// The id of the function telling whether a name is available.
+pub const IS_AVAILABLE: u32 = 0x0002;
+/// The id of the function registering a name for the calling contract.
+pub const REGISTER_FOR: u32 = 0x0003;
+
+/// The chain extension to set as the `ChainExtension` of `pallet-contracts`.
+pub struct RegistryExtension;
+
+impl<T: Config + pallet_contracts::Config> ChainExtension<T> for RegistryExtension {
+	fn call<E>(func_id: u32, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+	where
+		E: Ext<T = T>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		let mut env = env.buf_in_buf_out();
+		match func_id {
+			RESOLVE => {
+				// The normalized name's `Owners` and `SquattingReports` entries.
+				env.charge_weight(T::DbWeight::get().reads(2))?;
+				let name: T::Name = env.read_as()?;
+				env.write(&Pallet::<T>::resolve(&name).encode(), false, None)?;
+			},
+			IS_AVAILABLE => {
+				// The normalized name's `NameBuckets` and `Owners` entries.
+				env.charge_weight(T::DbWeight::get().reads(2))?;
+				let name: T::Name = env.read_as()?;
+				env.write(&Pallet::<T>::is_available(&name).encode(), false, None)?;
+			},
+			REGISTER_FOR => {
+				let name: T::Name = env.read_as()?;
+				env.charge_weight(
+					T::WeightInfo::register(name.encode().len())
+						.saturating_add(Pallet::<T>::prefix_index_weight()),
+				)?;
+				let contract = env.ext().address().clone();
+				Pallet::<T>::register(frame_system::RawOrigin::Signed(contract).into(), name)?;
+			},
+			_ => return Err(DispatchError::Other("unknown chain extension function")),
+		}
+		Ok(RetVal::Converging(0))
+	}
+}
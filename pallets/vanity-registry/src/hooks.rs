@@ -0,0 +1,352 @@
+//! The work done in the hooks of the pallet, which are declared in the `pallet` module as the
+//! pallet macro requires.
+
+use super::*;
+use sp_runtime::offchain::storage::StorageValueRef;
+
+/// The maximum number of squatting reports the off-chain worker files per scan.
+const MAX_REPORTS_PER_SCAN: usize = 5;
+
+/// Names shorter than this are too short to be told apart from a clone reliably.
+const MIN_SQUAT_CHECK_LEN: usize = 3;
+
+/// The maximum number of names the off-chain worker checks for being clones per scan.
+const MAX_SUSPECTS_PER_SCAN: u32 = 50;
+
+/// The key of the off-chain storage holding the raw `Owners` key the next scan resumes after.
+const SQUAT_SCAN_CURSOR: &[u8] = b"vanity-registry::squat-scan-cursor";
+
+impl<T: Config> Pallet<T> {
+	/// Process the expiries queued up to block n, oldest first. At most `MaxExpiriesPerBlock`
+	/// expiries and scanned blocks are processed, the rest is carried over to the next block.
+	pub(crate) fn process_expiries(now: T::BlockNumber) {
+		let max = T::MaxExpiriesPerBlock::get() as usize;
+		let mut budget = max;
+		let mut processed = 0usize;
+		let mut cursor = Self::expiry_cursor();
+		while cursor <= now && budget > 0 {
+			budget -= 1;
+			let due: Vec<ExpiryOf<T>> = ExpiryQueue::<T>::iter_prefix(cursor)
+				.map(|(expiry, _)| expiry)
+				.take(budget)
+				.collect();
+			budget -= due.len();
+			processed += due.len();
+			for expiry in due {
+				ExpiryQueue::<T>::remove(cursor, &expiry);
+				Self::expire(expiry, now);
+			}
+			// Left over, or queued again while being processed, e.g. the cleanup after a reaped
+			// account. Either way it is processed within the budget or carried over.
+			if ExpiryQueue::<T>::iter_prefix(cursor).next().is_some() {
+				continue
+			}
+			cursor = cursor.saturating_add(One::one());
+		}
+		ExpiryCursor::<T>::put(cursor);
+
+		if processed > 0 {
+			log::debug!(
+				target: LOG_TARGET,
+				"processed {} expiries at block {:?}, {} of {} budget left",
+				processed,
+				now,
+				budget,
+				max,
+			);
+		}
+		if cursor <= now {
+			log::warn!(
+				target: LOG_TARGET,
+				"expiry budget of {} exhausted at block {:?}, carrying over from block {:?}",
+				max,
+				now,
+				cursor,
+			);
+		}
+	}
+
+	/// Remove the commit or free the name of "expiry" if its lock period is over by block n, or
+	/// execute the transfer of "expiry" if it is due. An active name is kept for the grace
+	/// period first, while a frozen one is kept until unfrozen. A reaped account is cleaned up
+	/// one commit or name at a time.
+	pub(crate) fn expire(expiry: ExpiryOf<T>, now: T::BlockNumber) {
+		match expiry {
+			Expiry::Commit(id, commit) => {
+				if !Self::lock_periods(&id, commit).map_or(false, |period| period.end <= now) {
+					return
+				}
+				Self::take_commit(&id, commit);
+				Self::release_held_fund(&id);
+				Self::deposit_event(Event::CommitExpired(commit, id));
+			},
+			Expiry::Name(name) => {
+				let owner = match Owners::<T>::get(&name) {
+					Some(owner) if owner.lock_period.end <= now => owner,
+					_ => return,
+				};
+				let grace = T::GracePeriod::get();
+				match Self::name_status(&name) {
+					// Kept until unfrozen, which queues the expiry again.
+					Some(NameStatus::Frozen) => return,
+					Some(NameStatus::GracePeriod) => {
+						if owner.lock_period.end.saturating_add(grace) > now {
+							return
+						}
+					},
+					// A disputed name is not kept for its owner.
+					Some(NameStatus::Active | NameStatus::PendingTransfer) if !grace.is_zero() => {
+						let end = owner.lock_period.end.saturating_add(grace);
+						Self::enter_grace_period(&name, end);
+						return
+					},
+					_ => {},
+				}
+				Self::take_owner(&name);
+				Self::release_held_fund(&owner.id);
+				Self::refund_deposit(&name, &owner.id, owner.deposit);
+				Self::deposit_freed_event(&name, FreeReason::Expired);
+			},
+			Expiry::Transfer(name) => {
+				let transfer = match ScheduledTransfers::<T>::get(&name) {
+					Some(transfer) if transfer.at <= now => transfer,
+					_ => return,
+				};
+				ScheduledTransfers::<T>::remove(&name);
+				let owner = match Owners::<T>::get(&name) {
+					Some(owner) => owner,
+					None => return,
+				};
+				if Self::name_status(&name) != Some(NameStatus::PendingTransfer) {
+					return
+				}
+				NameStatuses::<T>::insert(&name, NameStatus::Active);
+				let to = transfer.to;
+				let held =
+					Self::held_deposits(&to).saturating_add(Self::ownership_deposit_of(&name));
+				if !Self::can_hold(&to, held) {
+					log::debug!(
+						target: LOG_TARGET,
+						"scheduled transfer of {:?} failed, {:?} can not hold the deposit",
+						name,
+						to,
+					);
+					Self::deposit_name_event(&name, Event::TransferFailed(name.clone(), to));
+					return
+				}
+				let from = owner.id.clone();
+				let result = Self::hand_over(&name, owner, &to, Acquisition::Transfer);
+				if let Err(e) = &result {
+					log::error!(
+						target: LOG_TARGET,
+						"handing {:?} over to {:?} failed after checking its funds: {:?}",
+						name,
+						to,
+						e,
+					);
+				}
+				debug_assert!(result.is_ok(), "the recipient can hold the deposit; qed");
+				Self::deposit_name_event(&name, Event::NameTransferred(name.clone(), from, to));
+			},
+			Expiry::Account(who, after) =>
+				if let Some(next) = Self::reap_step(&who, after, now) {
+					Self::schedule_expiry(now, Expiry::Account(who, next));
+				},
+		}
+	}
+
+	/// Keep "name" for its owner until block "end" before freeing it, cancelling its scheduled
+	/// transfer.
+	fn enter_grace_period(name: &T::Name, end: T::BlockNumber) {
+		Self::drop_scheduled_transfer(name);
+		NameStatuses::<T>::insert(name, NameStatus::GracePeriod);
+		Self::schedule_expiry(end, Expiry::Name(name.clone()));
+		Self::deposit_name_event(name, Event::NameInGracePeriod(name.clone(), end));
+	}
+
+	/// Clean up after "who" once their account is reaped. Their commits are dropped, and the
+	/// lock period of their active names ends now so that they are freed after the grace period
+	/// unless renewed, rather than held by a dead account. Frozen and disputed names are left to
+	/// governance, while permanent names never lapse. The account may own any number of names,
+	/// so the cleanup is only queued here and done by `Pallet::process_expiries`, within its
+	/// budget.
+	pub(crate) fn reap_account(who: &T::AccountId) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		Self::schedule_expiry(now, Expiry::Account(who.clone(), None));
+	}
+
+	/// Drop a commit of "who", or else handle the name of "who" following "after", at block
+	/// "now". Returns the name to resume after, or None once the account is cleaned up.
+	fn reap_step(
+		who: &T::AccountId,
+		after: Option<T::Name>,
+		now: T::BlockNumber,
+	) -> Option<Option<T::Name>> {
+		if after.is_none() {
+			if let Some((commit, _)) = LockPeriods::<T>::iter_prefix(who).next() {
+				Self::take_commit(who, commit);
+				Self::release_held_fund(who);
+				Self::deposit_event(Event::CommitDropped(commit, who.clone()));
+				return Some(None)
+			}
+		}
+
+		let trie = Self::owner_trie(who);
+		let key = match &after {
+			Some(name) => OwnerTrieKey::<T::Hash, T::Name>::Name(name.clone()).encode(),
+			None => sp_std::vec![OWNER_TRIE_NAME_PREFIX],
+		};
+		let next = sp_io::default_child_storage::next_key(trie.storage_key(), &key)
+			.filter(|next| next.first() == Some(&OWNER_TRIE_NAME_PREFIX))?;
+		let name = match OwnerTrieKey::<T::Hash, T::Name>::decode(&mut &next[..]) {
+			Ok(OwnerTrieKey::Name(name)) => name,
+			_ => return None,
+		};
+		let eligible = !PermanentNames::<T>::contains_key(&name) &&
+			matches!(
+				Self::name_status(&name),
+				Some(NameStatus::Active | NameStatus::PendingTransfer)
+			);
+		if let Some(mut owner) = Owners::<T>::get(&name).filter(|_| eligible) {
+			owner.lock_period.end = owner.lock_period.end.min(now);
+			// This queues a plain expiry at the new end of the lock period.
+			Self::insert_owner(&name, owner);
+			let grace = T::GracePeriod::get();
+			if !grace.is_zero() {
+				Self::enter_grace_period(&name, now.saturating_add(grace));
+			}
+		}
+		Some(Some(name))
+	}
+
+	/// Free the names whose takedown can no longer be appealed at block n, returning the bonds
+	/// of the appeals which were not decided in time.
+	pub(crate) fn settle_takedowns(now: T::BlockNumber) {
+		for (name, _) in TakedownDeadlines::<T>::drain_prefix(now) {
+			if let Some(takedown) = Takedowns::<T>::take(&name) {
+				if let Some(bond) = takedown.appeal_bond {
+					T::Currency::unreserve_named(&T::ModuleId::get(), &takedown.owner.id, bond);
+				}
+				Self::deposit_name_event(&name, Event::TakedownSettled(name.clone()));
+			}
+		}
+	}
+
+	/// Find registered names which look like clones of names registered before them and report
+	/// them from the off-chain worker's account. Each scan checks the next
+	/// `MAX_SUSPECTS_PER_SCAN` names, resuming where the previous scan stopped, against all the
+	/// registered names.
+	pub(crate) fn report_squatting_candidates() {
+		let signer = Signer::<T, T::AuthorityId>::any_account();
+		if !signer.can_sign() {
+			log::debug!(target: LOG_TARGET, "no local key to file squatting reports with");
+			return
+		}
+
+		let mut cursor = StorageValueRef::persistent(SQUAT_SCAN_CURSOR);
+		let iter = match cursor.get::<Vec<u8>>().ok().flatten() {
+			Some(key) => Owners::<T>::iter_from(key),
+			None => Owners::<T>::iter(),
+		};
+		let page = Self::page(iter, MAX_SUSPECTS_PER_SCAN, |(name, owner)| {
+			(name, owner.lock_period.begin)
+		});
+		match &page.next {
+			Some(next) => cursor.set(next),
+			None => cursor.clear(),
+		}
+
+		let suspects: Vec<(T::Name, Vec<u8>, T::BlockNumber)> = page
+			.items
+			.into_iter()
+			.filter(|(name, _)| !SquattingReports::<T>::contains_key(name.clone()))
+			.map(|(name, begin)| {
+				let bytes = Self::name_bytes(&name);
+				(name, bytes, begin)
+			})
+			.filter(|(_, bytes, _)| bytes.len() >= MIN_SQUAT_CHECK_LEN)
+			.collect();
+		let mut originals: Vec<Option<T::Name>> = sp_std::vec![None; suspects.len()];
+		for (original, owner) in Owners::<T>::iter() {
+			let original_bytes = Self::name_bytes(&original);
+			for ((_, suspect_bytes, begin), found) in suspects.iter().zip(originals.iter_mut()) {
+				if found.is_none() &&
+					owner.lock_period.begin < *begin &&
+					Self::looks_alike(suspect_bytes, &original_bytes)
+				{
+					*found = Some(original.clone());
+				}
+			}
+			if originals.iter().all(Option::is_some) {
+				break
+			}
+		}
+		let candidates: Vec<(T::Name, T::Name)> = suspects
+			.into_iter()
+			.zip(originals)
+			.filter_map(|((suspect, _, _), original)| Some((suspect, original?)))
+			.take(MAX_REPORTS_PER_SCAN)
+			.collect();
+
+		for (suspect, original) in candidates {
+			let result = signer.send_signed_transaction(|_| Call::report_squatting {
+				suspect: suspect.clone(),
+				original: original.clone(),
+			});
+			match result {
+				Some((_, Ok(()))) => log::debug!(
+					target: LOG_TARGET,
+					"reported {:?} as a clone of {:?}",
+					suspect,
+					original,
+				),
+				_ => log::warn!(
+					target: LOG_TARGET,
+					"failed to report {:?} as a clone of {:?}",
+					suspect,
+					original,
+				),
+			}
+		}
+	}
+
+	/// Whether "a" is a homoglyph clone of "b" or only one typo away from it.
+	pub(crate) fn looks_alike(a: &[u8], b: &[u8]) -> bool {
+		let a = Self::skeleton(a);
+		let b = Self::skeleton(b);
+		if a == b {
+			return true
+		}
+		let (shorter, longer) = if a.len() <= b.len() { (&a, &b) } else { (&b, &a) };
+		match longer.len() - shorter.len() {
+			0 => shorter.iter().zip(longer.iter()).filter(|(x, y)| x != y).count() == 1,
+			1 => {
+				let prefix = shorter.iter().zip(longer.iter()).take_while(|(x, y)| x == y).count();
+				shorter[prefix..] == longer[prefix + 1..]
+			},
+			_ => false,
+		}
+	}
+
+	/// Reduce a name to a skeleton in which visually confusable characters are the same.
+	pub(crate) fn skeleton(name: &[u8]) -> Vec<u8> {
+		let mut skeleton = Vec::with_capacity(name.len());
+		let mut i = 0;
+		while i < name.len() {
+			if name[i..].starts_with(b"rn") {
+				skeleton.push(b'm');
+				i += 2;
+				continue
+			}
+			skeleton.push(match name[i].to_ascii_lowercase() {
+				b'0' => b'o',
+				b'1' | b'i' => b'l',
+				b'3' => b'e',
+				b'5' => b's',
+				c => c,
+			});
+			i += 1;
+		}
+		skeleton
+	}
+}
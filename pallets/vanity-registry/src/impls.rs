@@ -0,0 +1,736 @@
+//! The internals of the pallet, shared by its calls, hooks and queries.
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+	/// The child trie indexing the commits and names of "who", so a light client can prove them
+	/// against a single child root. It is a mirror only: `LockPeriods` and `Owners` remain the
+	/// source of truth and are what the pallet reads, while the records of a name stay in
+	/// `NameRoles` and are proven with `Pallet::records_key`. Keeping the mirror costs one more
+	/// child trie write per commit or ownership change, two when a name changes hands.
+	pub fn owner_trie(who: &T::AccountId) -> ChildInfo {
+		let mut buf = Vec::new();
+		buf.extend_from_slice(b"vanity_registry:owner");
+		buf.extend_from_slice(&who.encode());
+		ChildInfo::new_default(T::Hashing::hash(&buf[..]).as_ref())
+	}
+
+	/// The storage key of the `Owners` entry of "name" in any casing, with the pallet and storage
+	/// prefixes and the key hasher applied, so light clients and bridges can request a proof of
+	/// it without hashing the key themselves.
+	pub fn owner_key(name: &T::Name) -> Vec<u8> {
+		Owners::<T>::hashed_key_for(Self::normalize(name))
+	}
+
+	/// The storage key of the `LockPeriods` entry of the commit "hash" of "who", see
+	/// `Pallet::owner_key`.
+	pub fn lock_period_key(who: &T::AccountId, hash: &T::Hash) -> Vec<u8> {
+		LockPeriods::<T>::hashed_key_for(who, hash)
+	}
+
+	/// The storage key of the `NameRoles` entry holding the records of "name" in any casing, see
+	/// `Pallet::owner_key`.
+	pub fn records_key(name: &T::Name) -> Vec<u8> {
+		NameRoles::<T>::hashed_key_for(Self::normalize(name))
+	}
+
+	/// Store a commit of "who" along with the deposit it currently requires, and mirror it in their
+	/// child trie.
+	pub(crate) fn insert_commit(
+		who: &T::AccountId,
+		commit: T::Hash,
+		lock_period: LockPeriod<T::BlockNumber>,
+	) {
+		let key = OwnerTrieKey::<T::Hash, T::Name>::Commit(commit).encode();
+		child::put(&Self::owner_trie(who), &key, &lock_period);
+		CommitDeposits::<T>::insert(who, commit, T::FundToLock::get());
+		Self::schedule_expiry(lock_period.end, Expiry::Commit(who.clone(), commit));
+		LockPeriods::<T>::insert(who, commit, lock_period);
+	}
+
+	/// Remove a commit of "who" from both the maps and their child trie, along with the leaves
+	/// revealed if it is a batch commit.
+	pub(crate) fn take_commit(
+		who: &T::AccountId,
+		commit: T::Hash,
+	) -> Option<LockPeriod<T::BlockNumber>> {
+		let key = OwnerTrieKey::<T::Hash, T::Name>::Commit(commit).encode();
+		child::kill(&Self::owner_trie(who), &key);
+		CommitDeposits::<T>::remove(who, commit);
+		let _ = BatchReveals::<T>::remove_prefix((who.clone(), commit), None);
+		LockPeriods::<T>::take(who, commit)
+	}
+
+	/// Store the ownership of "name" and move its mirror to the child trie of the new owner.
+	/// The ownership deposits are accounted for, but the caller must update the held funds of both
+	/// the new and the returned previous owner. A change of owner unwinds any deposit delegation
+	/// and makes the name active.
+	pub(crate) fn insert_owner(name: &T::Name, owner: OwnerOf<T>) -> Option<OwnerOf<T>> {
+		let key = OwnerTrieKey::<T::Hash, T::Name>::Name(name.clone()).encode();
+		let previous = Owners::<T>::get(name);
+		match &previous {
+			Some(previous) if previous.id == owner.id => {
+				let holder = Self::deposit_holder(name, &owner.id);
+				Self::sub_ownership_deposit(&holder, previous.deposit);
+			},
+			Some(previous) => {
+				Self::release_ownership_deposit(name, previous);
+				Self::end_joint_ownership(name);
+				Self::end_fractional_ownership(name);
+				Self::cancel_scheduled_actions(name);
+				Self::refund_renewal_pool(name);
+				NameRoles::<T>::remove(name);
+				Guardians::<T>::remove(name);
+				Recoveries::<T>::remove(name);
+				Heirs::<T>::remove(name);
+				Self::drop_scheduled_transfer(name);
+				Gifts::<T>::remove(name);
+				PaymentOffers::<T>::remove(name);
+				Payers::<T>::remove(name);
+				PendingRekeys::<T>::remove(name);
+				child::kill(&Self::owner_trie(&previous.id), &key);
+				Self::activate(name);
+				NameHistories::<T>::mutate(name, |history| {
+					history.transfers = history.transfers.saturating_add(1)
+				});
+			},
+			None => NameStatuses::<T>::insert(name, NameStatus::Active),
+		}
+		Self::add_ownership_deposit(&Self::deposit_holder(name, &owner.id), owner.deposit);
+		child::put(&Self::owner_trie(&owner.id), &key, &owner);
+		if previous.is_none() {
+			NameHashIndex::<T>::insert(Self::name_hash(name), name);
+			NameBuckets::<T>::mutate(Self::name_bucket(name), |count| *count += 1);
+			#[cfg(feature = "prefix-index")]
+			Self::index_prefixes(name);
+		}
+		if !PermanentNames::<T>::contains_key(name) {
+			Self::schedule_expiry(owner.lock_period.end, Expiry::Name(name.clone()));
+		}
+		Owners::<T>::insert(name, owner);
+		previous
+	}
+
+	/// Move "name" from its "owner" to "to" as is, keeping its lock period and records, unlike
+	/// `Pallet::insert_owner`. Its scheduled actions were dispatched as the owner, so they are
+	/// cancelled along with any rekey it proposed. The caller must move the ownership deposit and
+	/// update the held funds of both.
+	pub(crate) fn move_owner(name: &T::Name, owner: OwnerOf<T>, to: &T::AccountId) {
+		Self::cancel_scheduled_actions(name);
+		Self::relocate_owner(name, owner, to);
+	}
+
+	/// Move "name" from its "owner" to "to", the new key of the same holder, as
+	/// `Pallet::move_owner` does, but keep the actions scheduled on it. They are dispatched on
+	/// behalf of "to" from now on.
+	pub(crate) fn rekey(name: &T::Name, owner: OwnerOf<T>, to: &T::AccountId) {
+		Self::reschedule_actions(name, to);
+		Self::relocate_owner(name, owner, to);
+	}
+
+	/// Store "owner" of "name" as owned by "to" and move its mirror to the child trie of "to".
+	fn relocate_owner(name: &T::Name, mut owner: OwnerOf<T>, to: &T::AccountId) {
+		let key = OwnerTrieKey::<T::Hash, T::Name>::Name(name.clone()).encode();
+		child::kill(&Self::owner_trie(&owner.id), &key);
+		PendingRekeys::<T>::remove(name);
+		owner.id = to.clone();
+		child::put(&Self::owner_trie(to), &key, &owner);
+		Owners::<T>::insert(name, owner);
+		Self::deposit_name_event(name, Event::NameOwned(name.clone(), to.clone()));
+	}
+
+	/// Remove the ownership of "name" from both the map and the owner's child trie, unwinding any
+	/// deposit delegation. The caller must update the held funds of the returned owner.
+	pub(crate) fn take_owner(name: &T::Name) -> Option<OwnerOf<T>> {
+		let owner = Owners::<T>::take(name)?;
+		let key = OwnerTrieKey::<T::Hash, T::Name>::Name(name.clone()).encode();
+		child::kill(&Self::owner_trie(&owner.id), &key);
+		NameHashIndex::<T>::remove(Self::name_hash(name));
+		NameBuckets::<T>::mutate_exists(Self::name_bucket(name), |count| {
+			*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+		});
+		NameCasings::<T>::remove(name);
+		#[cfg(feature = "prefix-index")]
+		Self::unindex_prefixes(name);
+		Self::release_ownership_deposit(name, &owner);
+		Self::end_joint_ownership(name);
+		Self::end_fractional_ownership(name);
+		Self::cancel_scheduled_actions(name);
+		Self::refund_renewal_pool(name);
+		NameRoles::<T>::remove(name);
+		Guardians::<T>::remove(name);
+		Recoveries::<T>::remove(name);
+		Heirs::<T>::remove(name);
+		Self::drop_scheduled_transfer(name);
+		Gifts::<T>::remove(name);
+		PaymentOffers::<T>::remove(name);
+		Payers::<T>::remove(name);
+		PendingRekeys::<T>::remove(name);
+		PermanentNames::<T>::remove(name);
+		NameStatuses::<T>::remove(name);
+		NameHistories::<T>::remove(name);
+		Some(owner)
+	}
+
+	/// Ensure "name" is in one of the "allowed" statuses, failing with the error of its status.
+	pub(crate) fn ensure_status(name: &T::Name, allowed: &[NameStatus]) -> DispatchResult {
+		let status = Self::name_status(name).ok_or(Error::<T>::NameNotFound)?;
+		if allowed.contains(&status) {
+			return Ok(())
+		}
+		Err(match status {
+			NameStatus::Active => Error::<T>::NameActive,
+			NameStatus::GracePeriod => Error::<T>::NameInGracePeriod,
+			NameStatus::Disputed => Error::<T>::NameDisputed,
+			NameStatus::Frozen => Error::<T>::NameFrozen,
+			NameStatus::PendingTransfer => Error::<T>::TransferPending,
+		}
+		.into())
+	}
+
+	/// The block from which "name", registered until "end", is freed once its expiry is processed,
+	/// past any grace period, or None if it never lapses as it is permanent or frozen.
+	pub(crate) fn lapses_at(name: &T::Name, end: T::BlockNumber) -> Option<T::BlockNumber> {
+		match Self::name_status(name) {
+			_ if PermanentNames::<T>::contains_key(name) => None,
+			Some(NameStatus::Frozen) => None,
+			// A disputed name is not kept for the grace period.
+			Some(NameStatus::Disputed) => Some(end),
+			_ => Some(end.saturating_add(T::GracePeriod::get())),
+		}
+	}
+
+	/// Make "name" active, announcing that it left its grace period if it was in one.
+	pub(crate) fn activate(name: &T::Name) {
+		let previous = NameStatuses::<T>::mutate(name, |status| status.replace(NameStatus::Active));
+		if previous == Some(NameStatus::GracePeriod) {
+			Self::deposit_name_event(name, Event::NameLeftGracePeriod(name.clone()));
+		}
+	}
+
+	/// Drop the scheduled transfer of "name", if any, announcing that it was cancelled.
+	pub(crate) fn drop_scheduled_transfer(name: &T::Name) {
+		if ScheduledTransfers::<T>::take(name).is_some() {
+			Self::deposit_name_event(name, Event::TransferCancelled(name.clone()));
+		}
+	}
+
+	/// The ownership deposit held for "name" from whoever acquires it: the `OwnershipDeposit`
+	/// plus the `NameByteDeposit` for each byte of its encoding, none if it is permanent.
+	pub fn ownership_deposit_of(name: &T::Name) -> BalanceOf<T> {
+		if PermanentNames::<T>::contains_key(name) {
+			return Zero::zero()
+		}
+		let bytes = BalanceOf::<T>::from(name.encoded_size() as u32);
+		T::OwnershipDeposit::get().saturating_add(T::NameByteDeposit::get().saturating_mul(bytes))
+	}
+
+	/// The account from which the ownership deposit of "name" owned by "owner" is held.
+	pub(crate) fn deposit_holder(name: &T::Name, owner: &T::AccountId) -> T::AccountId {
+		Self::deposit_delegations(name).unwrap_or_else(|| owner.clone())
+	}
+
+	/// Release the ownership deposit of "name" from whoever holds it, ending any delegation along
+	/// with the pending offer. The caller must update the held funds of "owner".
+	pub(crate) fn release_ownership_deposit(name: &T::Name, owner: &OwnerOf<T>) {
+		DepositOffers::<T>::remove(name);
+		match DepositDelegations::<T>::take(name) {
+			Some(depositor) => {
+				Self::sub_ownership_deposit(&depositor, owner.deposit);
+				Self::release_held_fund(&depositor);
+				Self::deposit_name_event(
+					name,
+					Event::DepositDelegationEnded(name.clone(), depositor),
+				);
+			},
+			None => Self::sub_ownership_deposit(&owner.id, owner.deposit),
+		}
+	}
+
+	/// Add "deposit" to the total ownership deposit held from "who".
+	pub(crate) fn add_ownership_deposit(who: &T::AccountId, deposit: BalanceOf<T>) {
+		OwnershipDeposits::<T>::mutate(who, |total| *total = total.saturating_add(deposit));
+	}
+
+	/// Subtract "deposit" from the total ownership deposit held from "who", dropping the entry when
+	/// zero.
+	pub(crate) fn sub_ownership_deposit(who: &T::AccountId, deposit: BalanceOf<T>) {
+		OwnershipDeposits::<T>::mutate_exists(who, |total| {
+			let remaining = total.unwrap_or_else(Zero::zero).saturating_sub(deposit);
+			*total = if remaining.is_zero() { None } else { Some(remaining) };
+		});
+	}
+
+	/// Hand "amount" of the released ownership deposit of "name" over from its former owner "who"
+	/// to the beneficiary set for it, if any. The funds must have been released beforehand.
+	pub(crate) fn refund_deposit(name: &T::Name, who: &T::AccountId, amount: BalanceOf<T>) {
+		let beneficiary = match DepositBeneficiaries::<T>::take(name) {
+			Some(beneficiary) if beneficiary != *who => beneficiary,
+			_ => return,
+		};
+		let transferred =
+			T::Currency::transfer(who, &beneficiary, amount, ExistenceRequirement::AllowDeath);
+		match transferred {
+			Ok(()) => Self::deposit_name_event(
+				name,
+				Event::DepositRefunded(name.clone(), beneficiary, amount),
+			),
+			Err(e) => log::warn!(
+				target: LOG_TARGET,
+				"refunding the deposit of {:?} to {:?} failed: {:?}",
+				name,
+				beneficiary,
+				e,
+			),
+		}
+	}
+
+	/// The bucket "name" is counted in, taken from its hash, see `Pallet::name_hash`.
+	pub fn name_bucket(name: &T::Name) -> u16 {
+		let hash = Self::name_hash(name);
+		let bytes = hash.as_ref();
+		u16::from_le_bytes([bytes[0], bytes[1]])
+	}
+
+	/// Set lock (or reserve) according to the deposits recorded for the commits that are
+	/// associated with an id, plus the deposits of the names it owns. Remove the lock if neither.
+	/// This can only fail when the amount to hold increases.
+	pub(crate) fn update_locked_fund(id: &T::AccountId) -> DispatchResult {
+		let amount = Self::held_deposits(id);
+		let backend = Self::backend_of(id);
+		log::debug!(target: LOG_TARGET, "holding {:?} from {:?} with {:?}", amount, id, backend);
+		match backend {
+			DepositBackend::Lock => Self::set_locked(id, amount),
+			DepositBackend::Reserve => Self::set_reserved(id, amount).map_err(|e| {
+				log::debug!(target: LOG_TARGET, "reserving {:?} from {:?} failed", amount, id);
+				e
+			})?,
+		}
+		Ok(())
+	}
+
+	/// Hold the deposits of "id" after some of them have been released, or with a lock, where
+	/// there is no caller to fail. Only increasing a reserve can fail.
+	pub(crate) fn release_held_fund(id: &T::AccountId) {
+		let result = Self::update_locked_fund(id);
+		if let Err(e) = &result {
+			log::error!(target: LOG_TARGET, "releasing the funds of {:?} failed: {:?}", id, e);
+		}
+		debug_assert!(result.is_ok(), "holding fewer deposits can not fail; qed");
+	}
+
+	/// The total of the commit and ownership deposits to be held from "id".
+	pub(crate) fn held_deposits(id: &T::AccountId) -> BalanceOf<T> {
+		CommitDeposits::<T>::iter_prefix_values(id)
+			.fold(Self::ownership_deposits(id), |total, deposit| total.saturating_add(deposit))
+	}
+
+	/// The backend holding the deposits of "id", a lock if it backs them with its locked balance.
+	pub(crate) fn backend_of(id: &T::AccountId) -> DepositBackend {
+		if LockBackedAccounts::<T>::contains_key(id) {
+			DepositBackend::Lock
+		} else {
+			Self::active_backend()
+		}
+	}
+
+	/// Whether the funds of "id" cover holding "amount" with its backend. Only the balance locked
+	/// by other pallets counts for an account backing its deposits with it.
+	pub(crate) fn can_hold(id: &T::AccountId, amount: BalanceOf<T>) -> bool {
+		if LockBackedAccounts::<T>::contains_key(id) {
+			return T::LockedBalances::locked_balance(id) >= amount
+		}
+		let available = match Self::active_backend() {
+			DepositBackend::Lock => T::Currency::free_balance(id),
+			DepositBackend::Reserve =>
+				T::Currency::free_balance(id).saturating_add(Self::reserved_deposits(id)),
+		};
+		available >= amount
+	}
+
+	/// Lock exactly "amount" of the funds of "id", removing the lock when zero.
+	pub(crate) fn set_locked(id: &T::AccountId, amount: BalanceOf<T>) {
+		if amount.is_zero() {
+			T::Currency::remove_lock(T::ModuleId::get(), id);
+		} else {
+			T::Currency::set_lock(T::ModuleId::get(), id, amount, T::LockReasons::get());
+		}
+	}
+
+	/// Reserve exactly "amount" of the funds of "id" under the pallet's named reserve.
+	pub(crate) fn set_reserved(id: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		let current = Self::reserved_deposits(id);
+		if amount > current {
+			T::Currency::reserve_named(&T::ModuleId::get(), id, amount - current)?;
+		} else if amount < current {
+			T::Currency::unreserve_named(&T::ModuleId::get(), id, current - amount);
+		}
+		if amount.is_zero() {
+			ReservedDeposits::<T>::remove(id);
+		} else {
+			ReservedDeposits::<T>::insert(id, amount);
+		}
+		Ok(())
+	}
+
+	/// The block at which a period of "length" blocks starting at "begin" ends.
+	pub(crate) fn period_end(
+		begin: T::BlockNumber,
+		length: T::BlockNumber,
+	) -> Result<T::BlockNumber, DispatchError> {
+		Ok(begin.checked_add(&length).ok_or(Error::<T>::ArithmeticOverflow)?)
+	}
+
+	/// Queue "expiry" to be processed at block "end", or at the cursor if it is already past.
+	pub(crate) fn schedule_expiry(end: T::BlockNumber, expiry: ExpiryOf<T>) {
+		ExpiryQueue::<T>::insert(end.max(Self::expiry_cursor()), expiry, ());
+	}
+
+	/// Convert a fee given in reference units into the chain's currency, preferring the price feed
+	/// over the fallback price.
+	pub(crate) fn fee_in_currency(fee: u32) -> Result<BalanceOf<T>, DispatchError> {
+		if fee.is_zero() {
+			return Ok(Zero::zero())
+		}
+		let price = T::PriceFeed::price()
+			.or_else(Self::fallback_price)
+			.ok_or(Error::<T>::PriceUnavailable)?;
+		Ok(price.saturating_mul(fee.into()))
+	}
+
+	/// The fee of registering a name for "periods" register periods at once in the chain's
+	/// currency, discounted as set by `Config::MultiPeriodDiscounts`.
+	pub(crate) fn registration_fee(periods: u32) -> Result<BalanceOf<T>, DispatchError> {
+		let fee = Self::fee_in_currency(T::RegistrationFee::get())?.saturating_mul(periods.into());
+		let discount = T::MultiPeriodDiscounts::get()
+			.into_iter()
+			.filter(|(milestone, _)| *milestone <= periods)
+			.map(|(_, discount)| discount)
+			.max()
+			.unwrap_or_else(Perbill::zero);
+		Ok(fee.saturating_sub(discount * fee))
+	}
+
+	/// The renewal fee of the name held by "owner" in the chain's currency, discounted by the
+	/// tenure of "owner", see `Config::TenureDiscount`, and capped by `RenewalFeeCeiling`.
+	pub(crate) fn renewal_fee(owner: &OwnerOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+		let fee = Self::fee_in_currency(T::RenewalFee::get())?;
+		let discount = T::TenureDiscount::discount(Self::tenure(owner));
+		let fee = fee.saturating_sub(discount * fee);
+		Ok(Self::renewal_fee_ceiling().map_or(fee, |ceiling| fee.min(ceiling)))
+	}
+
+	/// Withdraw "amount" from "who" as a fee and hand it over to the fee handler.
+	pub(crate) fn charge_fee(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		let imbalance = Self::withdraw_amount(who, amount)?;
+		T::FeeHandler::on_unbalanced(imbalance);
+		Ok(())
+	}
+
+	/// Withdraw "amount" from "who" as a fee, keeping the account alive.
+	pub(crate) fn withdraw_amount(
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<NegativeImbalanceOf<T>, DispatchError> {
+		if amount.is_zero() {
+			return Ok(NegativeImbalanceOf::<T>::zero())
+		}
+		let remaining = T::Currency::free_balance(who)
+			.checked_sub(&amount)
+			.ok_or(Error::<T>::InsufficientBalance)?;
+		ensure!(remaining >= T::Currency::minimum_balance(), Error::<T>::BelowExistentialDeposit);
+		let imbalance = T::Currency::withdraw(
+			who,
+			amount,
+			WithdrawReasons::FEE,
+			ExistenceRequirement::KeepAlive,
+		)?;
+		Self::deposit_event(Event::FeeCharged(who.clone(), amount));
+		Ok(imbalance)
+	}
+
+	/// Slash "amount" of the reserved funds of "who" to the fee handler. If that would leave the
+	/// account below the existential deposit, the dust is slashed to the fee handler as well
+	/// instead of being left to the currency's dust removal.
+	pub(crate) fn slash_reserved(who: &T::AccountId, amount: BalanceOf<T>) {
+		let mut imbalance = NegativeImbalanceOf::<T>::zero();
+		let remaining = T::Currency::total_balance(who).saturating_sub(amount);
+		if !remaining.is_zero() && remaining < T::Currency::minimum_balance() {
+			let (dust, _) = T::Currency::slash(who, remaining);
+			imbalance.subsume(dust);
+		}
+		let (slashed, _) = T::Currency::slash_reserved_named(&T::ModuleId::get(), who, amount);
+		imbalance.subsume(slashed);
+		T::FeeHandler::on_unbalanced(imbalance);
+	}
+
+	/// The canonical fixed-size identifier of "name", i.e. the blake2_256 hash of the name's bytes.
+	/// All events concerning the name are indexed under it as a topic.
+	pub fn name_hash(name: &T::Name) -> T::Hash {
+		let hash = sp_io::hashing::blake2_256(&Self::name_bytes(name));
+		T::Hash::decode(&mut TrailingZeroInput::new(&hash[..]))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	/// Deposit an event concerning "name", indexed by the name's hash.
+	pub(crate) fn deposit_name_event(name: &T::Name, event: Event<T>) {
+		let event = <T as Config>::Event::from(event);
+		<frame_system::Pallet<T>>::deposit_event_indexed(&[Self::name_hash(name)], event.into());
+	}
+
+	/// Announce that "name" has been freed for "reason", notifying its watchers in particular.
+	/// Their deposits are released and, unless `WatchPriorityPeriod` is zero, they are given
+	/// priority on it.
+	pub(crate) fn deposit_freed_event(name: &T::Name, reason: FreeReason) {
+		Self::deposit_name_event(name, Event::NameFreed(name.clone(), reason));
+
+		let hash = Self::name_hash(name);
+		let watchers = Watchers::<T>::take(hash);
+		if watchers.is_empty() {
+			return
+		}
+		let mut ids = Vec::with_capacity(watchers.len());
+		for (watcher, deposit) in watchers {
+			T::Currency::unreserve_named(&T::ModuleId::get(), &watcher, deposit);
+			let topics = [hash, T::Hashing::hash_of(&watcher)];
+			let event = Event::WatchedNameAvailable(hash, watcher.clone());
+			let event = <T as Config>::Event::from(event);
+			<frame_system::Pallet<T>>::deposit_event_indexed(&topics, event.into());
+			ids.push(watcher);
+		}
+		let period = T::WatchPriorityPeriod::get();
+		if !period.is_zero() {
+			let ends_at = <frame_system::Pallet<T>>::block_number().saturating_add(period);
+			let watchers = BoundedVec::try_from(ids).expect("as many as the watchers taken; qed");
+			PriorityWindows::<T>::insert(hash, PriorityWindow { watchers, ends_at });
+		}
+	}
+
+	/// Check that "who" may claim "name" with a commit made at block "committed_at", given the
+	/// priority of its watchers if it has just been freed. Commits of others made before the
+	/// priority window ends never win the name, so the window is dropped once they have expired.
+	pub(crate) fn check_priority(
+		name: &T::Name,
+		who: &T::AccountId,
+		committed_at: T::BlockNumber,
+	) -> DispatchResult {
+		let hash = Self::name_hash(name);
+		let window = match PriorityWindows::<T>::get(hash) {
+			Some(window) => window,
+			None => return Ok(()),
+		};
+		let now = <frame_system::Pallet<T>>::block_number();
+		if window.ends_at.saturating_add(T::RegisterPeriod::get()) <= now {
+			PriorityWindows::<T>::remove(hash);
+			return Ok(())
+		}
+		ensure!(
+			committed_at >= window.ends_at || window.watchers.contains(who),
+			Error::<T>::ReservedForWatchers
+		);
+		Ok(())
+	}
+
+	/// The length of "name" in unicode scalar values, or in bytes if it is not valid UTF-8.
+	pub fn name_len(name: &T::Name) -> u32 {
+		let bytes = Self::name_bytes(name);
+		let len = match sp_std::str::from_utf8(&bytes) {
+			Ok(text) => text.chars().count(),
+			Err(_) => bytes.len(),
+		};
+		len as u32
+	}
+
+	/// "name" as text for display.
+	pub fn display_name(name: &T::Name) -> DisplayName {
+		DisplayName(Self::name_bytes(name))
+	}
+
+	/// The registered "name" in the casing it was revealed in.
+	pub fn revealed_name(name: &T::Name) -> T::Name {
+		Self::name_casings(name).unwrap_or_else(|| name.clone())
+	}
+
+	/// The lowercase form of "name" under which it is registered, so names differing only in
+	/// casing can't be told apart from each other. Names which are not valid UTF-8 are only
+	/// lowercased in their ASCII bytes.
+	pub fn normalize(name: &T::Name) -> T::Name {
+		let lowercase = vanity_name::lowercase(&Self::name_bytes(name));
+		T::Name::decode(&mut lowercase.encode().as_slice()).unwrap_or_else(|_| name.clone())
+	}
+
+	/// The prefixes of "name" indexed in `NamePrefixes`.
+	#[cfg(feature = "prefix-index")]
+	pub(crate) fn indexed_prefixes(name: &T::Name) -> impl Iterator<Item = PrefixOf<T>> {
+		let bytes = Self::name_bytes(name);
+		let len = bytes.len().min(T::MaxPrefixLen::get() as usize);
+		(1..=len).filter_map(move |end| PrefixOf::<T>::try_from(bytes[..end].to_vec()).ok())
+	}
+
+	/// Index "name" under each of its prefixes which has room left.
+	#[cfg(feature = "prefix-index")]
+	pub(crate) fn index_prefixes(name: &T::Name) {
+		for prefix in Self::indexed_prefixes(name) {
+			NamePrefixes::<T>::mutate(prefix, |names| {
+				let _ = names.try_push(name.clone());
+			});
+		}
+	}
+
+	/// Remove "name" from the prefixes it is indexed under.
+	#[cfg(feature = "prefix-index")]
+	pub(crate) fn unindex_prefixes(name: &T::Name) {
+		for prefix in Self::indexed_prefixes(name) {
+			NamePrefixes::<T>::mutate_exists(prefix, |names| {
+				if let Some(indexed) = names {
+					indexed.retain(|indexed| indexed != name);
+					if indexed.is_empty() {
+						*names = None;
+					}
+				}
+			});
+		}
+	}
+
+	/// The extra weight of maintaining `NamePrefixes` when registering or releasing a name.
+	pub(crate) fn prefix_index_weight() -> Weight {
+		if cfg!(feature = "prefix-index") {
+			let prefixes = T::MaxPrefixLen::get() as Weight;
+			T::DbWeight::get().reads_writes(prefixes, prefixes)
+		} else {
+			0
+		}
+	}
+
+	/// The extra weight of drawing from or refunding the renewal pool of a name.
+	pub(crate) fn renewal_pool_weight() -> Weight {
+		// The pool itself, along with the reserves and the account of each contributor.
+		let items = (T::MaxPoolContributors::get() as Weight).saturating_mul(2).saturating_add(1);
+		T::DbWeight::get().reads_writes(items, items)
+	}
+
+	/// The raw bytes of "name", assuming it is encoded the same as a `Vec<u8>`.
+	pub(crate) fn name_bytes(name: &T::Name) -> Vec<u8> {
+		let encoded = name.encode();
+		Vec::<u8>::decode(&mut encoded.as_slice()).unwrap_or(encoded)
+	}
+
+	/// The domain every commitment preimage starts with, i.e. `COMMIT_DOMAIN`, a slash and the
+	/// tag of `Config::CommitHashing`, e.g. `vanity-registry/commit/blake2-256`.
+	pub fn commit_domain() -> Vec<u8> {
+		vanity_registry_primitives::commit_domain(Self::commit_hashing_tag())
+	}
+
+	/// Calculate the commit for "name" from "id" which is the `Config::CommitHashing` hash of
+	/// the commit domain, `COMMIT_VERSION`, "id" and "name" concatenated, see
+	/// `vanity_registry_primitives::commit_preimage`.
+	pub fn hash_of(id: T::AccountId, name: T::Name) -> T::Hash {
+		let tag = Self::commit_hashing_tag();
+		T::CommitHashing::hash(&vanity_registry_primitives::commit_preimage(tag, &id, &name))
+	}
+
+	/// Calculate the salted commit for "name" from "id" which is the `Config::CommitHashing`
+	/// hash of the commit domain, `SALTED_COMMIT_VERSION`, "id", "name" and "salt" concatenated,
+	/// see `vanity_registry_primitives::salted_commit_preimage`. Commits under distinct salts
+	/// let an account hold several commits to the same name.
+	pub fn salted_hash_of(id: T::AccountId, name: T::Name, salt: T::Hash) -> T::Hash {
+		let tag = Self::commit_hashing_tag();
+		let preimage = vanity_registry_primitives::salted_commit_preimage(tag, &id, &name, &salt);
+		T::CommitHashing::hash(&preimage)
+	}
+
+	/// The tag of `Config::CommitHashing`, part of every commitment preimage.
+	fn commit_hashing_tag() -> &'static [u8] {
+		<T::CommitHashing as CommitHashing<T::Hash>>::TAG
+	}
+
+	/// The root of the merkle tree holding "leaf", given the hashes of its siblings from the leaf
+	/// up. Every pair of nodes is hashed in ascending order with `Config::CommitHashing`, so the
+	/// proof needs no positions.
+	pub fn merkle_root(leaf: T::Hash, proof: &[T::Hash]) -> T::Hash {
+		proof.iter().fold(leaf, |node, sibling| {
+			let pair = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+			T::CommitHashing::hash(&pair.encode())
+		})
+	}
+
+	/// Whether the "challenger" commit to "name" made at "begin" takes precedence over the
+	/// "holder" commit made at the same block. Each commit draws the hash of itself and
+	/// `Config::Randomness` for the name and block, and the lower draw wins. The outcome does
+	/// not depend on which commit is revealed first, so block producers can not pick the winner
+	/// by ordering the reveals.
+	pub(crate) fn wins_tie(
+		name: &T::Name,
+		begin: T::BlockNumber,
+		challenger: T::Hash,
+		holder: T::Hash,
+	) -> bool {
+		let subject = (TIE_BREAK_SUBJECT, name, begin).encode();
+		let (seed, _) = T::Randomness::random(&subject);
+		let draw = |commit: T::Hash| T::Hashing::hash_of(&(seed, commit));
+		draw(challenger) < draw(holder)
+	}
+
+	/// The oldest pending commit of "who" to "name", either unsalted or under one of "salts", so
+	/// a reveal claims the name with the priority of the earliest commit.
+	pub(crate) fn oldest_commit(
+		who: &T::AccountId,
+		name: &T::Name,
+		salts: &[T::Hash],
+	) -> Option<T::Hash> {
+		let salted =
+			salts.iter().map(|salt| Self::salted_hash_of(who.clone(), name.clone(), *salt));
+		sp_std::iter::once(Self::hash_of(who.clone(), name.clone()))
+			.chain(salted)
+			.filter_map(|commit| Some((LockPeriods::<T>::get(who, commit)?.begin, commit)))
+			.min_by_key(|(begin, _)| *begin)
+			.map(|(_, commit)| commit)
+	}
+}
+
+impl<T: Config> NameProvider<T::AccountId, T::Name> for Pallet<T> {
+	fn owner_of(name: &T::Name) -> Option<T::AccountId> {
+		Pallet::<T>::owner_of(name)
+	}
+
+	fn resolve(name: &T::Name) -> Option<Resolution<T::AccountId>> {
+		Pallet::<T>::resolve(name)
+	}
+
+	fn names_of(who: &T::AccountId) -> Vec<T::Name> {
+		Pallet::<T>::names_of(who)
+	}
+
+	fn role_of(name: &T::Name, role: &[u8]) -> Option<T::AccountId> {
+		Pallet::<T>::role_of(name, role)
+	}
+}
+
+impl<T: Config> NamePricer<BalanceOf<T>> for Pallet<T> {
+	fn quote() -> Option<Quote<BalanceOf<T>>> {
+		Pallet::<T>::quote()
+	}
+}
+
+impl<T: Config> NameValidator<T::AccountId, T::Name> for Pallet<T> {
+	fn validate(who: &T::AccountId, name: &T::Name) -> Result<T::Name, DispatchError> {
+		let name_len = Self::name_len(name);
+		ensure!(name_len <= T::NameMaxLen::get(), Error::<T>::NameTooLong);
+		ensure!(
+			name_len >= T::ShortNameLen::get() || T::IdentityVerifier::has_positive_judgement(who),
+			Error::<T>::IdentityRequired
+		);
+
+		let normalized = Self::normalize(name);
+		ensure!(!Takedowns::<T>::contains_key(&normalized), Error::<T>::NameTakenDown);
+		ensure!(!VoucherReservations::<T>::contains_key(&normalized), Error::<T>::NameReserved);
+		ensure!(T::NamePolicy::is_allowed(&normalized), Error::<T>::NameNotAllowed);
+		Ok(normalized)
+	}
+}
+
+impl<T: Config> OnKilledAccount<T::AccountId> for Pallet<T> {
+	fn on_killed_account(who: &T::AccountId) {
+		Pallet::<T>::reap_account(who)
+	}
+}
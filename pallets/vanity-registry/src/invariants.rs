@@ -0,0 +1,102 @@
+//! Invariants of the registry state, checked by `Migrate::post_upgrade` with `try-runtime` and at
+//! the end of every block with `defensive-checks`. Both walk the whole state, so they are meant
+//! for test networks and tooling rather than production runtimes.
+
+use super::*;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+impl<T: Config> Pallet<T> {
+	/// Check the invariants of the registry state, returning the first one found broken.
+	pub fn check_invariants() -> Result<(), &'static str> {
+		Self::check_expiries()?;
+		Self::check_deposits()
+	}
+
+	/// Check the invariants at the end of a block. A broken one is logged rather than halting the
+	/// chain, except in debug builds.
+	#[cfg(feature = "defensive-checks")]
+	pub(crate) fn defensive_check_invariants() {
+		if let Err(e) = Self::check_invariants() {
+			log::error!(target: LOG_TARGET, "broken invariant: {}", e);
+			debug_assert!(false, "broken invariant: {}", e);
+		}
+	}
+
+	/// No expiry is queued behind the cursor, where it would never be processed, and every
+	/// commit, expiring name and scheduled transfer has its expiry queued no earlier than due.
+	/// Every registered name has a status, and only registered names have a status or a history.
+	fn check_expiries() -> Result<(), &'static str> {
+		let cursor = Self::expiry_cursor();
+		let mut queued: BTreeMap<Vec<u8>, T::BlockNumber> = BTreeMap::new();
+		for (at, expiry, ()) in ExpiryQueue::<T>::iter() {
+			ensure!(at >= cursor, "an expiry is queued behind the expiry cursor");
+			let latest = queued.entry(expiry.encode()).or_insert(at);
+			*latest = at.max(*latest);
+		}
+		let is_queued = |expiry: ExpiryOf<T>, due: T::BlockNumber| {
+			queued.get(&expiry.encode()).map_or(false, |at| *at >= due)
+		};
+
+		for (id, commit, period) in LockPeriods::<T>::iter() {
+			ensure!(is_queued(Expiry::Commit(id, commit), period.end), "a commit never expires");
+		}
+		for ((id, root), _) in BatchReveals::<T>::iter_keys() {
+			ensure!(LockPeriods::<T>::contains_key(&id, root), "an expired batch has reveals left");
+		}
+		for (name, owner) in Owners::<T>::iter() {
+			let status = Self::name_status(&name).ok_or("a name has no status")?;
+			// Frozen names are queued again once unfrozen.
+			if status != NameStatus::Frozen && !PermanentNames::<T>::contains_key(&name) {
+				let due = owner.lock_period.end;
+				ensure!(is_queued(Expiry::Name(name), due), "a name never expires");
+			}
+		}
+		for name in NameStatuses::<T>::iter_keys() {
+			ensure!(Owners::<T>::contains_key(&name), "a freed name has a status left behind");
+		}
+		for name in NameHistories::<T>::iter_keys() {
+			ensure!(Owners::<T>::contains_key(&name), "a freed name has a history left behind");
+		}
+		for (name, transfer) in ScheduledTransfers::<T>::iter() {
+			ensure!(is_queued(Expiry::Transfer(name), transfer.at), "a transfer is never executed");
+		}
+		Ok(())
+	}
+
+	/// The ownership deposits recorded for every account add up to those of the names it holds
+	/// them for, and the reserve of every account holding its deposits with one matches its commit
+	/// and ownership deposits. Locks can not be read back through `Currency`, so they go
+	/// unchecked.
+	fn check_deposits() -> Result<(), &'static str> {
+		let mut expected: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+		for (name, owner) in Owners::<T>::iter() {
+			let holder = Self::deposit_holder(&name, &owner.id);
+			let held = expected.entry(holder).or_insert_with(Zero::zero);
+			*held = held.saturating_add(owner.deposit);
+		}
+		let mut accounts = BTreeSet::new();
+		for (id, deposit) in OwnershipDeposits::<T>::iter() {
+			let held = expected.remove(&id).unwrap_or_else(Zero::zero);
+			ensure!(deposit == held, "the ownership deposits of an account are miscounted");
+			accounts.insert(id);
+		}
+		ensure!(
+			expected.values().all(|held| held.is_zero()),
+			"the ownership deposits of an account are not recorded"
+		);
+
+		accounts.extend(CommitDeposits::<T>::iter_keys().map(|(id, _)| id));
+		accounts.extend(ReservedDeposits::<T>::iter_keys());
+		for id in accounts {
+			let reserved = match Self::backend_of(&id) {
+				DepositBackend::Reserve => Self::held_deposits(&id),
+				DepositBackend::Lock => Zero::zero(),
+			};
+			ensure!(
+				Self::reserved_deposits(&id) == reserved,
+				"the reserve of an account does not match its deposits"
+			);
+		}
+		Ok(())
+	}
+}
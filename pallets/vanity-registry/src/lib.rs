@@ -1,6 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub use traits::*;
+pub use types::*;
+pub use vanity_registry_primitives::{
+	InvalidName, VanityName, COMMIT_DOMAIN, COMMIT_VERSION, SALTED_COMMIT_VERSION,
+};
 pub use weights::WeightInfo;
 
 #[cfg(test)]
@@ -12,86 +17,369 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod calls;
+#[cfg(feature = "contracts")]
+pub mod chain_extension;
+mod hooks;
+mod impls;
+#[cfg(any(feature = "defensive-checks", feature = "try-runtime", test))]
+mod invariants;
+pub mod migrations;
+mod queries;
+mod traits;
+mod types;
 pub mod weights;
 
-use codec::{Decode, Encode, EncodeLike};
+use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
 use scale_info::TypeInfo;
 
-use frame_support::traits::{Currency, Get, LockIdentifier, LockableCurrency, WithdrawReasons};
-use frame_system::ensure_signed;
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	storage::{
+		child::{self, ChildInfo},
+		with_transaction, PrefixIterator,
+	},
+	traits::{
+		schedule::{DispatchTime, Named as ScheduleNamed, LOWEST_PRIORITY},
+		BalanceStatus, Currency, ExistenceRequirement, Get, Imbalance, IsSubType, LockIdentifier,
+		LockableCurrency, NamedReservableCurrency, OnKilledAccount, OnUnbalanced, Randomness,
+		StorageVersion, WithdrawReasons,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::{
+	ensure_signed,
+	offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer},
+};
+use sp_core::crypto::KeyTypeId;
 use sp_runtime::{
-	traits::{Hash, Saturating},
-	SaturatedConversion,
+	traits::{
+		Bounded, CheckedAdd, CheckedMul, CheckedSub, DispatchInfoOf, Hash, One, Saturating,
+		SignedExtension, TrailingZeroInput, Zero,
+	},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+	DispatchError, Perbill, TransactionOutcome,
+};
+use sp_std::{
+	convert::TryFrom,
+	fmt::{self, Debug},
+	marker::PhantomData,
+	vec::Vec,
 };
-use sp_std::{fmt::Debug, vec::Vec};
+use vanity_registry_primitives::vanity_name;
 
-/// The period during which a fund for a commit will be locked
-#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, Default, TypeInfo)]
-pub struct LockPeriod<BlockNumber> {
-	begin: BlockNumber,
-	end: BlockNumber,
-}
+/// The target of the pallet's log messages, to filter them by, e.g. with
+/// `-lruntime::vanity-registry=debug`.
+pub(crate) const LOG_TARGET: &str = "runtime::vanity-registry";
 
-/// An account with a commit
-#[derive(Decode, Encode, Clone, Eq, PartialEq, Default, TypeInfo)]
-pub struct Owner<AccountId, Hash, BlockNumber> {
-	id: AccountId,
-	commit: Hash,
-	lock_period: LockPeriod<BlockNumber>,
-}
+/// The tag of the randomness subject breaking ties between commits of the same age to a name,
+/// see `Pallet::wins_tie`.
+pub const TIE_BREAK_SUBJECT: &[u8] = b"vanity-registry/tie-break";
+
+/// The key type of the accounts the off-chain worker files squatting reports with.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"vnty");
+
+/// The crypto used by the off-chain worker to sign the squatting reports.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
 
-type BalanceOf<T> =
-	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
-type OwnerOf<T> = Owner<
-	<T as frame_system::Config>::AccountId,
-	<T as frame_system::Config>::Hash,
-	<T as frame_system::Config>::BlockNumber,
->;
+	pub struct ReporterAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for ReporterAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+	use frame_support::{dispatch::DispatchResult, pallet_prelude::*, transactional};
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// The currency that people use to lock their funds in, when they register.
-		type Currency: LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
+		type Currency: LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>
+			+ NamedReservableCurrency<Self::AccountId, ReserveIdentifier = LockIdentifier>;
 
 		/// The type of the names which are the main assets of this module.
-		type Name: EncodeLike + Clone + Decode + Eq + PartialEq + Debug + TypeInfo;
+		type Name: EncodeLike + Clone + Decode + Eq + PartialEq + Debug + TypeInfo + MaxEncodedLen;
 
-		/// Identifier for the pallet's locks
+		/// Identifier for the pallet's locks and named reserves
 		#[pallet::constant]
 		type ModuleId: Get<LockIdentifier>;
 
+		/// The kinds of withdrawals the pallet's locks prevent, see `DefaultLockReasons`.
+		type LockReasons: Get<WithdrawReasons>;
+
+		/// The share of the ownership deposit slashed when a name is forcibly unregistered.
+		#[pallet::constant]
+		type ForcedRemovalSlash: Get<Perbill>;
+
+		/// How the deposits should be held. Changing it migrates all deposits upon the next
+		/// runtime upgrade.
+		type DepositBackend: Get<DepositBackend>;
+
+		/// The balances locked by other pallets, e.g. staking and vesting, which may back the
+		/// deposits of the accounts opting in through `Pallet::set_lock_backing`.
+		type LockedBalances: LockedBalanceInspector<Self::AccountId, BalanceOf<Self>>;
+
 		/// A name is kept registered for a certain period configured in the runtime.
 		#[pallet::constant]
 		type RegisterPeriod: Get<Self::BlockNumber>;
 
-		/// A fund should be locked as long as the name is kept for an account.
+		/// The period after its register period during which a name is kept for its owner to
+		/// renew before it is freed, none if zero.
+		#[pallet::constant]
+		type GracePeriod: Get<Self::BlockNumber>;
+
+		/// A fund locked for each pending commit until it is revealed or expires.
 		#[pallet::constant]
 		type FundToLock: Get<BalanceOf<Self>>;
 
+		/// A fund locked for each owned name as long as the name is kept for an account.
+		#[pallet::constant]
+		type OwnershipDeposit: Get<BalanceOf<Self>>;
+
+		/// A fund locked along with the `OwnershipDeposit` for each byte of the stored name, so
+		/// long names pay for the state they occupy.
+		#[pallet::constant]
+		type NameByteDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum length of a name in characters, see `Pallet::name_len`.
 		#[pallet::constant]
 		type NameMaxLen: Get<u32>;
 
+		/// The maximum number of reveals processed in a single block.
+		#[pallet::constant]
+		type MaxRevealsPerBlock: Get<u32>;
+
+		/// The maximum number of expiries, and blocks scanned for them, processed in a single
+		/// block. The rest is carried over to the following blocks.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		/// Names shorter than this many characters may only be registered by accounts with a
+		/// positively judged identity.
+		#[pallet::constant]
+		type ShortNameLen: Get<u32>;
+
+		/// Names longer than this many characters may be registered without a commit, as
+		/// front-running them is not worth it.
+		#[pallet::constant]
+		type InstantRegistrationLen: Get<u32>;
+
+		/// The source of identity judgements for registering short names.
+		type IdentityVerifier: IdentityVerifier<Self::AccountId>;
+
+		/// The policy deciding which names may be registered at all.
+		type NamePolicy: NamePolicy<Self::Name>;
+
+		/// The hashing of commitments and batch commit roots, e.g. `BlakeTwo256` or `Keccak256`.
+		type CommitHashing: CommitHashing<Self::Hash>;
+
+		/// The on-chain randomness breaking ties between commits of the same age to a name.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// The verifier of the proofs given to `Pallet::reveal_with_proof`, `()` to only accept
+		/// the commits of `Pallet::hash_of`.
+		type RevealVerifier: RevealVerifier<Self::AccountId, Self::Name, Self::Hash>;
+
+		/// The maximum length of a proof given to `Pallet::reveal_with_proof`.
+		#[pallet::constant]
+		type MaxRevealProofLen: Get<u32>;
+
+		/// The maximum length of a challenge attested by `attest_ownership`.
+		#[pallet::constant]
+		type MaxChallengeLen: Get<u32>;
+
+		/// The length in bytes of the longest prefix indexed in `NamePrefixes`.
+		#[pallet::constant]
+		type MaxPrefixLen: Get<u32>;
+
+		/// The maximum number of names indexed under a single prefix in `NamePrefixes`.
+		#[pallet::constant]
+		type MaxNamesPerPrefix: Get<u32>;
+
+		/// The oracle converting reference units into the chain's currency.
+		type PriceFeed: PriceFeed<BalanceOf<Self>>;
+
+		/// The fee for registering a name, in reference units (e.g. USD cents).
+		#[pallet::constant]
+		type RegistrationFee: Get<u32>;
+
+		/// The fee for renewing a name, in reference units (e.g. USD cents).
+		#[pallet::constant]
+		type RenewalFee: Get<u32>;
+
+		/// The discount on the renewal fee of names held for long, e.g. `TenureMilestones`.
+		type TenureDiscount: TenureDiscount<Self::BlockNumber>;
+
+		/// The maximum number of register periods a name may be revealed for at once, see
+		/// `Pallet::reveal_for_periods`.
+		#[pallet::constant]
+		type MaxRegistrationPeriods: Get<u32>;
+
+		/// The discounts on the registration fee of names revealed for several register periods
+		/// at once, as pairs of a number of periods and the discount on the total fee from then on.
+		#[pallet::constant]
+		type MultiPeriodDiscounts: Get<Vec<(u32, Perbill)>>;
+
+		/// The handler of the fees collected upon registration and renewal.
+		type FeeHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The share of the renewal fees of a name paid to the account backing its deposit.
+		#[pallet::constant]
+		type DelegatorFeeShare: Get<Perbill>;
+
+		/// The maximum number of accounts owning a name together.
+		#[pallet::constant]
+		type MaxJointOwners: Get<u32>;
+
+		/// The number of blocks the approvals of a joint action are valid for.
+		#[pallet::constant]
+		type ApprovalPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of accounts holding shares of a fractionalized name.
+		#[pallet::constant]
+		type MaxShareholders: Get<u32>;
+
+		/// The maximum number of accounts contributing to the renewal pool of a name.
+		#[pallet::constant]
+		type MaxPoolContributors: Get<u32>;
+
+		/// The maximum number of roles, see `Pallet::set_role`, attached to a single name.
+		#[pallet::constant]
+		type MaxRoles: Get<u32>;
+
+		/// The maximum length of a role in bytes.
+		#[pallet::constant]
+		type MaxRoleLen: Get<u32>;
+
+		/// The maximum number of guardians, see `Pallet::set_guardians`, of a single name.
+		#[pallet::constant]
+		type MaxGuardians: Get<u32>;
+
+		/// The number of blocks the owner of a name has to veto its recovery once approved.
+		#[pallet::constant]
+		type RecoveryDelay: Get<Self::BlockNumber>;
+
+		/// The minimum number of blocks the owner of a name must be inactive for before their
+		/// heir may claim it, see `Pallet::set_heir`.
+		#[pallet::constant]
+		type MinInactivityPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of names revealed together, see `Pallet::reveal_bundle`.
+		#[pallet::constant]
+		type MaxBundleLen: Get<u32>;
+
+		/// The maximum number of salts a reveal may try, see `Pallet::reveal_salted`.
+		#[pallet::constant]
+		type MaxCommitSalts: Get<u32>;
+
+		/// The maximum depth of the merkle tree of a batch commit, see `Pallet::reveal_batched`.
+		#[pallet::constant]
+		type MaxProofLen: Get<u32>;
+
+		/// The maximum length of a voucher code, see `Pallet::redeem_voucher`.
+		#[pallet::constant]
+		type MaxVoucherCodeLen: Get<u32>;
+
+		/// The number of blocks the recipient of a gifted name has to reject it, see
+		/// `Pallet::reveal_gift`.
+		#[pallet::constant]
+		type GiftRejectionPeriod: Get<Self::BlockNumber>;
+
+		/// The deposit held from an account watching a name, see `Pallet::watch`.
+		#[pallet::constant]
+		type WatchDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum number of accounts watching a single name.
+		#[pallet::constant]
+		type MaxWatchers: Get<u32>;
+
+		/// The number of blocks during which only the watchers of a freed name may claim it, or
+		/// zero to only notify them.
+		#[pallet::constant]
+		type WatchPriorityPeriod: Get<Self::BlockNumber>;
+
+		/// The origin allowed to perform administrative operations, e.g. setting prices.
+		type AdminOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin allowed to edit the records of a name, i.e. its roles, for its owner.
+		type RecordOrigin: NameOrigin<Self::Origin, Self::Name, Self::AccountId>;
+
+		/// The origin allowed to schedule or cancel the transfer of a name for its owner.
+		type TransferOrigin: NameOrigin<Self::Origin, Self::Name, Self::AccountId>;
+
+		/// The origin allowed to unregister a name for its owner, e.g. `StrongerOriginFor` the
+		/// names critical to a namespace.
+		type UnregisterOrigin: NameOrigin<Self::Origin, Self::Name, Self::AccountId>;
+
+		/// The identifier type for the off-chain worker filing squatting reports.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// The bond reserved from a reporter until their squatting report is resolved.
+		#[pallet::constant]
+		type ReportBond: Get<BalanceOf<Self>>;
+
+		/// The bond reserved from a former owner appealing the takedown of their name.
+		#[pallet::constant]
+		type AppealBond: Get<BalanceOf<Self>>;
+
+		/// The number of blocks a takedown may be appealed for, and an appeal decided within.
+		#[pallet::constant]
+		type AppealWindow: Get<Self::BlockNumber>;
+
+		/// The minimum number of blocks between two squatting reports of the same account.
+		#[pallet::constant]
+		type ReportCooldown: Get<Self::BlockNumber>;
+
+		/// The off-chain worker scans for squatted names once every this many blocks, or never if
+		/// zero.
+		#[pallet::constant]
+		type SquattingScanInterval: Get<Self::BlockNumber>;
+
+		/// The runtime call the calls of this pallet are scheduled as.
+		type RegistryCall: From<Call<Self>>;
+
+		/// The origin the scheduled calls are dispatched with.
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+		/// Dispatches the actions scheduled by the owners of names.
+		type Scheduler: ScheduleNamed<Self::BlockNumber, Self::RegistryCall, Self::PalletsOrigin>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
 	pub struct Pallet<T>(_);
 
-	/// The lock periods mapped to their corresponding account ids and commits
+	/// The lock periods mapped to their corresponding account ids and commits, mirrored in the
+	/// child trie of the account, see `Pallet::owner_trie`
 	#[pallet::storage]
-	#[pallet::getter(fn lock_periods)]
 	pub(super) type LockPeriods<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
@@ -101,23 +389,452 @@ pub mod pallet {
 		LockPeriod<T::BlockNumber>,
 	>;
 
-	/// Owners (account id + commit) mapped to their revealed names
+	/// The leaves of the batch commits already revealed, keyed by the committing account and the
+	/// merkle root of the batch, see `Pallet::reveal_batched`
+	#[pallet::storage]
+	pub(super) type BatchReveals<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, (T::AccountId, T::Hash), Identity, T::Hash, ()>;
+
+	/// The deposits held for the commits, as recorded at commit time
+	#[pallet::storage]
+	pub(super) type CommitDeposits<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Identity, T::Hash, BalanceOf<T>>;
+
+	/// Owners (account id + commit) mapped to their revealed names, in normalized form, mirrored in
+	/// the child trie of the owner, see `Pallet::owner_trie`
 	#[pallet::storage]
-	#[pallet::getter(fn owners)]
 	pub(super) type Owners<T: Config> = StorageMap<_, Blake2_128Concat, T::Name, OwnerOf<T>>;
 
+	/// The sum of the ownership deposits held from each account, i.e. for the names it owns
+	/// without a depositor and the names it backs as a depositor
+	#[pallet::storage]
+	pub(super) type OwnershipDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// The accounts receiving the ownership deposits of names once released, instead of the owners
+	#[pallet::storage]
+	pub(super) type DepositBeneficiaries<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, T::AccountId>;
+
+	/// The pending offers to back the ownership deposit of a name
+	#[pallet::storage]
+	pub(super) type DepositOffers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, T::AccountId>;
+
+	/// The accounts backing the ownership deposit of a name on behalf of its owner
+	#[pallet::storage]
+	pub(super) type DepositDelegations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, T::AccountId>;
+
+	/// The registered names mapped to the casing they were revealed in, when it differs from their
+	/// normalized form, see `Pallet::normalize`
+	#[pallet::storage]
+	pub(super) type NameCasings<T: Config> = StorageMap<_, Blake2_128Concat, T::Name, T::Name>;
+
+	/// The registered names mapped to their hashes, see `Pallet::name_hash`
+	#[pallet::storage]
+	pub(super) type NameHashIndex<T: Config> = StorageMap<_, Identity, T::Hash, T::Name>;
+
+	/// The names taken down by `force_unregister` which are still open to an appeal
+	#[pallet::storage]
+	pub(super) type Takedowns<T: Config> = StorageMap<_, Blake2_128Concat, T::Name, TakedownOf<T>>;
+
+	/// The deadlines of the takedowns mapped to the names taken down
+	#[pallet::storage]
+	pub(super) type TakedownDeadlines<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		T::Name,
+		(),
+		OptionQuery,
+	>;
+
+	/// The jointly owned names mapped to their owners and approval threshold
+	#[pallet::storage]
+	pub(super) type JointOwners<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, JointOwnership<MembersOf<T>>>;
+
+	/// The actions on jointly owned names mapped to the approvals collected for them
+	#[pallet::storage]
+	pub(super) type PendingActions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::Name,
+		Twox64Concat,
+		JointActionOf<T>,
+		PendingActionOf<T>,
+	>;
+
+	/// The fractionalized names mapped to their shareholders and their number of shares
+	#[pallet::storage]
+	pub(super) type NameShares<T: Config> = StorageMap<_, Blake2_128Concat, T::Name, SharesOf<T>>;
+
+	/// The fractionalized names mapped to the shareholders voting for their renewal
+	#[pallet::storage]
+	pub(super) type RenewalVotes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, VotersOf<T>, ValueQuery>;
+
+	/// The names mapped to the contributions reserved to pay for their renewals, drawn in order
+	#[pallet::storage]
+	pub(super) type RenewalPools<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, ContributionsOf<T>>;
+
+	/// The number of registered names in each bucket, see `Pallet::name_bucket`
+	#[pallet::storage]
+	pub(super) type NameBuckets<T: Config> = StorageMap<_, Identity, u16, u32, ValueQuery>;
+
+	/// The prefixes of the registered names mapped to the first names registered under them, see
+	/// `Pallet::names_with_prefix`. Only maintained with the `prefix-index` feature.
+	#[pallet::storage]
+	pub(super) type NamePrefixes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		PrefixOf<T>,
+		BoundedVec<T::Name, T::MaxNamesPerPrefix>,
+		ValueQuery,
+	>;
+
+	/// The deposit backend currently in effect, which may lag behind the configured one until the
+	/// deposits are migrated
+	#[pallet::storage]
+	pub(super) type ActiveBackend<T: Config> = StorageValue<_, DepositBackend, ValueQuery>;
+
+	/// The amounts reserved by the pallet per account, when using the reserve backend
+	#[pallet::storage]
+	pub(super) type ReservedDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// The accounts whose deposits are held by a lock overlapping the balance locked by other
+	/// pallets, whatever the active backend, see `Pallet::set_lock_backing`
+	#[pallet::storage]
+	pub(super) type LockBackedAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The price of one reference unit used whenever the price feed has no data
+	#[pallet::storage]
+	pub(super) type FallbackPrice<T: Config> = StorageValue<_, BalanceOf<T>>;
+
+	/// The most any renewal fee may come to, whatever the price of a reference unit
+	#[pallet::storage]
+	pub(super) type RenewalFeeCeiling<T: Config> = StorageValue<_, BalanceOf<T>>;
+
+	/// The hashes of the names denied by the `DenyList` name policy, see `Pallet::name_hash`
+	#[pallet::storage]
+	pub(super) type DeniedNames<T: Config> = StorageMap<_, Identity, T::Hash, ()>;
+
+	/// Pending squatting reports mapped to the names suspected to be squatted
+	#[pallet::storage]
+	pub(super) type SquattingReports<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, SquattingReportOf<T>>;
+
+	/// The block at which each account has filed its latest squatting report
+	#[pallet::storage]
+	pub(super) type LastReportAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	/// The commits and names mapped to the block they are due to expire at. Entries left behind
+	/// by a renewal or a release are skipped when processed.
+	#[pallet::storage]
+	pub(super) type ExpiryQueue<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		ExpiryOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	/// The earliest block whose expiries in `ExpiryQueue` have not all been processed yet
+	#[pallet::storage]
+	pub(super) type ExpiryCursor<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The blocks the actions scheduled on names are due at. An entry is left behind once its
+	/// action has been dispatched, until the action is scheduled again or the name changes hands.
+	#[pallet::storage]
+	pub(super) type ScheduledActions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::Name,
+		Twox64Concat,
+		ScheduledAction,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	/// The number of reveals processed in the current block, cleared when the block is finalized
+	#[pallet::storage]
+	pub(super) type RevealCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The names mapped to their roles and the accounts filling them, see `Pallet::set_role`
+	#[pallet::storage]
+	pub(super) type NameRoles<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, RolesOf<T>, ValueQuery>;
+
+	/// The names mapped to the guardians who may recover them, see `Pallet::set_guardians`
+	#[pallet::storage]
+	pub(super) type Guardians<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, GuardianshipOf<T>>;
+
+	/// The names mapped to the recovery their guardians are voting on
+	#[pallet::storage]
+	pub(super) type Recoveries<T: Config> = StorageMap<_, Blake2_128Concat, T::Name, RecoveryOf<T>>;
+
+	/// The names mapped to their heirs, see `Pallet::set_heir`
+	#[pallet::storage]
+	pub(super) type Heirs<T: Config> = StorageMap<_, Blake2_128Concat, T::Name, HeirOf<T>>;
+
+	/// The names mapped to the transfer they are locked for until it is executed, see
+	/// `Pallet::schedule_transfer`
+	#[pallet::storage]
+	pub(super) type ScheduledTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, ScheduledTransferOf<T>>;
+
+	/// The hashes of the outstanding voucher codes mapped to what they entitle their bearer to,
+	/// see `Pallet::mint_voucher`
+	#[pallet::storage]
+	pub(super) type Vouchers<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, VoucherOf<T>>;
+
+	/// The names reserved for the bearer of a voucher mapped to the hash of its code
+	#[pallet::storage]
+	pub(super) type VoucherReservations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, T::Hash>;
+
+	/// The names received as gifts mapped to their giver, see `Pallet::reveal_gift`. An entry is
+	/// left behind once the gift can no longer be rejected, until the name changes hands.
+	#[pallet::storage]
+	pub(super) type Gifts<T: Config> = StorageMap<_, Blake2_128Concat, T::Name, GiftOf<T>>;
+
+	/// The accounts mapped to the credits they reserved to pay for the renewals of their names,
+	/// see `Pallet::buy_credits`
+	#[pallet::storage]
+	pub(super) type RenewalCredits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// The hashes of names, see `Pallet::name_hash`, mapped to the accounts watching for them to
+	/// become available and the deposits held from them, see `Pallet::watch`
+	#[pallet::storage]
+	pub(super) type Watchers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, WatchersOf<T>, ValueQuery>;
+
+	/// The hashes of freed names mapped to the watchers who may claim them first. An entry is
+	/// left behind until the commits made before its end have expired.
+	#[pallet::storage]
+	pub(super) type PriorityWindows<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, PriorityWindowOf<T>>;
+
+	/// The names granted by governance which never expire and are held without a deposit, see
+	/// `Pallet::grant_permanent`
+	#[pallet::storage]
+	pub(super) type PermanentNames<T: Config> = StorageMap<_, Blake2_128Concat, T::Name, ()>;
+
+	/// The status of every registered name, which its calls and expiry transition
+	#[pallet::storage]
+	pub(super) type NameStatuses<T: Config> = StorageMap<_, Blake2_128Concat, T::Name, NameStatus>;
+
+	/// The registered names mapped to how often they changed hands and were renewed, forgotten
+	/// once they are freed
+	#[pallet::storage]
+	pub(super) type NameHistories<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, NameHistory, ValueQuery>;
+
+	/// The accounts mapped to the account they agreed to take over the registry state of, see
+	/// `Pallet::migrate_account`
+	#[pallet::storage]
+	pub(super) type MigrationConsents<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	/// The pending offers to pay the ongoing costs of a name, see `Pallet::offer_payment`
+	#[pallet::storage]
+	pub(super) type PaymentOffers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, T::AccountId>;
+
+	/// The accounts paying the renewal fees of a name on behalf of its owner
+	#[pallet::storage]
+	pub(super) type Payers<T: Config> = StorageMap<_, Blake2_128Concat, T::Name, T::AccountId>;
+
+	/// The accounts a name is to be rekeyed to once they accept, see `Pallet::rekey_owner`
+	#[pallet::storage]
+	pub(super) type PendingRekeys<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Name, T::AccountId>;
+
+	/// Events concerning a single name are indexed under the topic `Pallet::name_hash(name)`,
+	/// which `NameHashIndex` resolves back to the name while it is registered.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Register of a name for an AccountId succeeded.
 		/// The third field will be Some(id) if this name was deemed to belong to that "id" prior to this event.
 		NameOwned(T::Name, T::AccountId),
-		/// The name is freed, either got expired from someone's possession or unregistered.
-		NameFreed(T::Name),
+		/// The name is freed for the given reason.
+		NameFreed(T::Name, FreeReason),
 		/// There has been a claim just discovered which wins over this claim. The claimer's fund will be unlocked.
 		RevealDiscredited(T::Name, T::AccountId),
 		/// The claim got expired before being able to register a name.
 		CommitExpired(T::Hash, T::AccountId),
+		/// The commit was dropped as the account holding it was reaped.
+		CommitDropped(T::Hash, T::AccountId),
+		/// A fee has been charged from an account for registering or renewing a name.
+		FeeCharged(T::AccountId, BalanceOf<T>),
+		/// The fallback price of one reference unit is set (or cleared when None).
+		FallbackPriceSet(Option<BalanceOf<T>>),
+		/// The ceiling of renewal fees is set (or cleared when None).
+		RenewalFeeCeilingSet(Option<BalanceOf<T>>),
+		/// The hash of a name is put on (true) or taken off (false) the deny list.
+		NameDenied(T::Hash, bool),
+		/// A name (first) is reported as a clone of another name (second) by an account, and is
+		/// disputed until the report is resolved.
+		SquattingReported(T::Name, T::Name, T::AccountId),
+		/// A squatting report is resolved. The name has been freed if the report was upheld, and
+		/// is active again otherwise.
+		SquattingReportResolved(T::Name, bool),
+		/// The ownership deposit of a name will be refunded to an account upon release.
+		DepositBeneficiarySet(T::Name, T::AccountId),
+		/// The released ownership deposit of a name has been refunded to its beneficiary.
+		DepositRefunded(T::Name, T::AccountId, BalanceOf<T>),
+		/// A name has been forcibly unregistered from an account. The ownership deposit is split
+		/// into the slashed (first) and refunded (second) amounts.
+		NameForceUnregistered(T::Name, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// An account offers to back the ownership deposit of a name.
+		DepositOffered(T::Name, T::AccountId),
+		/// The ownership deposit of a name is now backed by an account.
+		DepositDelegated(T::Name, T::AccountId),
+		/// An account no longer backs the ownership deposit of a name.
+		DepositDelegationEnded(T::Name, T::AccountId),
+		/// The account backing the ownership deposit of a name received its share of a renewal
+		/// fee.
+		DelegatorRewarded(T::Name, T::AccountId, BalanceOf<T>),
+		/// The owner of a name has attested its ownership by submitting a challenge.
+		OwnershipAttested(T::Name, T::AccountId, BoundedVec<u8, T::MaxChallengeLen>),
+		/// A name is owned jointly by the accounts, with the number of approvals actions need.
+		JointOwnersSet(T::Name, MembersOf<T>, u32),
+		/// An owner of a jointly owned name has approved an action on it.
+		ActionApproved(T::Name, JointActionOf<T>, T::AccountId),
+		/// An action on a jointly owned name has collected enough approvals and is executed.
+		ActionExecuted(T::Name, JointActionOf<T>),
+		/// The ownership of a name is split into shares, all held by the account.
+		NameFractionalized(T::Name, T::AccountId, u32),
+		/// Shares of a name are transferred from the first to the second account.
+		SharesTransferred(T::Name, T::AccountId, T::AccountId, u32),
+		/// An amount is paid out to the shareholders of a name pro-rata.
+		RevenueDistributed(T::Name, T::AccountId, BalanceOf<T>),
+		/// A shareholder has voted for the renewal of a name.
+		RenewalVoted(T::Name, T::AccountId),
+		/// An account has contributed an amount to the renewal pool of a name.
+		RenewalContributed(T::Name, T::AccountId, BalanceOf<T>),
+		/// An amount of the renewal fee of a name has been drawn from its renewal pool.
+		RenewalPoolDrawn(T::Name, BalanceOf<T>),
+		/// The renewal pool of a name has been refunded to its contributors.
+		RenewalPoolRefunded(T::Name),
+		/// The former owner of a name taken down has appealed the takedown.
+		TakedownAppealed(T::Name, T::AccountId),
+		/// The appeal against the takedown of a name is decided. The name is back with its former
+		/// owner if the takedown was reversed.
+		AppealResolved(T::Name, bool),
+		/// The takedown of a name can no longer be appealed and the name is free again.
+		TakedownSettled(T::Name),
+		/// The owner of a name has scheduled an action on it for the block.
+		ActionScheduled(T::Name, ScheduledAction, T::BlockNumber),
+		/// An action scheduled on a name has been cancelled.
+		ScheduledActionCancelled(T::Name, ScheduledAction),
+		/// The owner of a name has attached a role filled by an account to it.
+		RoleSet(T::Name, RoleOf<T>, T::AccountId),
+		/// A role has been removed from a name.
+		RoleCleared(T::Name, RoleOf<T>),
+		/// The owner of a name has set its guardians along with the threshold of their votes
+		/// needed for a recovery. No guardians means the name can not be recovered.
+		GuardiansSet(T::Name, GuardiansOf<T>, u32),
+		/// A guardian has voted to recover a name to a new account.
+		RecoveryVoted(T::Name, T::AccountId, T::AccountId),
+		/// The recovery of a name to a new account has been approved, and may be executed from
+		/// the given block unless vetoed by the owner.
+		RecoveryApproved(T::Name, T::AccountId, T::BlockNumber),
+		/// The owner of a name has vetoed its recovery.
+		RecoveryVetoed(T::Name),
+		/// A name has been recovered to a new account.
+		NameRecovered(T::Name, T::AccountId),
+		/// The owner of a name has designated an heir to claim it after the given number of
+		/// blocks of inactivity.
+		HeirSet(T::Name, T::AccountId, T::BlockNumber),
+		/// The owner of a name has removed its heir.
+		HeirRemoved(T::Name),
+		/// The heir of a name has claimed it after its owner's inactivity.
+		InheritanceClaimed(T::Name, T::AccountId),
+		/// The owner of a name has scheduled its transfer to an account at the given block.
+		TransferScheduled(T::Name, T::AccountId, T::BlockNumber),
+		/// The scheduled transfer of a name has been cancelled, by its owner or as the name was
+		/// reported, frozen, handed over or left to lapse.
+		TransferCancelled(T::Name),
+		/// The scheduled transfer of a name has been executed.
+		NameTransferred(T::Name, T::AccountId, T::AccountId),
+		/// The scheduled transfer of a name has been dropped, as its recipient could not cover
+		/// the ownership deposit.
+		TransferFailed(T::Name, T::AccountId),
+		/// An account has opted in or out of backing its deposits with its locked balance.
+		LockBackingSet(T::AccountId, bool),
+		/// An account has claimed all the given number of names of a bundle.
+		BundleRevealed(T::AccountId, u32),
+		/// A bundle of names could not be claimed as a whole, so none were and the commits of
+		/// the account to them were dropped.
+		BundleFailed(T::AccountId, DispatchError),
+		/// A voucher has been minted under the hash of its code.
+		VoucherMinted(T::Hash),
+		/// A voucher has been revoked before it was redeemed.
+		VoucherRevoked(T::Hash),
+		/// A voucher has been redeemed for a name by an account.
+		VoucherRedeemed(T::Hash, T::Name, T::AccountId),
+		/// An account has revealed a name as a gift to another account.
+		NameGifted(T::Name, T::AccountId, T::AccountId),
+		/// The recipient of a gifted name has rejected it, handing it back to its giver.
+		GiftRejected(T::Name, T::AccountId),
+		/// An account has reserved an amount as renewal credits.
+		CreditsBought(T::AccountId, BalanceOf<T>),
+		/// An account has withdrawn an amount of its unused renewal credits.
+		CreditsWithdrawn(T::AccountId, BalanceOf<T>),
+		/// An amount of the renewal credits of an account has paid for a renewal fee.
+		CreditsConsumed(T::AccountId, BalanceOf<T>),
+		/// An account has started watching the name with the given hash.
+		NameWatched(T::Hash, T::AccountId),
+		/// An account has stopped watching the name with the given hash.
+		NameUnwatched(T::Hash, T::AccountId),
+		/// The name with the given hash, watched by the account, has been freed. The event is
+		/// also indexed under the hash of the account.
+		WatchedNameAvailable(T::Hash, T::AccountId),
+		/// Governance has granted a permanent name to an account.
+		PermanentNameGranted(T::Name, T::AccountId),
+		/// Governance has revoked a permanent name.
+		PermanentNameRevoked(T::Name),
+		/// An account has agreed to take over the registry state of another account, or
+		/// withdrawn its consent when None.
+		MigrationConsentSet(T::AccountId, Option<T::AccountId>),
+		/// An account has moved the given number of names along with its deposits and renewal
+		/// credits to another account.
+		AccountMigrated(T::AccountId, T::AccountId, u32),
+		/// An account offers to pay the renewal fees of a name.
+		PaymentOffered(T::Name, T::AccountId),
+		/// The renewal fees of a name are now paid by an account.
+		PayerSet(T::Name, T::AccountId),
+		/// An account no longer pays the renewal fees of a name.
+		PaymentEnded(T::Name, T::AccountId),
+		/// A name is past its register period and will be freed unless renewed by the given
+		/// block.
+		NameInGracePeriod(T::Name, T::BlockNumber),
+		/// A name in its grace period has been renewed or handed over, making it active again.
+		NameLeftGracePeriod(T::Name),
+		/// Governance has frozen a name.
+		NameFrozen(T::Name),
+		/// Governance has unfrozen a name.
+		NameUnfrozen(T::Name),
+		/// A name has been renewed by an account, moving the end of its register period from the
+		/// first block to the second.
+		NameRenewed(T::Name, T::AccountId, T::BlockNumber, T::BlockNumber),
+		/// The owner of a name has proposed to rekey it to an account.
+		RekeyProposed(T::Name, T::AccountId),
+		/// A name has been rekeyed from the first account to the second, keeping its expiry,
+		/// deposit, records and history.
+		OwnerRekeyed(T::Name, T::AccountId, T::AccountId),
 	}
 
 	#[pallet::error]
@@ -128,150 +845,1261 @@ pub mod pallet {
 		NameNotOwned,
 		/// The hash_of(account_id + name) must have been provided before a reveal.
 		CommitNotFound,
+		/// The name has more characters than `NameMaxLen`.
+		NameTooLong,
+		/// Names shorter than `ShortNameLen` need a positively judged identity.
+		IdentityRequired,
+		/// The block has already processed `MaxRevealsPerBlock` reveals.
+		TooManyReveals,
+		/// Names up to `InstantRegistrationLen` characters long need a commit.
+		NameTooShort,
+		/// The name is already registered.
+		NameAlreadyRegistered,
+		/// The name is not allowed by the `NamePolicy`.
+		NameNotAllowed,
+		/// Neither the price feed nor the fallback price can tell the current price.
+		PriceUnavailable,
+		/// The account can not afford the fee.
+		InsufficientBalance,
+		/// Paying the fee would leave the account below the existential deposit.
+		BelowExistentialDeposit,
+		/// A name can not be reported as a clone of itself.
+		SelfReport,
+		/// The reported name is already under review.
+		AlreadyReported,
+		/// The reporter has filed a report too recently.
+		ReportTooSoon,
+		/// There is no pending squatting report for the name.
+		ReportNotFound,
+		/// The owner of a name can not back its ownership deposit as a depositor.
+		SelfDelegation,
+		/// The ownership deposit of the name is already backed by a depositor.
+		AlreadyDelegated,
+		/// There is no pending offer to back the ownership deposit of the name.
+		OfferNotFound,
+		/// The ownership deposit of the name is not backed by a depositor.
+		NotDelegated,
+		/// Only the owner of the name or its depositor may end the delegation.
+		NotDelegationParty,
+		/// The name is owned jointly, so the action needs approvals through `approve_action`.
+		JointlyOwned,
+		/// The name is not owned jointly.
+		NotJointlyOwned,
+		/// The threshold must be at least one and at most the number of owners or guardians.
+		InvalidThreshold,
+		/// Only the joint owners of the name may approve actions on it.
+		NotJointOwner,
+		/// The account has already approved the action.
+		AlreadyApproved,
+		/// The name is fractionalized, so the action is decided by its shareholders.
+		Fractionalized,
+		/// The name is not fractionalized.
+		NotFractionalized,
+		/// A name can't be split into zero shares.
+		NoShares,
+		/// The account holds fewer shares of the name than requested.
+		InsufficientShares,
+		/// The name has reached `MaxShareholders`.
+		TooManyShareholders,
+		/// The shareholder or guardian has already voted.
+		AlreadyVoted,
+		/// The name has been taken down and is open to an appeal.
+		NameTakenDown,
+		/// The name has not been taken down or the takedown is settled.
+		TakedownNotFound,
+		/// Only the former owner of a name may appeal its takedown.
+		NotFormerOwner,
+		/// The takedown has already been appealed.
+		AlreadyAppealed,
+		/// The takedown has not been appealed.
+		AppealNotFound,
+		/// A period would end past the largest block number.
+		ArithmeticOverflow,
+		/// The name is under dispute until its squatting report is resolved.
+		NameDisputed,
+		/// Actions can only be scheduled for a future block.
+		ScheduleInPast,
+		/// The scheduler refused to schedule the action.
+		ScheduleFailed,
+		/// No such action is scheduled on the name.
+		NotScheduled,
+		/// Contributions to a renewal pool and renewal credits must not be zero.
+		ZeroContribution,
+		/// The renewal pool of the name has reached `MaxPoolContributors`.
+		TooManyContributors,
+		/// The name already has `MaxRoles` roles.
+		TooManyRoles,
+		/// The name has no such role.
+		RoleNotFound,
+		/// Only the guardians of the name may vote on its recovery.
+		NotGuardian,
+		/// The guardians are already voting to recover the name to another account.
+		RecoveryMismatch,
+		/// The name is not being recovered.
+		RecoveryNotFound,
+		/// The recovery has not been approved yet, or the owner may still veto it.
+		RecoveryNotDue,
+		/// The inactivity period is shorter than `MinInactivityPeriod`.
+		InactivityPeriodTooShort,
+		/// The name has no heir.
+		HeirNotFound,
+		/// Only the heir of the name may claim it.
+		NotHeir,
+		/// The owner of the name has acted on it within the inactivity period.
+		OwnerStillActive,
+		/// The name is locked until its scheduled transfer is executed or cancelled.
+		TransferPending,
+		/// No transfer of the name is scheduled.
+		TransferNotScheduled,
+		/// The name would expire before the transfer.
+		TransferAfterExpiry,
+		/// A bundle must contain at least one name.
+		EmptyBundle,
+		/// A name of the bundle is held by an earlier commit.
+		BundleClaimLost,
+		/// The balance locked by other pallets does not cover the deposits of the account.
+		InsufficientLockedBalance,
+		/// A voucher has already been minted under this hash.
+		VoucherExists,
+		/// No voucher has been minted under the hash of this code.
+		VoucherNotFound,
+		/// The voucher is for a different name.
+		VoucherMismatch,
+		/// The name is reserved for the bearer of a voucher.
+		NameReserved,
+		/// A name can not be gifted to the account revealing it.
+		GiftToSelf,
+		/// The name has not been received as a gift, or can no longer be rejected.
+		GiftNotFound,
+		/// The account has fewer renewal credits than it tries to withdraw.
+		InsufficientCredits,
+		/// A name must be revealed for at least one and at most `MaxRegistrationPeriods`
+		/// register periods.
+		InvalidPeriods,
+		/// The account is already watching the name.
+		AlreadyWatching,
+		/// The name is watched by `MaxWatchers` accounts already.
+		TooManyWatchers,
+		/// The account is not watching the name.
+		NotWatching,
+		/// The name has just been freed and may only be claimed by its watchers for now.
+		ReservedForWatchers,
+		/// The name is permanent, so it is neither renewed nor unregistered by its owner.
+		PermanentName,
+		/// The name is not permanent.
+		NotPermanent,
+		/// The new account has not agreed to take over the registry state of the account.
+		MigrationNotConsented,
+		/// The account still has commits, which are bound to it and must be revealed or expire
+		/// before it migrates.
+		PendingCommits,
+		/// The account owns more names than the given count.
+		TooManyNames,
+		/// The owner of a name can not be its payer.
+		SelfPayment,
+		/// The renewal fees of the name are not paid by a payer.
+		NoPayer,
+		/// Only the owner of the name or its payer may end the payment.
+		NotPaymentParty,
+		/// The name is past its register period, so it may only be renewed or unregistered.
+		NameInGracePeriod,
+		/// The name is frozen by governance.
+		NameFrozen,
+		/// The name is active, while the call applies to names in another status.
+		NameActive,
+		/// The register period of the name has run out, so it is freed rather than renewed.
+		RegistrationLapsed,
+		/// The merkle proof does not lead from the revealed name to the batch commit, or the proof
+		/// given to `reveal_with_proof` shows no commit to the name.
+		InvalidProof,
+		/// The name has already been revealed with the batch commit.
+		AlreadyRevealed,
+		/// A name can not be rekeyed to its owner.
+		RekeyToSelf,
+		/// The name is not to be rekeyed to the account.
+		RekeyNotFound,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		/// Find and remove expired commits and free the corresponding currency locks at block n.
+		/// Account for the expiries processed when the block is finalized.
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			T::WeightInfo::process_expiries(T::MaxExpiriesPerBlock::get())
+		}
+
+		/// Remove expired commits and names and free the corresponding currency locks at block n.
 		fn on_finalize(n: T::BlockNumber) {
-			Self::remove_expired_commits(n);
-			Self::remove_expired_names(n);
+			Self::process_expiries(n);
+			Self::settle_takedowns(n);
+			RevealCount::<T>::kill();
+			#[cfg(feature = "defensive-checks")]
+			Self::defensive_check_invariants();
+		}
+
+		/// Look for likely clones of established names and report them.
+		fn offchain_worker(n: T::BlockNumber) {
+			let interval = T::SquattingScanInterval::get();
+			if !interval.is_zero() && (n % interval).is_zero() {
+				Self::report_squatting_candidates();
+			}
 		}
 	}
 
+	// The index of a call is its position in this block, so new calls are appended at the end, as
+	// `calls_keep_their_indices` checks.
+	// Calls writing more than one item after a fallible step are `transactional`, so they never
+	// leave partial state behind when they fail.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Commit the hash of 'your id concatenated to your desired name'.
+		/// Commit the hash of 'the commitment domain and version, your id and your desired name
+		/// concatenated', see `Pallet::hash_of`.
 		/// Reveal the name only after you made sure your commit is registered.
 		#[pallet::weight(T::WeightInfo::commit())]
+		#[transactional]
 		pub fn commit(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let begin = <frame_system::Pallet<T>>::block_number();
-			let end = begin + T::RegisterPeriod::get();
-			let lock_period: LockPeriod<T::BlockNumber> = LockPeriod { begin, end };
-			<LockPeriods<T>>::insert(who.clone(), hash, lock_period);
-			Self::update_locked_fund(who);
-			Ok(())
+
+			Self::do_commit(who, hash)
 		}
 
 		/// Reveal the name for which you have previously registered a commit.
-		#[pallet::weight(T::WeightInfo::reveal(name.encode().len()))]
+		/// A successful claim is charged the registration fee.
+		#[pallet::weight(
+			T::WeightInfo::reveal(name.encode().len())
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+		)]
+		#[transactional]
 		pub fn reveal(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let commit = Self::hash_of(who.clone(), name.clone());
+			Self::do_reveal(&who, name, &[], 1)?;
 
-			let new_claim_lock_period =
-				LockPeriods::<T>::take(who.clone(), commit).ok_or(Error::<T>::CommitNotFound)?;
+			Ok(())
+		}
 
-			if let Some(current_owner) = Owners::<T>::get(name.clone()) {
-				if current_owner.lock_period.begin <= new_claim_lock_period.begin {
-					Self::update_locked_fund(who.clone());
-					Self::deposit_event(Event::RevealDiscredited(name, who));
-					return Ok(()); // The reveal originator has successfully discredited their own reveal!
-				};
-			}
+		/// Renew the "name" for "origin". The name should belong to "origin" in the first place.
+		/// When successful, this will extend the register period by another "RegisterPeriod" since
+		/// the renew time. The renewal fee is charged from "origin". A name past its register and
+		/// grace periods can not be renewed, even before its expiry is processed.
+		#[pallet::weight(
+			T::WeightInfo::renew().saturating_add(Pallet::<T>::renewal_pool_weight())
+		)]
+		#[transactional]
+		pub fn renew(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
 
-			// TODO check if mutate is necessary
-			Owners::<T>::insert(
-				name.clone(),
-				Owner { id: who.clone(), commit, lock_period: new_claim_lock_period },
+			let owner = Self::ensure_acting_owner(&who, &name)?;
+			ensure!(!NameShares::<T>::contains_key(&name), Error::<T>::Fractionalized);
+
+			let payer = Self::payers(&name).unwrap_or_else(|| who.clone());
+			Self::do_renew(&name, owner, &who, &payer)
+		}
+
+		/// Unregister the name for origin and unlock the associated fund
+		#[pallet::weight(
+			T::WeightInfo::unregister()
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+				.saturating_add(Pallet::<T>::renewal_pool_weight())
+		)]
+		#[transactional]
+		pub fn unregister(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let name = Self::normalize(&name);
+			let who = T::UnregisterOrigin::ensure_origin(origin, &name)?;
+
+			let owner = Self::ensure_owner(&who, &name)?;
+			ensure!(!PermanentNames::<T>::contains_key(&name), Error::<T>::PermanentName);
+			Self::ensure_status(&name, &[NameStatus::Active, NameStatus::GracePeriod])?;
+			ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+			ensure!(!NameShares::<T>::contains_key(&name), Error::<T>::Fractionalized);
+
+			Self::do_unregister(&name, owner, FreeReason::Unregistered)
+		}
+
+		/// Set the price of one reference unit to be used when the price feed is unavailable.
+		/// Passing None clears the fallback.
+		#[pallet::weight(T::WeightInfo::set_fallback_price())]
+		pub fn set_fallback_price(
+			origin: OriginFor<T>,
+			price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_set_fallback_price(price)
+		}
+
+		/// Report "suspect" as a typosquat or homoglyph clone of "original".
+		/// The reporter's bond stays reserved until the report is resolved. Until then the
+		/// suspect is disputed, closed to its owner, and any transfer of it is cancelled.
+		#[pallet::weight(T::WeightInfo::report_squatting())]
+		pub fn report_squatting(
+			origin: OriginFor<T>,
+			suspect: T::Name,
+			original: T::Name,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_report_squatting(who, suspect, original)
+		}
+
+		/// Resolve the squatting report on "suspect". An upheld report frees the name and returns
+		/// the bond to the reporter, a rejected one hands the bond over to the fee handler.
+		#[pallet::weight(
+			T::WeightInfo::resolve_report()
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+				.saturating_add(Pallet::<T>::renewal_pool_weight())
+		)]
+		#[transactional]
+		pub fn resolve_report(
+			origin: OriginFor<T>,
+			suspect: T::Name,
+			upheld: bool,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_resolve_report(suspect, upheld)
+		}
+
+		/// Have the ownership deposit of "name" refunded to "beneficiary" instead of "origin" once
+		/// the name is released. The name should belong to "origin".
+		#[pallet::weight(T::WeightInfo::set_deposit_beneficiary())]
+		pub fn set_deposit_beneficiary(
+			origin: OriginFor<T>,
+			name: T::Name,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_set_deposit_beneficiary(who, name, beneficiary)
+		}
+
+		/// Hold exactly the deposits recorded for the commits and names of "account", e.g. after
+		/// holding an outdated amount. Anyone may reconcile any account.
+		#[pallet::weight(T::WeightInfo::reconcile_lock())]
+		#[transactional]
+		pub fn reconcile_lock(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::update_locked_fund(&account)
+		}
+
+		/// Unregister "name" regardless of its owner, slashing `ForcedRemovalSlash` of the
+		/// ownership deposit to the fee handler and refunding the remainder.
+		#[pallet::weight(
+			T::WeightInfo::force_unregister()
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+				.saturating_add(Pallet::<T>::renewal_pool_weight())
+		)]
+		#[transactional]
+		pub fn force_unregister(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_force_unregister(name)
+		}
+
+		/// Offer to back the ownership deposit of "name" in exchange for a share of its renewal
+		/// fees. The offer takes effect once accepted by the owner and grants no right over the
+		/// name.
+		#[pallet::weight(T::WeightInfo::offer_deposit())]
+		pub fn offer_deposit(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_offer_deposit(who, name)
+		}
+
+		/// Accept the pending offer to back the ownership deposit of "name", which is then held
+		/// from the depositor instead of "origin" until the name is released or the delegation
+		/// ends. Any deposit beneficiary is cleared.
+		#[pallet::weight(T::WeightInfo::accept_deposit_offer())]
+		#[transactional]
+		pub fn accept_deposit_offer(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_accept_deposit_offer(who, name)
+		}
+
+		/// End the delegation of the ownership deposit of "name", which is held from the owner
+		/// again. Either the owner or the depositor may end it.
+		#[pallet::weight(T::WeightInfo::end_deposit_delegation())]
+		#[transactional]
+		pub fn end_deposit_delegation(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_end_deposit_delegation(who, name)
+		}
+
+		/// Attest that "origin" owns "name" by echoing a challenge issued off-chain, e.g. by a
+		/// service letting its users sign in with their names.
+		#[pallet::weight(T::WeightInfo::attest_ownership(challenge.len() as u32))]
+		pub fn attest_ownership(
+			origin: OriginFor<T>,
+			name: T::Name,
+			challenge: BoundedVec<u8, T::MaxChallengeLen>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_attest_ownership(who, name, challenge)
+		}
+
+		/// Have "name" owned jointly by "members", after which it is only unregistered,
+		/// transferred or given roles once "threshold" of them approved it through
+		/// `approve_action`. The name should belong to "origin", which keeps holding its deposit.
+		#[pallet::weight(T::WeightInfo::set_joint_owners(members.len() as u32))]
+		pub fn set_joint_owners(
+			origin: OriginFor<T>,
+			name: T::Name,
+			members: MembersOf<T>,
+			threshold: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_set_joint_owners(who, name, members, threshold)
+		}
+
+		/// Approve "action" on the jointly owned "name", executing it once it has collected the
+		/// threshold of approvals. Approvals older than `ApprovalPeriod` are void. Jointly owned
+		/// names are only unregistered, transferred and given roles this way.
+		#[pallet::weight(
+			T::WeightInfo::approve_action()
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+				.saturating_add(Pallet::<T>::renewal_pool_weight())
+		)]
+		#[transactional]
+		pub fn approve_action(
+			origin: OriginFor<T>,
+			name: T::Name,
+			action: JointActionOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_approve_action(who, name, action)
+		}
+
+		/// Split the ownership of "name" into "shares" shares, all held by "origin" at first. The
+		/// shareholders then share the revenue of the name and decide on its renewal. The name
+		/// should belong to "origin", which keeps holding its deposit.
+		#[pallet::weight(T::WeightInfo::fractionalize())]
+		pub fn fractionalize(origin: OriginFor<T>, name: T::Name, shares: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_fractionalize(who, name, shares)
+		}
+
+		/// Transfer "amount" shares of "name" from "origin" to "dest".
+		#[pallet::weight(T::WeightInfo::transfer_shares())]
+		pub fn transfer_shares(
+			origin: OriginFor<T>,
+			name: T::Name,
+			dest: T::AccountId,
+			amount: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_transfer_shares(who, name, dest, amount)
+		}
+
+		/// Pay "amount" from "origin" to the shareholders of "name" pro-rata, e.g. the proceeds of
+		/// a lease. Each payout is rounded down, the dust stays with "origin".
+		#[pallet::weight(T::WeightInfo::distribute_revenue(T::MaxShareholders::get()))]
+		#[transactional]
+		pub fn distribute_revenue(
+			origin: OriginFor<T>,
+			name: T::Name,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_distribute_revenue(who, name, amount)
+		}
+
+		/// Vote for the renewal of the fractionalized "name". Once the voters hold more than half
+		/// of the shares, the name is renewed and "origin" is charged the renewal fee.
+		#[pallet::weight(
+			T::WeightInfo::vote_renewal(T::MaxShareholders::get())
+				.saturating_add(Pallet::<T>::renewal_pool_weight())
+		)]
+		#[transactional]
+		pub fn vote_renewal(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_vote_renewal(who, name)
+		}
+
+		/// Appeal the takedown of "name", which "origin" owned before. The appeal bond stays
+		/// reserved until the appeal is decided, or returned if it is not decided within
+		/// `AppealWindow`.
+		#[pallet::weight(T::WeightInfo::appeal_takedown())]
+		pub fn appeal_takedown(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_appeal_takedown(who, name)
+		}
+
+		/// Decide the appeal against the takedown of "name". A reversed takedown returns the name
+		/// to its former owner for the remainder of its register period and the bond to them,
+		/// while the bond of a confirmed one is handed over to the fee handler. The deposit
+		/// slashed by the takedown is not restored.
+		#[pallet::weight(
+			T::WeightInfo::resolve_appeal().saturating_add(Pallet::<T>::prefix_index_weight())
+		)]
+		#[transactional]
+		pub fn resolve_appeal(
+			origin: OriginFor<T>,
+			name: T::Name,
+			reversed: bool,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_resolve_appeal(name, reversed)
+		}
+
+		/// Put the name hashed to "hash" on the deny list, or take it off when "denied" is false.
+		/// The hash is the `name_hash` of the normalized name.
+		#[pallet::weight(T::WeightInfo::set_name_denied())]
+		pub fn set_name_denied(
+			origin: OriginFor<T>,
+			hash: T::Hash,
+			denied: bool,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_set_name_denied(hash, denied)
+		}
+
+		/// Schedule "action" on "name", owned by "origin", to be dispatched on behalf of "origin"
+		/// at block "when". An action of the same kind scheduled before is replaced. The actions
+		/// scheduled on a name are cancelled when it changes hands.
+		#[pallet::weight(T::WeightInfo::schedule_action())]
+		#[transactional]
+		pub fn schedule_action(
+			origin: OriginFor<T>,
+			name: T::Name,
+			action: ScheduledAction,
+			when: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_schedule_action(who, name, action, when)
+		}
+
+		/// Cancel "action" scheduled on "name", owned by "origin".
+		#[pallet::weight(T::WeightInfo::cancel_scheduled_action())]
+		pub fn cancel_scheduled_action(
+			origin: OriginFor<T>,
+			name: T::Name,
+			action: ScheduledAction,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_cancel_scheduled_action(who, name, action)
+		}
+
+		/// Reserve "amount" from "origin" towards the renewals of the registered "name". Renewal
+		/// fees are drawn from the pool before charging whoever renews, and what is left is
+		/// refunded once the name is released or changes hands.
+		#[pallet::weight(T::WeightInfo::contribute_to_renewal())]
+		pub fn contribute_to_renewal(
+			origin: OriginFor<T>,
+			name: T::Name,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_contribute_to_renewal(who, name, amount)
+		}
+
+		/// Have "account" fill "role" under "name", owned by "origin", e.g. `payout` for the
+		/// account receiving the payments made to the name. Another account filling the role
+		/// before is replaced. The roles of a name are removed when it changes hands. The roles of
+		/// a jointly owned name are set through `approve_action`.
+		#[pallet::weight(T::WeightInfo::set_role())]
+		pub fn set_role(
+			origin: OriginFor<T>,
+			name: T::Name,
+			role: RoleOf<T>,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let name = Self::normalize(&name);
+			let who = T::RecordOrigin::ensure_origin(origin, &name)?;
+
+			Self::ensure_acting_owner(&who, &name)?;
+			ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+			Self::do_set_role(&name, role, account)
+		}
+
+		/// Remove "role" from "name", owned by "origin", or through `approve_action` if it is
+		/// jointly owned.
+		#[pallet::weight(T::WeightInfo::clear_role())]
+		pub fn clear_role(origin: OriginFor<T>, name: T::Name, role: RoleOf<T>) -> DispatchResult {
+			let name = Self::normalize(&name);
+			let who = T::RecordOrigin::ensure_origin(origin, &name)?;
+
+			Self::ensure_acting_owner(&who, &name)?;
+			ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+			Self::do_clear_role(&name, role)
+		}
+
+		/// Trust "guardians" to recover "name", owned by "origin", to a new account of its owner
+		/// once "threshold" of them voted for it through `vote_recovery`, e.g. after the owning
+		/// key is lost. No guardians disable the recovery. Any recovery under way is cancelled.
+		/// Jointly owned and fractionalized names can not be recovered, as a recovery would take
+		/// them from their owners without their approval.
+		#[pallet::weight(T::WeightInfo::set_guardians(guardians.len() as u32))]
+		pub fn set_guardians(
+			origin: OriginFor<T>,
+			name: T::Name,
+			guardians: GuardiansOf<T>,
+			threshold: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_set_guardians(who, name, guardians, threshold)
+		}
+
+		/// Vote as a guardian of "name" to recover it to "new_owner". Once the threshold of votes
+		/// is reached, the recovery may be executed through `execute_recovery` after
+		/// `RecoveryDelay`, during which the owner may veto it. Votes older than `ApprovalPeriod`
+		/// are void unless the recovery was approved.
+		#[pallet::weight(T::WeightInfo::vote_recovery())]
+		pub fn vote_recovery(
+			origin: OriginFor<T>,
+			name: T::Name,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_vote_recovery(who, name, new_owner)
+		}
+
+		/// Veto the recovery of "name", owned by "origin", discarding the votes of its guardians.
+		#[pallet::weight(T::WeightInfo::veto_recovery())]
+		pub fn veto_recovery(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_veto_recovery(who, name)
+		}
+
+		/// Move "name" to the new owner its guardians approved once the veto delay has passed.
+		/// The new owner takes over the ownership deposit and the name keeps its expiry and
+		/// guardians, everything else is unwound as with any change of owner. Anyone may execute
+		/// an approved recovery.
+		#[pallet::weight(
+			T::WeightInfo::execute_recovery()
+				.saturating_add(Pallet::<T>::renewal_pool_weight())
+		)]
+		#[transactional]
+		pub fn execute_recovery(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_execute_recovery(name)
+		}
+
+		/// Designate "heir" to inherit "name", owned by "origin", once the owner has not acted on
+		/// the name for "inactivity_blocks", see `claim_inheritance`. Any heir designated before
+		/// is replaced. The inactivity is counted from the last action of the owner rather than
+		/// from the end of the register period, so the heir may claim the name before it lapses.
+		/// Jointly owned and fractionalized names can not be inherited, as the heir would take
+		/// them from their owners without their approval.
+		#[pallet::weight(T::WeightInfo::set_heir())]
+		pub fn set_heir(
+			origin: OriginFor<T>,
+			name: T::Name,
+			heir: T::AccountId,
+			inactivity_blocks: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_set_heir(who, name, heir, inactivity_blocks)
+		}
+
+		/// Remove the heir of "name", owned by "origin".
+		#[pallet::weight(T::WeightInfo::remove_heir())]
+		pub fn remove_heir(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_remove_heir(who, name)
+		}
+
+		/// Claim "name" as its heir, bypassing commit-reveal, once its owner has not acted on it
+		/// for the inactivity period they set. The heir takes over the ownership deposit and the
+		/// name keeps its expiry, everything else is unwound as with any change of owner.
+		#[pallet::weight(
+			T::WeightInfo::claim_inheritance()
+				.saturating_add(Pallet::<T>::renewal_pool_weight())
+		)]
+		#[transactional]
+		pub fn claim_inheritance(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_claim_inheritance(who, name)
+		}
+
+		/// Transfer "name", owned by "origin", to "to" at block "at", locking it against any
+		/// other transfer until then. The transfer is executed along with the expiries of that
+		/// block and dropped if "to" can not cover the ownership deposit by then. Jointly owned
+		/// names are transferred through `approve_action`, while fractionalized names can not be
+		/// transferred.
+		#[pallet::weight(T::WeightInfo::schedule_transfer())]
+		pub fn schedule_transfer(
+			origin: OriginFor<T>,
+			name: T::Name,
+			to: T::AccountId,
+			at: T::BlockNumber,
+		) -> DispatchResult {
+			let name = Self::normalize(&name);
+			let who = T::TransferOrigin::ensure_origin(origin, &name)?;
+
+			let owner = Self::ensure_acting_owner(&who, &name)?;
+			ensure!(!JointOwners::<T>::contains_key(&name), Error::<T>::JointlyOwned);
+			Self::do_schedule_transfer(&name, &owner, to, at)
+		}
+
+		/// Cancel the scheduled transfer of "name", owned by "origin".
+		#[pallet::weight(T::WeightInfo::cancel_transfer())]
+		pub fn cancel_transfer(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let name = Self::normalize(&name);
+			let who = T::TransferOrigin::ensure_origin(origin, &name)?;
+
+			Self::do_cancel_transfer(who, name)
+		}
+
+		/// Reveal all of "names" committed to by "origin" as `reveal` does, or none of them, e.g.
+		/// so a brand is not left with part of its variants. Unless every name is claimed, the
+		/// claims are undone and the commits of "origin" to the names are dropped, releasing
+		/// their deposits.
+		#[pallet::weight(
+			T::WeightInfo::reveal_bundle(names.len() as u32).saturating_add(
+				Pallet::<T>::prefix_index_weight().saturating_mul(names.len() as Weight)
+			)
+		)]
+		pub fn reveal_bundle(
+			origin: OriginFor<T>,
+			names: BoundedVec<T::Name, T::MaxBundleLen>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_reveal_bundle(who, names)
+		}
+
+		/// Opt "origin" in or out of backing its deposits with the balance already locked by
+		/// other pallets, e.g. its stake, see `Config::LockedBalances`. The deposits of an account
+		/// opting in are held by a lock overlapping the others, even with the reserve backend, and
+		/// must be covered by its locked balance. Opting out holds them with the active backend
+		/// again.
+		#[pallet::weight(T::WeightInfo::set_lock_backing())]
+		#[transactional]
+		pub fn set_lock_backing(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_set_lock_backing(who, enabled)
+		}
+
+		/// Mint a voucher under "hash", the hash of its code (see `Pallet::redeem_voucher`),
+		/// entitling its bearer to register "name" or, when None, any name once without paying
+		/// the registration fee. Nobody but the bearer may claim a name reserved this way until
+		/// the voucher is redeemed or revoked.
+		#[pallet::weight(T::WeightInfo::mint_voucher())]
+		pub fn mint_voucher(
+			origin: OriginFor<T>,
+			hash: T::Hash,
+			name: Option<T::Name>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_mint_voucher(hash, name)
+		}
+
+		/// Revoke the voucher minted under "hash", releasing the name it reserves if any.
+		#[pallet::weight(T::WeightInfo::revoke_voucher())]
+		pub fn revoke_voucher(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_revoke_voucher(hash)
+		}
+
+		/// Redeem the voucher whose code is "code" by registering "name" for "origin" right away,
+		/// as `Pallet::register` would but without charging the registration fee. The ownership
+		/// deposit is held as usual. The code is revealed by this call, so it should only be
+		/// handed to the bearer privately.
+		#[pallet::weight(
+			T::WeightInfo::redeem_voucher(name.encode().len())
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+		)]
+		#[transactional]
+		pub fn redeem_voucher(
+			origin: OriginFor<T>,
+			code: VoucherCodeOf<T>,
+			name: T::Name,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_redeem_voucher(who, code, name)
+		}
+
+		/// Reveal "name" as `Pallet::reveal` does, from the commit of "origin" which pays the
+		/// registration fee, but make "beneficiary" its owner. "origin" backs the ownership deposit
+		/// as if "beneficiary" had accepted its offer to (see `Pallet::offer_deposit`), so it is
+		/// released to "origin" with the name unless "beneficiary" ends the delegation. The
+		/// beneficiary may reject the name within `GiftRejectionPeriod`, see
+		/// `Pallet::reject_gift`.
+		#[pallet::weight(
+			T::WeightInfo::reveal_gift(name.encode().len())
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+		)]
+		#[transactional]
+		pub fn reveal_gift(
+			origin: OriginFor<T>,
+			name: T::Name,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_reveal_gift(who, name, beneficiary)
+		}
+
+		/// Reject "name", received by "origin" as a gift within `GiftRejectionPeriod`, handing it
+		/// back to its giver along with the ownership deposit they already back.
+		#[pallet::weight(
+			T::WeightInfo::reject_gift().saturating_add(Pallet::<T>::renewal_pool_weight())
+		)]
+		#[transactional]
+		pub fn reject_gift(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_reject_gift(who, name)
+		}
+
+		/// Reserve "amount" from "origin" as renewal credits, which pay for the renewal fees
+		/// "origin" is charged, e.g. by the renewals it scheduled (see `Pallet::schedule_action`),
+		/// once the renewal pool of the name is exhausted.
+		#[pallet::weight(T::WeightInfo::buy_credits())]
+		pub fn buy_credits(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_buy_credits(who, amount)
+		}
+
+		/// Unreserve "amount" of the unused renewal credits of "origin".
+		#[pallet::weight(T::WeightInfo::withdraw_credits())]
+		pub fn withdraw_credits(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_withdraw_credits(who, amount)
+		}
+
+		/// Reveal "name" as `Pallet::reveal` does, but register it for "periods" register periods
+		/// at once. The registration fee of every period is charged up front, discounted as set by
+		/// `MultiPeriodDiscounts`, and the name expires at the end of the last period.
+		#[pallet::weight(
+			T::WeightInfo::reveal_for_periods(name.encode().len())
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+		)]
+		#[transactional]
+		pub fn reveal_for_periods(
+			origin: OriginFor<T>,
+			name: T::Name,
+			periods: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				periods >= 1 && periods <= T::MaxRegistrationPeriods::get(),
+				Error::<T>::InvalidPeriods
 			);
 
-			Self::deposit_event(Event::NameOwned(name, who));
+			Self::do_reveal(&who, name, &[], periods)?;
 
 			Ok(())
 		}
 
-		/// Renew the "name" for "origin". The name should belong to "origin" in the first place.
-		/// When successful, this will extend the register period by another "RegisterPeriod" since
-		/// the renew time.
-		#[pallet::weight(T::WeightInfo::renew())]
-		pub fn renew(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+		/// Watch the name whose hash (see `Pallet::name_hash`) is "hash", holding `WatchDeposit`
+		/// from "origin". Once the name is freed its watchers are notified with a
+		/// `WatchedNameAvailable` event, their deposits are released, and only they may claim it
+		/// with commits made before the following `WatchPriorityPeriod` blocks have passed.
+		#[pallet::weight(T::WeightInfo::watch())]
+		pub fn watch(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_watch(who, hash)
+		}
+
+		/// Stop watching the name whose hash is "hash", releasing the deposit of "origin".
+		#[pallet::weight(T::WeightInfo::unwatch())]
+		pub fn unwatch(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_unwatch(who, hash)
+		}
+
+		/// Grant "name", which must be free, to "owner" for good, e.g. for a system or public-good
+		/// name. It never expires, is held without a deposit or fees, and only governance may
+		/// free it again, see `Pallet::revoke_permanent`.
+		#[pallet::weight(
+			T::WeightInfo::grant_permanent().saturating_add(Pallet::<T>::prefix_index_weight())
+		)]
+		#[transactional]
+		pub fn grant_permanent(
+			origin: OriginFor<T>,
+			name: T::Name,
+			owner: T::AccountId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_grant_permanent(name, owner)
+		}
+
+		/// Free the permanent "name" granted by `Pallet::grant_permanent`.
+		#[pallet::weight(
+			T::WeightInfo::revoke_permanent()
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+				.saturating_add(Pallet::<T>::renewal_pool_weight())
+		)]
+		#[transactional]
+		pub fn revoke_permanent(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_revoke_permanent(name)
+		}
+
+		/// Agree to take over the registry state of "from" through `Pallet::migrate_account`, or
+		/// withdraw the consent when None.
+		#[pallet::weight(T::WeightInfo::consent_to_migration())]
+		pub fn consent_to_migration(
+			origin: OriginFor<T>,
+			from: Option<T::AccountId>,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let mut owner = Self::ensure_owner(who, name.clone())?;
+			Self::do_consent_to_migration(who, from)
+		}
+
+		/// Move all the names of "origin", at most "names" of them, to "new_account" at once along
+		/// with their records, deposits and the renewal credits of "origin", e.g. to rotate keys.
+		/// "new_account" must have agreed through `Pallet::consent_to_migration` and be able to
+		/// hold the deposits. Commits are bound to the account which made them, so "origin" must
+		/// have none left. Names owned jointly or in shares stay with their members.
+		#[pallet::weight(T::WeightInfo::migrate_account(*names))]
+		#[transactional]
+		pub fn migrate_account(
+			origin: OriginFor<T>,
+			new_account: T::AccountId,
+			names: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_migrate_account(who, new_account, names)
+		}
+
+		/// Offer to pay the renewal fees of "name", e.g. as the employer of its owner. The offer
+		/// takes effect once accepted by the owner and grants no right over the name.
+		#[pallet::weight(T::WeightInfo::offer_payment())]
+		pub fn offer_payment(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_offer_payment(who, name)
+		}
+
+		/// Accept the pending offer to pay the renewal fees of "name", which are then charged
+		/// from the payer instead of "origin" until the name changes hands or the payment ends.
+		/// Any payer accepted before is replaced.
+		#[pallet::weight(T::WeightInfo::accept_payment_offer())]
+		pub fn accept_payment_offer(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_accept_payment_offer(who, name)
+		}
+
+		/// End the payment of the renewal fees of "name" by its payer, after which they are
+		/// charged from the owner again. Either the payer, resigning, or the owner may end it.
+		#[pallet::weight(T::WeightInfo::end_payment())]
+		pub fn end_payment(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
 
-			owner.lock_period.end =
-				<frame_system::Pallet<T>>::block_number() + T::RegisterPeriod::get();
-			Owners::<T>::insert(name, owner);
+			Self::do_end_payment(who, name)
+		}
+
+		/// Freeze "name", e.g. pending a legal dispute, closing it to its owner and keeping it
+		/// from expiring until it is unfrozen. Any transfer of it is cancelled.
+		#[pallet::weight(T::WeightInfo::freeze_name())]
+		pub fn freeze_name(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_freeze_name(name)
+		}
+
+		/// Unfreeze "name", making it active again. A name whose register period ended while
+		/// frozen is kept until this block, so its owner still gets the grace period.
+		#[pallet::weight(T::WeightInfo::unfreeze_name())]
+		pub fn unfreeze_name(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_unfreeze_name(name)
+		}
+
+		/// Reveal "name" with the oldest of the commits of "origin" to it, either unsalted or
+		/// under one of "salts", see `Pallet::salted_hash_of`. Salts let an account commit to the
+		/// same name several times, e.g. from different devices, without the commits replacing
+		/// each other. The other commits are left to expire.
+		#[pallet::weight(
+			T::WeightInfo::reveal_salted(name.encode().len(), salts.len() as u32)
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+		)]
+		#[transactional]
+		pub fn reveal_salted(
+			origin: OriginFor<T>,
+			name: T::Name,
+			salts: BoundedVec<T::Hash, T::MaxCommitSalts>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_reveal(&who, name, &salts, 1)?;
 
 			Ok(())
 		}
 
-		/// Unregister the name for origin and unlock the associated fund
-		#[pallet::weight(T::WeightInfo::unregister())]
-		pub fn unregister(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+		/// Reveal "name" with the batch commit "root" of "origin", the merkle root of the salted
+		/// commits to every name of the batch, see `Pallet::salted_hash_of`. "proof" holds the
+		/// sibling hashes from the salted commit to "name" up to the root, see
+		/// `Pallet::merkle_root`. A batch holds a single commit deposit for all of its names,
+		/// which stay hidden until revealed, and it expires as a whole.
+		#[pallet::weight(
+			T::WeightInfo::reveal_batched(name.encode().len(), proof.len() as u32)
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+		)]
+		#[transactional]
+		pub fn reveal_batched(
+			origin: OriginFor<T>,
+			name: T::Name,
+			salt: T::Hash,
+			root: T::Hash,
+			proof: BoundedVec<T::Hash, T::MaxProofLen>,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let _ = Self::ensure_owner(who.clone(), name.clone())?;
+			Self::do_reveal_batched(&who, name, salt, root, &proof)?;
 
-			Owners::<T>::remove(name.clone());
+			Ok(())
+		}
 
-			Self::update_locked_fund(who);
+		/// Reveal "name" with the commit of "origin" that "proof" shows to be to it, as checked by
+		/// `Config::RevealVerifier`. Lets runtimes accept e.g. blinded commitments, while the
+		/// default verifier only accepts the commits of `Pallet::hash_of` like `Pallet::reveal`.
+		#[pallet::weight(
+			T::WeightInfo::reveal_with_proof(name.encode().len())
+				.saturating_add(T::RevealVerifier::weight(proof.len() as u32))
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+		)]
+		#[transactional]
+		pub fn reveal_with_proof(
+			origin: OriginFor<T>,
+			name: T::Name,
+			proof: BoundedVec<u8, T::MaxRevealProofLen>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			Self::deposit_event(Event::NameFreed(name));
+			Self::do_reveal_with_proof(&who, name, &proof)?;
 
 			Ok(())
 		}
+
+		/// Cap every renewal fee at "ceiling", so a rising price of the reference unit can not
+		/// price owners out of their names. Passing None lifts the cap.
+		#[pallet::weight(T::WeightInfo::set_renewal_fee_ceiling())]
+		pub fn set_renewal_fee_ceiling(
+			origin: OriginFor<T>,
+			ceiling: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_set_renewal_fee_ceiling(ceiling)
+		}
+
+		/// Propose to rekey "name" to "new_account", e.g. to rotate the key of its owner. Unlike
+		/// a transfer, the name keeps its expiry, deposit, records, scheduled actions and renewal
+		/// history, and becomes owned by "new_account" once it accepts through
+		/// `Pallet::accept_rekey`. Any rekey proposed before is replaced.
+		#[pallet::weight(T::WeightInfo::rekey_owner())]
+		pub fn rekey_owner(
+			origin: OriginFor<T>,
+			name: T::Name,
+			new_account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_rekey_owner(who, name, new_account)
+		}
+
+		/// Accept the rekey of "name" to "origin" proposed by its owner, taking over the name
+		/// along with its ownership deposit unless someone else holds it. The actions scheduled
+		/// on the name are dispatched on behalf of "origin" from now on.
+		#[pallet::weight(T::WeightInfo::accept_rekey())]
+		#[transactional]
+		pub fn accept_rekey(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let name = Self::normalize(&name);
+
+			Self::do_accept_rekey(who, name)
+		}
+
+		/// Register "name" for "origin" right away, without a commit. Only names longer than
+		/// `InstantRegistrationLen` may skip commit-reveal. The name is held as if committed to
+		/// now, so a reveal of an earlier commit still wins it over. A successful registration is
+		/// charged the registration fee.
+		#[pallet::weight(
+			T::WeightInfo::register(name.encode().len())
+				.saturating_add(Pallet::<T>::prefix_index_weight())
+		)]
+		#[transactional]
+		pub fn register(origin: OriginFor<T>, name: T::Name) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_register(who, name)
+		}
 	}
 }
 
-impl<T: Config> Pallet<T> {
-	/// Set lock according to the number of commits that are associated with and id.
-	/// Remove the lock if no commits.
-	fn update_locked_fund(id: T::AccountId) {
-		let num_of_commits = LockPeriods::<T>::iter_prefix_values(id.clone()).count();
-		if num_of_commits > 0 {
-			let amount_to_lock =
-				T::FundToLock::get().saturating_mul(num_of_commits.saturated_into());
-			T::Currency::set_lock(T::ModuleId::get(), &id, amount_to_lock, WithdrawReasons::all());
-		} else {
-			T::Currency::remove_lock(T::ModuleId::get(), &id);
-		}
+/// Custom validity errors of the registry calls rejected by `CheckRegistryCall`.
+#[repr(u8)]
+pub enum ValidityError {
+	/// The signer does not own the name they try to renew, unregister or manage.
+	NameNotOwned = 0,
+	/// The signer has no commit for the name they try to reveal.
+	CommitNotFound = 1,
+}
+
+impl From<ValidityError> for u8 {
+	fn from(err: ValidityError) -> Self {
+		err as u8
+	}
+}
+
+/// Reject registry calls which are bound to fail before they are included in a block, so the
+/// signer does not pay the inclusion fees for them.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckRegistryCall<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckRegistryCall<T> {
+	/// Create a new instance of the extension.
+	pub fn new() -> Self {
+		Self(PhantomData)
 	}
+}
 
-	/// Remove expired commits for which the lock period is over.
-	fn remove_expired_commits(now: T::BlockNumber) {
-		let expired_commits: Vec<(T::AccountId, T::Hash)> = LockPeriods::<T>::iter()
-			.filter(|(_, _, lock_period)| lock_period.end <= now)
-			.map(|(id, commit, _)| (id, commit))
-			.collect();
-		expired_commits.iter().for_each(|(id, commit)| {
-			LockPeriods::<T>::remove(id.clone(), commit);
-			Self::update_locked_fund(id.clone());
-			Self::deposit_event(Event::CommitExpired(*commit, id.clone()));
-		});
+impl<T: Config + Send + Sync> Default for CheckRegistryCall<T> {
+	fn default() -> Self {
+		Self::new()
 	}
+}
 
-	/// Free names when their corresponding fund lock is expired.
-	fn remove_expired_names(now: T::BlockNumber) {
-		let expired_names: Vec<(T::Name, OwnerOf<T>)> =
-			Owners::<T>::iter().filter(|(_, owner)| owner.lock_period.end <= now).collect();
-		expired_names.iter().for_each(|(name, owner)| {
-			Owners::<T>::remove(name.clone());
-			Self::update_locked_fund(owner.id.clone());
-			Self::deposit_event(Event::NameFreed(name.clone()));
-		});
+impl<T: Config + Send + Sync> Debug for CheckRegistryCall<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CheckRegistryCall")
 	}
 
-	/// Calculate the commit for "name" from "id" which the hash of 'id concatenated name'.
-	fn hash_of(id: T::AccountId, name: T::Name) -> T::Hash {
-		let mut id_plus_name = id.encode();
-		id_plus_name.extend_from_slice(&name.encode());
-		T::Hashing::hash_of(&id_plus_name)
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
 	}
+}
 
-	/// Ensure origin is the owner of the "name" and when successful return the ownership details.
-	fn ensure_owner(origin: T::AccountId, name: T::Name) -> Result<OwnerOf<T>, Error<T>> {
-		if let Some(owner) = Owners::<T>::get(name) {
-			if owner.id != origin {
-				Err(Error::<T>::NameNotOwned)
-			} else {
-				Ok(owner)
-			}
-		} else {
-			Err(Error::<T>::NameNotFound)
+impl<T: Config + Send + Sync> SignedExtension for CheckRegistryCall<T>
+where
+	<T as frame_system::Config>::Call: IsSubType<Call<T>>,
+{
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+	const IDENTIFIER: &'static str = "CheckRegistryCall";
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		match call.is_sub_type() {
+			Some(Call::renew { name }) |
+			Some(Call::unregister { name }) |
+			Some(Call::set_deposit_beneficiary { name, .. }) |
+			Some(Call::attest_ownership { name, .. }) => {
+				Pallet::<T>::ensure_owner(who, &Pallet::<T>::normalize(name))
+					.map_err(|_| InvalidTransaction::Custom(ValidityError::NameNotOwned.into()))?;
+			},
+			Some(Call::reveal { name }) | Some(Call::reveal_for_periods { name, .. }) => {
+				// Leave the reveal in the pool for a later block
+				if RevealCount::<T>::get() >= T::MaxRevealsPerBlock::get() {
+					return Err(InvalidTransaction::ExhaustsResources.into())
+				}
+				let commit = Pallet::<T>::hash_of(who.clone(), name.clone());
+				if !LockPeriods::<T>::contains_key(who, commit) {
+					let error = InvalidTransaction::Custom(ValidityError::CommitNotFound.into());
+					return Err(error.into())
+				}
+			},
+			Some(Call::reveal_salted { name, salts }) => {
+				if RevealCount::<T>::get() >= T::MaxRevealsPerBlock::get() {
+					return Err(InvalidTransaction::ExhaustsResources.into())
+				}
+				if Pallet::<T>::oldest_commit(who, name, salts).is_none() {
+					let error = InvalidTransaction::Custom(ValidityError::CommitNotFound.into());
+					return Err(error.into())
+				}
+			},
+			Some(Call::reveal_with_proof { .. }) => {
+				if RevealCount::<T>::get() >= T::MaxRevealsPerBlock::get() {
+					return Err(InvalidTransaction::ExhaustsResources.into())
+				}
+			},
+			Some(Call::reveal_batched { root, .. }) => {
+				if RevealCount::<T>::get() >= T::MaxRevealsPerBlock::get() {
+					return Err(InvalidTransaction::ExhaustsResources.into())
+				}
+				if !LockPeriods::<T>::contains_key(who, root) {
+					let error = InvalidTransaction::Custom(ValidityError::CommitNotFound.into());
+					return Err(error.into())
+				}
+			},
+			_ => {},
 		}
+		Ok(ValidTransaction::default())
 	}
 }
@@ -0,0 +1,420 @@
+//! Storage migrations of the vanity registry pallet.
+//!
+//! The pallet does not migrate itself: runtimes list `Migrate` among the custom migrations of
+//! their `Executive`, which runs it on every runtime upgrade.
+
+use super::*;
+use frame_support::{
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+
+/// Migrate the storage to the latest layout, then the deposits if the configured backend has
+/// changed. Every step is skipped once done, so running it again is cheap.
+pub struct Migrate<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for Migrate<T> {
+	fn on_runtime_upgrade() -> Weight {
+		v1::migrate::<T>()
+			.saturating_add(v2::migrate::<T>())
+			.saturating_add(v3::migrate::<T>())
+			.saturating_add(v4::migrate::<T>())
+			.saturating_add(v5::migrate::<T>())
+			.saturating_add(v6::migrate::<T>())
+			.saturating_add(v7::migrate::<T>())
+			.saturating_add(v8::migrate::<T>())
+			.saturating_add(switch_deposit_backend::<T>())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		frame_support::ensure!(
+			Pallet::<T>::on_chain_storage_version() == Pallet::<T>::current_storage_version(),
+			"the vanity registry storage is not migrated to the latest version"
+		);
+		Pallet::<T>::check_invariants()
+	}
+}
+
+/// Version 1 records the ownership deposit of each name.
+pub mod v1 {
+	use super::*;
+
+	/// An owner as stored before version 1.
+	#[derive(Decode)]
+	struct OldOwner<AccountId, Hash, BlockNumber> {
+		id: AccountId,
+		commit: Hash,
+		lock_period: LockPeriod<BlockNumber>,
+	}
+
+	/// Translate the owners to the new layout, both in `Owners` and in their child tries.
+	///
+	/// The names registered before the upgrade carry no ownership deposit, so no account sees its
+	/// held funds change.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated: Weight = 0;
+		Owners::<T>::translate::<OldOwner<T::AccountId, T::Hash, T::BlockNumber>, _>(
+			|name, old| {
+				translated += 1;
+				let owner = Owner {
+					acquired_at: old.lock_period.begin,
+					id: old.id,
+					commit: old.commit,
+					lock_period: old.lock_period,
+					deposit: Zero::zero(),
+					acquisition: Acquisition::Reveal,
+					renewals: 0,
+				};
+				let key = OwnerTrieKey::<T::Hash, T::Name>::Name(name).encode();
+				child::put(&Pallet::<T>::owner_trie(&owner.id), &key, &owner);
+				Some(owner)
+			},
+		);
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1 + translated, 1 + 2 * translated)
+	}
+}
+
+/// Version 2 records the deposit of each commit, so changing `Config::FundToLock` leaves the
+/// terms of the existing commits alone.
+pub mod v2 {
+	use super::*;
+
+	/// Record the current `Config::FundToLock` as the deposit of every commit lacking one.
+	///
+	/// This must run before `Config::FundToLock` is changed for the existing commits to keep
+	/// their original terms.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 2 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut recorded: Weight = 0;
+		for (id, commit) in LockPeriods::<T>::iter_keys() {
+			if !CommitDeposits::<T>::contains_key(&id, commit) {
+				CommitDeposits::<T>::insert(&id, commit, T::FundToLock::get());
+				recorded += 1;
+			}
+		}
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1 + 2 * recorded, 1 + recorded)
+	}
+}
+
+/// Version 3 indexes the registered names by their hashes.
+pub mod v3 {
+	use super::*;
+
+	/// Fill `NameHashIndex` with the names registered before the upgrade.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 3 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut indexed: Weight = 0;
+		for name in Owners::<T>::iter_keys() {
+			NameHashIndex::<T>::insert(Pallet::<T>::name_hash(&name), name);
+			indexed += 1;
+		}
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1 + indexed, 1 + indexed)
+	}
+}
+
+/// Version 4 registers the names under their normalized form, see `Pallet::normalize`.
+pub mod v4 {
+	use super::*;
+
+	/// Rekey every name registered in another casing than its normalized form, keeping that
+	/// casing for display.
+	///
+	/// Of several names colliding once normalized, the one committed first keeps the name and
+	/// the others are released with their deposits refunded.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 4 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let names: Vec<(T::Name, T::Name)> = Owners::<T>::iter_keys()
+			.filter_map(|name| {
+				let normalized = Pallet::<T>::normalize(&name);
+				if normalized == name {
+					None
+				} else {
+					Some((name, normalized))
+				}
+			})
+			.collect();
+
+		let mut rekeyed: Weight = 0;
+		for (name, normalized) in names {
+			let owner = match Owners::<T>::get(&name) {
+				Some(owner) => owner,
+				None => continue,
+			};
+			if let Some(existing) = Owners::<T>::get(&normalized) {
+				if existing.lock_period.begin <= owner.lock_period.begin {
+					release::<T>(&name, owner);
+				} else {
+					release::<T>(&normalized, existing);
+				}
+			}
+			if !Owners::<T>::contains_key(&name) {
+				continue
+			}
+
+			Owners::<T>::remove(&name);
+			let key = OwnerTrieKey::<T::Hash, T::Name>::Name(name.clone()).encode();
+			child::kill(&Pallet::<T>::owner_trie(&owner.id), &key);
+			NameHashIndex::<T>::remove(Pallet::<T>::name_hash(&name));
+			if let Some(beneficiary) = DepositBeneficiaries::<T>::take(&name) {
+				DepositBeneficiaries::<T>::insert(&normalized, beneficiary);
+			}
+			if let Some(depositor) = DepositOffers::<T>::take(&name) {
+				DepositOffers::<T>::insert(&normalized, depositor);
+			}
+			if let Some(depositor) = DepositDelegations::<T>::take(&name) {
+				DepositDelegations::<T>::insert(&normalized, depositor);
+			}
+			if let Some(report) = SquattingReports::<T>::take(&name) {
+				if SquattingReports::<T>::contains_key(&normalized) {
+					let reporter = &report.reporter;
+					T::Currency::unreserve_named(&T::ModuleId::get(), reporter, report.bond);
+				} else {
+					SquattingReports::<T>::insert(&normalized, report);
+				}
+			}
+
+			let key = OwnerTrieKey::<T::Hash, T::Name>::Name(normalized.clone()).encode();
+			child::put(&Pallet::<T>::owner_trie(&owner.id), &key, &owner);
+			NameHashIndex::<T>::insert(Pallet::<T>::name_hash(&normalized), normalized.clone());
+			Owners::<T>::insert(&normalized, owner);
+			NameCasings::<T>::insert(normalized, name);
+			rekeyed += 1;
+		}
+
+		StorageVersion::new(4).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1 + 8 * rekeyed, 1 + 12 * rekeyed)
+	}
+
+	/// Release "name" from "owner" like `Pallet::unregister` does.
+	fn release<T: Config>(name: &T::Name, owner: OwnerOf<T>) {
+		Pallet::<T>::take_owner(name);
+		Pallet::<T>::release_held_fund(&owner.id);
+		Pallet::<T>::refund_deposit(name, &owner.id, owner.deposit);
+	}
+}
+
+/// Version 5 counts the registered names per bucket, see `Pallet::is_available`.
+pub mod v5 {
+	use super::*;
+
+	/// Count the names registered before the upgrade in `NameBuckets`.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 5 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut counted: Weight = 0;
+		for name in Owners::<T>::iter_keys() {
+			NameBuckets::<T>::mutate(Pallet::<T>::name_bucket(&name), |count| *count += 1);
+			counted += 1;
+		}
+
+		StorageVersion::new(5).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1 + 2 * counted, 1 + counted)
+	}
+}
+
+/// Version 6 queues the commits and names expiring after the upgrade in `ExpiryQueue`.
+pub mod v6 {
+	use super::*;
+
+	/// Queue the existing commits and names and start processing them from the current block.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 6 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		ExpiryCursor::<T>::put(now);
+
+		let mut queued: Weight = 0;
+		for (id, commit, lock_period) in LockPeriods::<T>::iter() {
+			Pallet::<T>::schedule_expiry(lock_period.end, Expiry::Commit(id, commit));
+			queued += 1;
+		}
+		for (name, owner) in Owners::<T>::iter() {
+			Pallet::<T>::schedule_expiry(owner.lock_period.end, Expiry::Name(name));
+			queued += 1;
+		}
+
+		StorageVersion::new(6).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(2 + queued, 2 + queued)
+	}
+}
+
+/// Version 7 records how and when the owner of each name came by it.
+pub mod v7 {
+	use super::*;
+
+	/// An owner as stored before version 7.
+	#[derive(Decode)]
+	struct OldOwner<AccountId, Hash, BlockNumber, Balance> {
+		id: AccountId,
+		commit: Hash,
+		lock_period: LockPeriod<BlockNumber>,
+		deposit: Balance,
+	}
+
+	/// A takedown as stored before version 7, holding an owner of the old layout.
+	#[derive(Decode)]
+	struct OldTakedown<AccountId, Hash, BlockNumber, Balance> {
+		owner: OldOwner<AccountId, Hash, BlockNumber, Balance>,
+		taken_at: BlockNumber,
+		deadline: BlockNumber,
+		appeal_bond: Option<Balance>,
+	}
+
+	type OldOwnerOf<T> = OldOwner<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::Hash,
+		<T as frame_system::Config>::BlockNumber,
+		BalanceOf<T>,
+	>;
+	type OldTakedownOf<T> = OldTakedown<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::Hash,
+		<T as frame_system::Config>::BlockNumber,
+		BalanceOf<T>,
+	>;
+
+	/// The owner of the new layout, taken to have revealed the name at its commit block as the
+	/// actual block is not known.
+	fn translate<T: Config>(old: OldOwnerOf<T>) -> OwnerOf<T> {
+		Owner {
+			acquired_at: old.lock_period.begin,
+			id: old.id,
+			commit: old.commit,
+			lock_period: old.lock_period,
+			deposit: old.deposit,
+			acquisition: Acquisition::Reveal,
+			renewals: 0,
+		}
+	}
+
+	/// Translate the owners to the new layout, in `Owners`, in their child tries and in the
+	/// pending `Takedowns`.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 7 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated: Weight = 0;
+		Owners::<T>::translate::<OldOwnerOf<T>, _>(|name, old| {
+			translated += 1;
+			let owner = translate::<T>(old);
+			let key = OwnerTrieKey::<T::Hash, T::Name>::Name(name).encode();
+			child::put(&Pallet::<T>::owner_trie(&owner.id), &key, &owner);
+			Some(owner)
+		});
+		let mut takedowns: Weight = 0;
+		Takedowns::<T>::translate::<OldTakedownOf<T>, _>(|_, old| {
+			takedowns += 1;
+			Some(Takedown {
+				owner: translate::<T>(old.owner),
+				taken_at: old.taken_at,
+				deadline: old.deadline,
+				appeal_bond: old.appeal_bond,
+			})
+		});
+
+		StorageVersion::new(7).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1 + translated + takedowns, 1 + 2 * translated + takedowns)
+	}
+}
+
+/// Version 8 records the status of every name in `NameStatuses`.
+pub mod v8 {
+	use super::*;
+
+	/// Record every registered name as disputed if it is reported, as pending transfer if one is
+	/// scheduled, and as active otherwise. No name is in its grace period or frozen yet.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 8 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut names: Weight = 0;
+		for name in Owners::<T>::iter_keys() {
+			names += 1;
+			let status = if SquattingReports::<T>::contains_key(&name) {
+				NameStatus::Disputed
+			} else if ScheduledTransfers::<T>::contains_key(&name) {
+				NameStatus::PendingTransfer
+			} else {
+				NameStatus::Active
+			};
+			NameStatuses::<T>::insert(name, status);
+		}
+
+		StorageVersion::new(8).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1 + 3 * names, 1 + names)
+	}
+}
+
+/// Move all deposits over to the configured `DepositBackend` if it differs from the active one.
+///
+/// An account whose free balance can not cover the reserve when moving from locks to reserves
+/// keeps its lock, so its commits and names stay backed either way. So do the accounts backing
+/// their deposits with their locked balance.
+pub fn switch_deposit_backend<T: Config>() -> Weight {
+	let target = T::DepositBackend::get();
+	let active = ActiveBackend::<T>::get();
+	if active == target {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut accounts: Vec<T::AccountId> = LockPeriods::<T>::iter_keys().map(|(id, _)| id).collect();
+	accounts.extend(OwnershipDeposits::<T>::iter_keys());
+	accounts.extend(ReservedDeposits::<T>::iter_keys());
+	accounts.sort();
+	accounts.dedup();
+
+	ActiveBackend::<T>::put(target);
+
+	for id in accounts.iter() {
+		match target {
+			DepositBackend::Reserve =>
+				if !LockBackedAccounts::<T>::contains_key(id) &&
+					Pallet::<T>::update_locked_fund(id).is_ok()
+				{
+					T::Currency::remove_lock(T::ModuleId::get(), id);
+				},
+			DepositBackend::Lock => {
+				let reserved = ReservedDeposits::<T>::take(id);
+				T::Currency::unreserve_named(&T::ModuleId::get(), id, reserved);
+				Pallet::<T>::release_held_fund(id);
+			},
+		}
+	}
+
+	let migrated = accounts.len() as Weight;
+	T::DbWeight::get().reads_writes(2 + 3 * migrated, 1 + 3 * migrated)
+}
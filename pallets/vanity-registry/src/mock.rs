@@ -1,16 +1,43 @@
 use super::*;
 use crate as vanity_registry;
 
-use frame_support::{construct_runtime, parameter_types};
+use frame_support::{
+	construct_runtime, ord_parameter_types, parameter_types,
+	traits::{
+		schedule::{Period, Priority},
+		Contains,
+	},
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestSignature, TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, Dispatchable, Extrinsic as ExtrinsicT, IdentityLookup},
 };
 
+use std::cell::RefCell;
+
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
+thread_local! {
+	static PRICE: RefCell<Option<u64>> = RefCell::new(None);
+	static REGISTRATION_FEE: RefCell<u32> = RefCell::new(0);
+	static RENEWAL_FEE: RefCell<u32> = RefCell::new(0);
+	static NAME_BYTE_DEPOSIT: RefCell<u64> = RefCell::new(0);
+	static GRACE_PERIOD: RefCell<u64> = RefCell::new(0);
+	static DEPOSIT_BACKEND: RefCell<DepositBackend> = RefCell::new(DepositBackend::Lock);
+	static EXISTENTIAL_DEPOSIT: RefCell<u64> = RefCell::new(1);
+	static COLLECTED: RefCell<u64> = RefCell::new(0);
+	static VERIFIED: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+	static RANDOM_SEED: RefCell<H256> = RefCell::new(H256::zero());
+	static MAX_REVEALS_PER_BLOCK: RefCell<u32> = RefCell::new(100);
+	static MAX_EXPIRIES_PER_BLOCK: RefCell<u32> = RefCell::new(1_000);
+	static LOCKED: RefCell<Vec<(u64, u64)>> = RefCell::new(Vec::new());
+	static TENURE_MILESTONES: RefCell<Vec<(u64, Perbill)>> = RefCell::new(Vec::new());
+	static SCHEDULED: RefCell<Vec<(Vec<u8>, u64, OriginCaller, Call)>> = RefCell::new(Vec::new());
+}
+
 construct_runtime!(
 	pub enum Test where
 		Block = Block,
@@ -46,14 +73,26 @@ impl frame_system::Config for Test {
 	type PalletInfo = PalletInfo;
 	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
-	type OnKilledAccount = ();
+	type OnKilledAccount = VanityRegistry;
 	type SystemWeightInfo = ();
 	type SS58Prefix = ();
 	type OnSetCode = ();
 }
 
 parameter_types! {
-	pub const ExistentialDeposit: u64 = 1;
+	pub const MaxReserves: u32 = 50;
+}
+
+pub struct ExistentialDeposit;
+impl ExistentialDeposit {
+	pub fn set(amount: u64) {
+		EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = amount);
+	}
+}
+impl Get<u64> for ExistentialDeposit {
+	fn get() -> u64 {
+		EXISTENTIAL_DEPOSIT.with(|v| *v.borrow())
+	}
 }
 
 impl pallet_balances::Config for Test {
@@ -64,28 +103,451 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 	type WeightInfo = ();
 	type MaxLocks = ();
-	type MaxReserves = ();
+	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
 }
 
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = TestXt<Call, ()>;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	fn create_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+		call: Call,
+		_public: UintAuthorityId,
+		_account: u64,
+		nonce: u64,
+	) -> Option<(Call, <TestXt<Call, ()> as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+/// Signs the squatting reports of the off-chain worker in tests.
+pub struct TestAuthId;
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+	type GenericPublic = UintAuthorityId;
+}
+
 parameter_types! {
 		pub const VanityRegistryId: LockIdentifier = *b"registry";
 		pub const RegisterPeriod: <Test as frame_system::Config>::BlockNumber = 95;
 		pub const FundToLock: <Test as pallet_balances::Config>::Balance = 57;
+		pub const OwnershipDeposit: <Test as pallet_balances::Config>::Balance = 43;
+		pub const ForcedRemovalSlash: Perbill = Perbill::from_percent(25);
+		pub const DelegatorFeeShare: Perbill = Perbill::from_percent(10);
+		pub const MaxJointOwners: u32 = 3;
+		pub const ApprovalPeriod: <Test as frame_system::Config>::BlockNumber = 10;
+		pub const MaxShareholders: u32 = 4;
+		pub const MaxPoolContributors: u32 = 2;
+		pub const MaxRoles: u32 = 2;
+		pub const MaxRoleLen: u32 = 16;
+		pub const MaxGuardians: u32 = 3;
+		pub const RecoveryDelay: <Test as frame_system::Config>::BlockNumber = 5;
+		pub const MinInactivityPeriod: <Test as frame_system::Config>::BlockNumber = 10;
+		pub const MaxBundleLen: u32 = 3;
+		pub const MaxCommitSalts: u32 = 2;
+		pub const MaxProofLen: u32 = 4;
+		pub const MaxRevealProofLen: u32 = 32;
+		pub const MaxVoucherCodeLen: u32 = 16;
+		pub const GiftRejectionPeriod: <Test as frame_system::Config>::BlockNumber = 10;
+		pub const WatchDeposit: <Test as pallet_balances::Config>::Balance = 5;
+		pub const MaxWatchers: u32 = 2;
+		pub const WatchPriorityPeriod: <Test as frame_system::Config>::BlockNumber = 10;
 		pub const NameMaxLen: u32 = 255;
+		pub const MaxNameBytes: u32 = 4 * 255;
+		pub const ShortNameLen: u32 = 3;
+		pub const InstantRegistrationLen: u32 = 10;
+		pub const MaxChallengeLen: u32 = 32;
+		pub const MaxPrefixLen: u32 = 4;
+		pub const MaxNamesPerPrefix: u32 = 2;
+		pub const ReportBond: <Test as pallet_balances::Config>::Balance = 20;
+		pub const AppealBond: <Test as pallet_balances::Config>::Balance = 30;
+		pub const AppealWindow: <Test as frame_system::Config>::BlockNumber = 20;
+		pub const ReportCooldown: <Test as frame_system::Config>::BlockNumber = 10;
+		pub const SquattingScanInterval: <Test as frame_system::Config>::BlockNumber = 5;
+		pub const MaxRegistrationPeriods: u32 = 5;
+		pub MultiPeriodDiscounts: Vec<(u32, Perbill)> = vec![(3, Perbill::from_percent(50))];
+}
+
+/// A price feed whose price can be set by the tests.
+pub struct MockPriceFeed;
+impl MockPriceFeed {
+	pub fn set(price: Option<u64>) {
+		PRICE.with(|v| *v.borrow_mut() = price);
+	}
+}
+impl PriceFeed<u64> for MockPriceFeed {
+	fn price() -> Option<u64> {
+		PRICE.with(|v| *v.borrow())
+	}
+}
+
+pub struct MockTenureMilestones;
+impl MockTenureMilestones {
+	pub fn set(milestones: Vec<(u64, Perbill)>) {
+		TENURE_MILESTONES.with(|v| *v.borrow_mut() = milestones);
+	}
+}
+impl Get<Vec<(u64, Perbill)>> for MockTenureMilestones {
+	fn get() -> Vec<(u64, Perbill)> {
+		TENURE_MILESTONES.with(|v| v.borrow().clone())
+	}
+}
+
+/// Keeps track of the total amount handed over to it, e.g. fees and slashes.
+pub struct MockFeeHandler;
+impl MockFeeHandler {
+	pub fn collected() -> u64 {
+		COLLECTED.with(|v| *v.borrow())
+	}
+}
+impl OnUnbalanced<NegativeImbalanceOf<Test>> for MockFeeHandler {
+	fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<Test>) {
+		COLLECTED.with(|v| *v.borrow_mut() += amount.peek());
+	}
+}
+
+/// Vouches for the identities of the accounts set by the tests.
+pub struct MockIdentityVerifier;
+impl MockIdentityVerifier {
+	pub fn set(accounts: Vec<u64>) {
+		VERIFIED.with(|v| *v.borrow_mut() = accounts);
+	}
+}
+impl IdentityVerifier<u64> for MockIdentityVerifier {
+	fn has_positive_judgement(who: &u64) -> bool {
+		VERIFIED.with(|v| v.borrow().contains(who))
+	}
+}
+
+/// Accepts blinded commits as well, hashing the name with the blinding factor given as proof
+/// and without the account, alongside the commits of `Pallet::hash_of` given no proof.
+pub struct MockRevealVerifier;
+impl MockRevealVerifier {
+	pub fn blinded_commit(name: &Name, blinding: &[u8]) -> H256 {
+		BlakeTwo256::hash_of(&(name, blinding))
+	}
+}
+impl RevealVerifier<u64, Name, H256> for MockRevealVerifier {
+	fn commit_of(_who: &u64, name: &Name, hashed: H256, proof: &[u8]) -> Option<H256> {
+		if proof.is_empty() {
+			Some(hashed)
+		} else {
+			Some(Self::blinded_commit(name, proof))
+		}
+	}
+
+	fn weight(_len: u32) -> Weight {
+		0
+	}
+}
+
+/// Derives the randomness of every subject from the seed set by the tests.
+pub struct MockRandomness;
+impl MockRandomness {
+	pub fn set(seed: H256) {
+		RANDOM_SEED.with(|v| *v.borrow_mut() = seed);
+	}
+}
+impl Randomness<H256, u64> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		let seed = RANDOM_SEED.with(|v| *v.borrow());
+		(BlakeTwo256::hash_of(&(seed, subject)), System::block_number())
+	}
+}
+
+pub struct MockDepositBackend;
+impl MockDepositBackend {
+	pub fn set(backend: DepositBackend) {
+		DEPOSIT_BACKEND.with(|v| *v.borrow_mut() = backend);
+	}
+}
+impl Get<DepositBackend> for MockDepositBackend {
+	fn get() -> DepositBackend {
+		DEPOSIT_BACKEND.with(|v| *v.borrow())
+	}
+}
+
+pub struct MockLockedBalances;
+impl MockLockedBalances {
+	pub fn set(who: u64, amount: u64) {
+		LOCKED.with(|v| {
+			let mut locked = v.borrow_mut();
+			locked.retain(|(account, _)| *account != who);
+			locked.push((who, amount));
+		});
+	}
 }
+impl LockedBalanceInspector<u64, u64> for MockLockedBalances {
+	fn locked_balance(who: &u64) -> u64 {
+		LOCKED.with(|v| v.borrow().iter().find(|(account, _)| account == who).map_or(0, |l| l.1))
+	}
+}
+
+pub struct MaxRevealsPerBlock;
+impl MaxRevealsPerBlock {
+	pub fn set(max: u32) {
+		MAX_REVEALS_PER_BLOCK.with(|v| *v.borrow_mut() = max);
+	}
+}
+impl Get<u32> for MaxRevealsPerBlock {
+	fn get() -> u32 {
+		MAX_REVEALS_PER_BLOCK.with(|v| *v.borrow())
+	}
+}
+
+pub struct MaxExpiriesPerBlock;
+impl MaxExpiriesPerBlock {
+	pub fn set(max: u32) {
+		MAX_EXPIRIES_PER_BLOCK.with(|v| *v.borrow_mut() = max);
+	}
+}
+impl Get<u32> for MaxExpiriesPerBlock {
+	fn get() -> u32 {
+		MAX_EXPIRIES_PER_BLOCK.with(|v| *v.borrow())
+	}
+}
+
+/// Keeps the scheduled calls until the tests run them.
+pub struct MockScheduler;
+impl MockScheduler {
+	/// The ids and blocks of the scheduled calls.
+	pub fn scheduled() -> Vec<(Vec<u8>, u64)> {
+		SCHEDULED.with(|v| v.borrow().iter().map(|(id, when, ..)| (id.clone(), *when)).collect())
+	}
+
+	/// Dispatch and forget the calls due at or before "now".
+	pub fn run(now: u64) {
+		let due = SCHEDULED.with(|v| {
+			let (due, rest): (Vec<_>, Vec<_>) =
+				v.borrow_mut().drain(..).partition(|(_, when, ..)| *when <= now);
+			*v.borrow_mut() = rest;
+			due
+		});
+		for (_, _, origin, call) in due {
+			let _ = call.dispatch(origin.into());
+		}
+	}
+}
+impl ScheduleNamed<u64, Call, OriginCaller> for MockScheduler {
+	type Address = u64;
+
+	fn schedule_named(
+		id: Vec<u8>,
+		when: DispatchTime<u64>,
+		_maybe_periodic: Option<Period<u64>>,
+		_priority: Priority,
+		origin: OriginCaller,
+		call: Call,
+	) -> Result<u64, ()> {
+		let when = match when {
+			DispatchTime::At(at) => at,
+			DispatchTime::After(after) => System::block_number() + after + 1,
+		};
+		SCHEDULED.with(|v| {
+			let mut scheduled = v.borrow_mut();
+			if scheduled.iter().any(|(other, ..)| *other == id) {
+				return Err(())
+			}
+			scheduled.push((id, when, origin, call));
+			Ok(when)
+		})
+	}
+
+	fn cancel_named(id: Vec<u8>) -> Result<(), ()> {
+		SCHEDULED.with(|v| {
+			let mut scheduled = v.borrow_mut();
+			let index = scheduled.iter().position(|(other, ..)| *other == id).ok_or(())?;
+			scheduled.remove(index);
+			Ok(())
+		})
+	}
+
+	fn reschedule_named(_id: Vec<u8>, _when: DispatchTime<u64>) -> Result<u64, DispatchError> {
+		Err(DispatchError::Other("not supported by the mock"))
+	}
+
+	fn next_dispatch_time(id: Vec<u8>) -> Result<u64, ()> {
+		SCHEDULED.with(|v| {
+			v.borrow()
+				.iter()
+				.find(|(other, ..)| *other == id)
+				.map(|(_, when, ..)| *when)
+				.ok_or(())
+		})
+	}
+}
+
+pub struct RegistrationFee;
+impl RegistrationFee {
+	pub fn set(fee: u32) {
+		REGISTRATION_FEE.with(|v| *v.borrow_mut() = fee);
+	}
+}
+impl Get<u32> for RegistrationFee {
+	fn get() -> u32 {
+		REGISTRATION_FEE.with(|v| *v.borrow())
+	}
+}
+
+pub struct GracePeriod;
+impl GracePeriod {
+	pub fn set(period: u64) {
+		GRACE_PERIOD.with(|v| *v.borrow_mut() = period);
+	}
+}
+impl Get<u64> for GracePeriod {
+	fn get() -> u64 {
+		GRACE_PERIOD.with(|v| *v.borrow())
+	}
+}
+
+pub struct NameByteDeposit;
+impl NameByteDeposit {
+	pub fn set(deposit: u64) {
+		NAME_BYTE_DEPOSIT.with(|v| *v.borrow_mut() = deposit);
+	}
+}
+impl Get<u64> for NameByteDeposit {
+	fn get() -> u64 {
+		NAME_BYTE_DEPOSIT.with(|v| *v.borrow())
+	}
+}
+
+pub struct RenewalFee;
+impl RenewalFee {
+	pub fn set(fee: u32) {
+		RENEWAL_FEE.with(|v| *v.borrow_mut() = fee);
+	}
+}
+impl Get<u32> for RenewalFee {
+	fn get() -> u32 {
+		RENEWAL_FEE.with(|v| *v.borrow())
+	}
+}
+
+/// The names of the tests, bounded to hold `NameMaxLen` characters of any width.
+pub type Name = BoundedVec<u8, MaxNameBytes>;
+
+/// Turns the byte strings of the tests into names.
+pub trait ToName {
+	fn to_name(&self) -> Name;
+}
+impl ToName for [u8] {
+	fn to_name(&self) -> Name {
+		Name::try_from(self.to_vec()).expect("names in tests are within bounds")
+	}
+}
+
+ord_parameter_types! {
+	pub const Council: u64 = 9;
+}
+
+/// The names only `Council` may unregister.
+pub struct CriticalNames;
+impl Contains<Name> for CriticalNames {
+	fn contains(name: &Name) -> bool {
+		&name[..] == b"treasury"
+	}
+}
+
 impl Config for Test {
 	type Event = Event;
 	type Currency = Balances;
 	type ModuleId = VanityRegistryId;
+	type LockReasons = DefaultLockReasons;
+	type DepositBackend = MockDepositBackend;
+	type LockedBalances = MockLockedBalances;
 	type RegisterPeriod = RegisterPeriod;
+	type GracePeriod = GracePeriod;
 	type FundToLock = FundToLock;
-	type Name = Vec<u8>;
+	type OwnershipDeposit = OwnershipDeposit;
+	type NameByteDeposit = NameByteDeposit;
+	type ForcedRemovalSlash = ForcedRemovalSlash;
+	type Name = Name;
 	type NameMaxLen = NameMaxLen;
+	type MaxRevealsPerBlock = MaxRevealsPerBlock;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type ShortNameLen = ShortNameLen;
+	type InstantRegistrationLen = InstantRegistrationLen;
+	type IdentityVerifier = MockIdentityVerifier;
+	type NamePolicy = DenyList<Test>;
+	type CommitHashing = BlakeTwo256;
+	type Randomness = MockRandomness;
+	type RevealVerifier = MockRevealVerifier;
+	type MaxChallengeLen = MaxChallengeLen;
+	type MaxPrefixLen = MaxPrefixLen;
+	type MaxNamesPerPrefix = MaxNamesPerPrefix;
+	type PriceFeed = MockPriceFeed;
+	type RegistrationFee = RegistrationFee;
+	type RenewalFee = RenewalFee;
+	type TenureDiscount = TenureMilestones<MockTenureMilestones>;
+	type MaxRegistrationPeriods = MaxRegistrationPeriods;
+	type MultiPeriodDiscounts = MultiPeriodDiscounts;
+	type FeeHandler = MockFeeHandler;
+	type DelegatorFeeShare = DelegatorFeeShare;
+	type MaxJointOwners = MaxJointOwners;
+	type ApprovalPeriod = ApprovalPeriod;
+	type MaxShareholders = MaxShareholders;
+	type MaxPoolContributors = MaxPoolContributors;
+	type MaxRoles = MaxRoles;
+	type MaxRoleLen = MaxRoleLen;
+	type MaxGuardians = MaxGuardians;
+	type RecoveryDelay = RecoveryDelay;
+	type MinInactivityPeriod = MinInactivityPeriod;
+	type MaxBundleLen = MaxBundleLen;
+	type MaxCommitSalts = MaxCommitSalts;
+	type MaxProofLen = MaxProofLen;
+	type MaxRevealProofLen = MaxRevealProofLen;
+	type MaxVoucherCodeLen = MaxVoucherCodeLen;
+	type GiftRejectionPeriod = GiftRejectionPeriod;
+	type WatchDeposit = WatchDeposit;
+	type MaxWatchers = MaxWatchers;
+	type WatchPriorityPeriod = WatchPriorityPeriod;
+	type AdminOrigin = EnsureRoot<u64>;
+	type RecordOrigin = SignedOrigin;
+	type TransferOrigin = SignedOrigin;
+	type UnregisterOrigin = StrongerOriginFor<CriticalNames, EnsureSignedBy<Council, u64>>;
+	type AuthorityId = TestAuthId;
+	type ReportBond = ReportBond;
+	type AppealBond = AppealBond;
+	type AppealWindow = AppealWindow;
+	type ReportCooldown = ReportCooldown;
+	type SquattingScanInterval = SquattingScanInterval;
+	type RegistryCall = Call;
+	type PalletsOrigin = OriginCaller;
+	type Scheduler = MockScheduler;
 	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+	MockPriceFeed::set(None);
+	RegistrationFee::set(0);
+	RenewalFee::set(0);
+	MockDepositBackend::set(DepositBackend::Lock);
+	ExistentialDeposit::set(1);
+	COLLECTED.with(|v| *v.borrow_mut() = 0);
+	MockIdentityVerifier::set(Vec::new());
+	MockRandomness::set(H256::zero());
+	MaxRevealsPerBlock::set(100);
+	MaxExpiriesPerBlock::set(1_000);
+	LOCKED.with(|v| v.borrow_mut().clear());
+	TENURE_MILESTONES.with(|v| v.borrow_mut().clear());
+	SCHEDULED.with(|v| v.borrow_mut().clear());
 	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
 }
@@ -0,0 +1,509 @@
+//! Read access to the storage of the pallet.
+//!
+//! The `active_*` queries hide the entries which have run out but were not cleaned up yet, e.g.
+//! names whose expiry was carried over to a later block. The plain getters return the stored
+//! entries as they are.
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+	/// The owner of "name", in normalized form, unless its registration has run out.
+	pub fn active_owner(name: &T::Name) -> Option<OwnerOf<T>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		Owners::<T>::get(name).filter(|owner| owner.lock_period.end > now)
+	}
+
+	/// The lock period of the commit "hash" of "who", unless it has expired.
+	pub fn active_commit(who: &T::AccountId, hash: &T::Hash) -> Option<LockPeriod<T::BlockNumber>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		LockPeriods::<T>::get(who, hash).filter(|period| period.end > now)
+	}
+
+	/// The approvals collected for "action" on "name", unless they have become void.
+	pub fn active_pending_action(
+		name: &T::Name,
+		action: &JointActionOf<T>,
+	) -> Option<PendingActionOf<T>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		PendingActions::<T>::get(name, action).filter(|pending| pending.expires_at > now)
+	}
+
+	/// See `LockPeriods`.
+	pub fn lock_periods<KArg1, KArg2>(who: KArg1, hash: KArg2) -> Option<LockPeriod<T::BlockNumber>>
+	where
+		KArg1: EncodeLike<T::AccountId>,
+		KArg2: EncodeLike<T::Hash>,
+	{
+		LockPeriods::<T>::get(who, hash)
+	}
+
+	/// See `CommitDeposits`.
+	pub fn commit_deposits<KArg1, KArg2>(who: KArg1, hash: KArg2) -> Option<BalanceOf<T>>
+	where
+		KArg1: EncodeLike<T::AccountId>,
+		KArg2: EncodeLike<T::Hash>,
+	{
+		CommitDeposits::<T>::get(who, hash)
+	}
+
+	/// See `Owners`, `Pallet::active_owner` leaves out the registrations which have run out.
+	pub fn owners<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<OwnerOf<T>> {
+		Owners::<T>::get(name)
+	}
+
+	/// See `OwnershipDeposits`.
+	pub fn ownership_deposits<KArg: EncodeLike<T::AccountId>>(who: KArg) -> BalanceOf<T> {
+		OwnershipDeposits::<T>::get(who)
+	}
+
+	/// See `DepositBeneficiaries`.
+	pub fn deposit_beneficiaries<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<T::AccountId> {
+		DepositBeneficiaries::<T>::get(name)
+	}
+
+	/// See `DepositOffers`.
+	pub fn deposit_offers<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<T::AccountId> {
+		DepositOffers::<T>::get(name)
+	}
+
+	/// See `DepositDelegations`.
+	pub fn deposit_delegations<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<T::AccountId> {
+		DepositDelegations::<T>::get(name)
+	}
+
+	/// See `NameCasings`.
+	pub fn name_casings<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<T::Name> {
+		NameCasings::<T>::get(name)
+	}
+
+	/// See `NameHashIndex`.
+	pub fn name_hash_index<KArg: EncodeLike<T::Hash>>(hash: KArg) -> Option<T::Name> {
+		NameHashIndex::<T>::get(hash)
+	}
+
+	/// See `Takedowns`.
+	pub fn takedowns<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<TakedownOf<T>> {
+		Takedowns::<T>::get(name)
+	}
+
+	/// See `JointOwners`.
+	pub fn joint_owners<KArg: EncodeLike<T::Name>>(
+		name: KArg,
+	) -> Option<JointOwnership<MembersOf<T>>> {
+		JointOwners::<T>::get(name)
+	}
+
+	/// See `PendingActions`, `Pallet::active_pending_action` leaves out the void approvals.
+	pub fn pending_actions<KArg1, KArg2>(name: KArg1, action: KArg2) -> Option<PendingActionOf<T>>
+	where
+		KArg1: EncodeLike<T::Name>,
+		KArg2: EncodeLike<JointActionOf<T>>,
+	{
+		PendingActions::<T>::get(name, action)
+	}
+
+	/// See `NameShares`.
+	pub fn name_shares<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<SharesOf<T>> {
+		NameShares::<T>::get(name)
+	}
+
+	/// See `RenewalVotes`.
+	pub fn renewal_votes<KArg: EncodeLike<T::Name>>(name: KArg) -> VotersOf<T> {
+		RenewalVotes::<T>::get(name)
+	}
+
+	/// See `RenewalPools`.
+	pub fn renewal_pools<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<ContributionsOf<T>> {
+		RenewalPools::<T>::get(name)
+	}
+
+	/// See `NameBuckets`.
+	pub fn name_bucket_count<KArg: EncodeLike<u16>>(bucket: KArg) -> u32 {
+		NameBuckets::<T>::get(bucket)
+	}
+
+	/// See `NamePrefixes`.
+	pub fn name_prefixes<KArg: EncodeLike<PrefixOf<T>>>(
+		prefix: KArg,
+	) -> BoundedVec<T::Name, T::MaxNamesPerPrefix> {
+		NamePrefixes::<T>::get(prefix)
+	}
+
+	/// See `ActiveBackend`.
+	pub fn active_backend() -> DepositBackend {
+		ActiveBackend::<T>::get()
+	}
+
+	/// Whether "who" backs its deposits with the balance locked by other pallets, see
+	/// `LockBackedAccounts`.
+	pub fn is_lock_backed<KArg: EncodeLike<T::AccountId>>(who: KArg) -> bool {
+		LockBackedAccounts::<T>::contains_key(who)
+	}
+
+	/// See `ReservedDeposits`.
+	pub fn reserved_deposits<KArg: EncodeLike<T::AccountId>>(who: KArg) -> BalanceOf<T> {
+		ReservedDeposits::<T>::get(who)
+	}
+
+	/// See `FallbackPrice`.
+	pub fn fallback_price() -> Option<BalanceOf<T>> {
+		FallbackPrice::<T>::get()
+	}
+
+	/// See `RenewalFeeCeiling`.
+	pub fn renewal_fee_ceiling() -> Option<BalanceOf<T>> {
+		RenewalFeeCeiling::<T>::get()
+	}
+
+	/// See `SquattingReports`.
+	pub fn squatting_reports<KArg: EncodeLike<T::Name>>(
+		name: KArg,
+	) -> Option<SquattingReportOf<T>> {
+		SquattingReports::<T>::get(name)
+	}
+
+	/// See `LastReportAt`.
+	pub fn last_report_at<KArg: EncodeLike<T::AccountId>>(who: KArg) -> Option<T::BlockNumber> {
+		LastReportAt::<T>::get(who)
+	}
+
+	/// See `ExpiryCursor`.
+	pub fn expiry_cursor() -> T::BlockNumber {
+		ExpiryCursor::<T>::get()
+	}
+
+	/// See `ScheduledActions`.
+	pub fn scheduled_actions<KArg1, KArg2>(name: KArg1, action: KArg2) -> Option<T::BlockNumber>
+	where
+		KArg1: EncodeLike<T::Name>,
+		KArg2: EncodeLike<ScheduledAction>,
+	{
+		ScheduledActions::<T>::get(name, action)
+	}
+
+	/// See `RevealCount`.
+	pub fn reveal_count() -> u32 {
+		RevealCount::<T>::get()
+	}
+
+	/// See `NameRoles`.
+	pub fn name_roles<KArg: EncodeLike<T::Name>>(name: KArg) -> RolesOf<T> {
+		NameRoles::<T>::get(name)
+	}
+
+	/// See `Guardians`.
+	pub fn guardians<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<GuardianshipOf<T>> {
+		Guardians::<T>::get(name)
+	}
+
+	/// See `Recoveries`.
+	pub fn recoveries<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<RecoveryOf<T>> {
+		Recoveries::<T>::get(name)
+	}
+
+	/// See `Heirs`.
+	pub fn heirs<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<HeirOf<T>> {
+		Heirs::<T>::get(name)
+	}
+
+	/// See `ScheduledTransfers`.
+	pub fn scheduled_transfers<KArg: EncodeLike<T::Name>>(
+		name: KArg,
+	) -> Option<ScheduledTransferOf<T>> {
+		ScheduledTransfers::<T>::get(name)
+	}
+
+	/// See `RenewalCredits`.
+	pub fn renewal_credits<KArg: EncodeLike<T::AccountId>>(who: KArg) -> BalanceOf<T> {
+		RenewalCredits::<T>::get(who)
+	}
+
+	/// See `Gifts`.
+	pub fn gifts<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<GiftOf<T>> {
+		Gifts::<T>::get(name)
+	}
+
+	/// Whether "name", in normalized form, has been granted permanently, see `PermanentNames`.
+	pub fn is_permanent(name: &T::Name) -> bool {
+		PermanentNames::<T>::contains_key(name)
+	}
+
+	/// The status of "name", in normalized form, or None if it is not registered, see
+	/// `NameStatuses`.
+	pub fn name_status<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<NameStatus> {
+		NameStatuses::<T>::get(name)
+	}
+
+	/// How often "name", in normalized form, has changed hands and been renewed since it was
+	/// registered, see `NameHistories`.
+	pub fn name_history<KArg: EncodeLike<T::Name>>(name: KArg) -> NameHistory {
+		NameHistories::<T>::get(name)
+	}
+
+	/// See `PaymentOffers`.
+	pub fn payment_offers<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<T::AccountId> {
+		PaymentOffers::<T>::get(name)
+	}
+
+	/// See `Payers`.
+	pub fn payers<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<T::AccountId> {
+		Payers::<T>::get(name)
+	}
+
+	/// See `PendingRekeys`.
+	pub fn pending_rekeys<KArg: EncodeLike<T::Name>>(name: KArg) -> Option<T::AccountId> {
+		PendingRekeys::<T>::get(name)
+	}
+
+	/// See `MigrationConsents`.
+	pub fn migration_consents<KArg: EncodeLike<T::AccountId>>(who: KArg) -> Option<T::AccountId> {
+		MigrationConsents::<T>::get(who)
+	}
+
+	/// See `Watchers`.
+	pub fn watchers<KArg: EncodeLike<T::Hash>>(hash: KArg) -> WatchersOf<T> {
+		Watchers::<T>::get(hash)
+	}
+
+	/// See `PriorityWindows`.
+	pub fn priority_windows<KArg: EncodeLike<T::Hash>>(hash: KArg) -> Option<PriorityWindowOf<T>> {
+		PriorityWindows::<T>::get(hash)
+	}
+
+	/// The recovery of "name" being voted on, unless its votes have become void.
+	pub fn active_recovery(name: &T::Name) -> Option<RecoveryOf<T>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		Recoveries::<T>::get(name)
+			.filter(|recovery| recovery.executable_at.is_some() || recovery.expires_at > now)
+	}
+
+	/// The gift "name" has been received as, while its recipient may still reject it.
+	pub fn pending_gift(name: &T::Name) -> Option<GiftOf<T>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		Gifts::<T>::get(name).filter(|gift| gift.rejectable_until >= now)
+	}
+
+	/// The owner of "name" in any casing, if registered.
+	pub fn owner_of(name: &T::Name) -> Option<T::AccountId> {
+		Self::owners(Self::normalize(name)).map(|owner| owner.id)
+	}
+
+	/// The account filling "role" under "name" in any casing, see `Pallet::set_role`.
+	pub fn role_of(name: &T::Name, role: &[u8]) -> Option<T::AccountId> {
+		Self::name_roles(Self::normalize(name))
+			.into_iter()
+			.find(|(filled, _)| filled[..] == *role)
+			.map(|(_, account)| account)
+	}
+
+	/// What "name" in any casing resolves to, or None if it is not registered or its
+	/// registration has run out. A name reported for squatting is suspended until the report is
+	/// resolved.
+	pub fn resolve(name: &T::Name) -> Option<Resolution<T::AccountId>> {
+		let name = Self::normalize(name);
+		let owner = Self::active_owner(&name)?;
+		if SquattingReports::<T>::contains_key(&name) {
+			Some(Resolution::Suspended)
+		} else {
+			Some(Resolution::Owner(owner.id))
+		}
+	}
+
+	/// The details of "name" in any casing, or None if it is not registered.
+	pub fn registration_details(name: &T::Name) -> Option<RegistrationDetailsOf<T>> {
+		let name = Self::normalize(name);
+		let owner = Self::owners(&name)?;
+		let renewal_pool = Self::renewal_pools(&name).map_or_else(Zero::zero, |pool| {
+			pool.iter().fold(Zero::zero(), |total: BalanceOf<T>, (_, contribution)| {
+				total.saturating_add(*contribution)
+			})
+		});
+		Some(RegistrationDetails {
+			deposit_holder: Self::deposit_holder(&name, &owner.id),
+			display_name: Self::name_casings(&name).unwrap_or_else(|| name.clone()),
+			renewal_pool,
+			jointly_owned: JointOwners::<T>::contains_key(&name),
+			fractionalized: NameShares::<T>::contains_key(&name),
+			disputed: SquattingReports::<T>::contains_key(&name),
+			owner: owner.id,
+			lock_period: owner.lock_period,
+			deposit: owner.deposit,
+			acquisition: owner.acquisition,
+			acquired_at: owner.acquired_at,
+			renewals: owner.renewals,
+		})
+	}
+
+	/// The details, status, records and pending transfer of "name" in any casing, or None if it
+	/// is not registered.
+	pub fn registration(name: &T::Name) -> Option<RegistrationInfoOf<T>> {
+		let details = Self::registration_details(name)?;
+		let name = Self::normalize(name);
+		Some(RegistrationInfo {
+			status: Self::name_status(&name).unwrap_or(NameStatus::Active),
+			expires_at: Self::lapses_at(&name, details.lock_period.end),
+			records: Self::name_roles(&name).len() as u32,
+			history: Self::name_history(&name),
+			pending_transfer: Self::scheduled_transfers(&name),
+			details,
+		})
+	}
+
+	/// The records of "name" in any casing laid out like those of an ENS resolver, or None if it
+	/// is not registered.
+	pub fn ens_metadata(name: &T::Name) -> Option<EnsMetadataOf<T>> {
+		let info = Self::registration(name)?;
+		let name = Self::normalize(name);
+		let addr = match Self::resolve(&name) {
+			Some(Resolution::Owner(id)) => Some(id),
+			_ => None,
+		};
+		let addresses = Self::name_roles(&name)
+			.into_iter()
+			.map(|(role, account)| (role.into_inner(), account))
+			.collect();
+		Some(EnsMetadata {
+			name: info.details.display_name,
+			owner: info.details.owner,
+			expiry: info.expires_at,
+			addr,
+			addresses,
+		})
+	}
+
+	/// Whether "name" in any casing is free to be registered. Most free names are told apart by
+	/// an empty bucket in `NameBuckets`, without reading `Owners`.
+	pub fn is_available(name: &T::Name) -> bool {
+		let name = Self::normalize(name);
+		Self::name_bucket_count(Self::name_bucket(&name)) == 0 || !Owners::<T>::contains_key(&name)
+	}
+
+	/// The owner of the registered name whose hash is "hash", see `Pallet::name_hash`.
+	pub fn owner_of_hash(hash: T::Hash) -> Option<T::AccountId> {
+		Self::name_hash_index(hash).and_then(|name| Self::owner_of(&name))
+	}
+
+	/// A page of at most "limit" names with their owners, starting after "start_key" which is the
+	/// cursor of the previous page, i.e. a raw `Owners` storage key.
+	pub fn list_names(start_key: Option<Vec<u8>>, limit: u32) -> Page<(T::Name, T::AccountId)> {
+		let iter = match start_key {
+			Some(key) => Owners::<T>::iter_from(key),
+			None => Owners::<T>::iter(),
+		};
+		Self::page(iter, limit, |(name, owner)| (name, owner.id))
+	}
+
+	/// A page of at most "limit" pending commits with their accounts and lock periods, starting
+	/// after "start_key" which is the cursor of the previous page, i.e. a raw `LockPeriods` storage
+	/// key.
+	pub fn list_commits(
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+	) -> Page<(T::AccountId, T::Hash, LockPeriod<T::BlockNumber>)> {
+		let iter = match start_key {
+			Some(key) => LockPeriods::<T>::iter_from(key),
+			None => LockPeriods::<T>::iter(),
+		};
+		Self::page(iter, limit, |entry| entry)
+	}
+
+	/// Collect at most "limit" items, capped at `MAX_PAGE_SIZE`, from a storage iterator. The
+	/// raw key of the last item is the cursor of the next page, so no call walks the whole map.
+	pub(crate) fn page<Entry, Item>(
+		mut iter: PrefixIterator<Entry>,
+		limit: u32,
+		f: impl FnMut(Entry) -> Item,
+	) -> Page<Item> {
+		let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		let items: Vec<Item> = iter.by_ref().take(limit).map(f).collect();
+		let next = if items.len() == limit { Some(iter.last_raw_key().to_vec()) } else { None };
+		Page { items, next }
+	}
+
+	/// A page of at most "limit" names owned by "who", walking their child trie from "start" which
+	/// is the cursor of the previous page.
+	pub fn list_names_of(who: &T::AccountId, start: Option<Vec<u8>>, limit: u32) -> Page<T::Name> {
+		let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		let trie = Self::owner_trie(who);
+		let mut key = start.unwrap_or_else(|| sp_std::vec![OWNER_TRIE_NAME_PREFIX]);
+		let mut items = Vec::new();
+		while items.len() < limit {
+			match sp_io::default_child_storage::next_key(trie.storage_key(), &key) {
+				Some(next) if next.first() == Some(&OWNER_TRIE_NAME_PREFIX) => {
+					if let Ok(OwnerTrieKey::Name(name)) =
+						OwnerTrieKey::<T::Hash, T::Name>::decode(&mut &next[..])
+					{
+						items.push(name);
+					}
+					key = next;
+				},
+				_ => return Page { items, next: None },
+			}
+		}
+		Page { items, next: Some(key) }
+	}
+
+	/// The names owned by "who", at most `MAX_PAGE_SIZE` of them. See `Pallet::list_names_of`
+	/// for paging through all of them.
+	pub fn names_of(who: &T::AccountId) -> Vec<T::Name> {
+		Self::list_names_of(who, None, MAX_PAGE_SIZE).items
+	}
+
+	/// The current deposits and fees of registering and renewing a name, or None if the fees can
+	/// not be converted as no price is known.
+	pub fn quote() -> Option<Quote<BalanceOf<T>>> {
+		Some(Quote {
+			commit_deposit: T::FundToLock::get(),
+			ownership_deposit: T::OwnershipDeposit::get(),
+			name_byte_deposit: T::NameByteDeposit::get(),
+			registration_fee: Self::fee_in_currency(T::RegistrationFee::get()).ok()?,
+			renewal_fee: Self::fee_in_currency(T::RenewalFee::get()).ok()?,
+		})
+	}
+
+	/// The number of blocks "owner" has held their name for without interruption, counted from
+	/// its acquisition as renewals do not interrupt it.
+	pub fn tenure(owner: &OwnerOf<T>) -> T::BlockNumber {
+		<frame_system::Pallet<T>>::block_number().saturating_sub(owner.acquired_at)
+	}
+
+	/// The fee of registering "name" in any casing for "periods" register periods at once,
+	/// discounted as `Pallet::reveal_for_periods` would charge it, or None if the name is taken,
+	/// "periods" is out of range or no price is known.
+	pub fn registration_quote(name: &T::Name, periods: u32) -> Option<BalanceOf<T>> {
+		let in_range = (1..=T::MaxRegistrationPeriods::get()).contains(&periods);
+		if !in_range || !Self::is_available(name) {
+			return None
+		}
+		Self::registration_fee(periods).ok()
+	}
+
+	/// The fee of renewing "name" in any casing "periods" times for its current owner, discounted
+	/// by their tenure, or None if it is not registered, permanent or no price is known.
+	pub fn renewal_quote(name: &T::Name, periods: u32) -> Option<BalanceOf<T>> {
+		let name = Self::normalize(name);
+		if Self::is_permanent(&name) {
+			return None
+		}
+		let fee = Self::renewal_fee(&Self::owners(name)?).ok()?;
+		Some(fee.saturating_mul(periods.into()))
+	}
+
+	/// At most "limit" registered names starting with "prefix" in any casing, for autocompletion.
+	/// Names are only found once indexed with the `prefix-index` feature, and each prefix holds
+	/// at most `MaxNamesPerPrefix` of them, the first registered.
+	pub fn names_with_prefix(prefix: Vec<u8>, limit: u32) -> Vec<T::Name> {
+		let prefix = vanity_name::lowercase(&prefix);
+		let node = &prefix[..prefix.len().min(T::MaxPrefixLen::get() as usize)];
+		let node = match PrefixOf::<T>::try_from(node.to_vec()) {
+			Ok(node) if !node.is_empty() => node,
+			_ => return Vec::new(),
+		};
+		Self::name_prefixes(node)
+			.into_inner()
+			.into_iter()
+			.filter(|name| Self::name_bytes(name).starts_with(&prefix))
+			.take(limit.min(MAX_PAGE_SIZE) as usize)
+			.collect()
+	}
+}
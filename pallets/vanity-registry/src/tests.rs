@@ -1,16 +1,45 @@
-use crate::{mock::*, Error, LockPeriod, LockPeriods, Owners};
+use crate::{
+	migrations::Migrate, mock::*, Acquisition, BatchReveals, CheckRegistryCall, CommitDeposits,
+	DeniedNames, DepositBackend, EnsMetadata, Error, ExpiryQueue, FreeReason, InvalidName,
+	JointAction, LockPeriod, LockPeriods, NameBuckets, NameHashIndex, NameHistories, NameHistory,
+	NamePricer, NameProvider, NameRoles, NameStatus, NameValidator, Owner, OwnerTrieKey, Owners,
+	OwnershipDeposits, Quote, RegistrationDetails, Resolution, ScheduledAction, ScheduledTransfer,
+	SquattingReports, Takedowns, ValidityError, VanityName, Vouchers, COMMIT_VERSION,
+	MAX_PAGE_SIZE,
+};
+use codec::{Decode, Encode};
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{Currency, OnFinalize},
+	storage::{child, unhashed},
+	traits::{
+		ConstU32, Currency, ExistenceRequirement, GetStorageVersion, NamedReservableCurrency,
+		OffchainWorker, OnFinalize, OnRuntimeUpgrade, StorageInfoTrait, StorageVersion,
+		WithdrawReasons,
+	},
+	BoundedVec,
 };
 use frame_system::Config as SystemConfig;
 use pallet_balances::Error as BalancesError;
+use sp_core::{
+	offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	H256,
+};
+use sp_runtime::{
+	testing::{TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, Hash, Keccak256, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError},
+	DispatchError, Perbill,
+};
+use sp_std::convert::TryFrom;
 
 #[test]
 fn straight_forward_commit() {
 	new_test_ext().execute_with(|| {
 		let alice_id: <Test as SystemConfig>::AccountId = 1;
-		let name = b"Alice".to_vec();
+		let name = b"alice".to_name();
 		let commit = VanityRegistry::hash_of(alice_id, name);
 
 		let block_number = 7;
@@ -28,7 +57,7 @@ fn straight_forward_commit() {
 fn straight_forward_reveal() {
 	new_test_ext().execute_with(|| {
 		let id: <Test as SystemConfig>::AccountId = 1;
-		let name = b"Alice".to_vec();
+		let name = b"alice".to_name();
 		let commit = VanityRegistry::hash_of(id.clone(), name.clone());
 		assert_ok!(VanityRegistry::commit(Origin::signed(id.clone()), commit.clone()));
 		assert_ok!(VanityRegistry::reveal(Origin::signed(id.clone()), name.clone()));
@@ -43,8 +72,9 @@ fn straight_forward_reveal() {
 fn straight_forward_renew() {
 	new_test_ext().execute_with(|| {
 		let id: <Test as SystemConfig>::AccountId = 1;
-		let name = b"Alice".to_vec();
+		let name = b"alice".to_name();
 		let commit = VanityRegistry::hash_of(id.clone(), name.clone());
+		let _ = Balances::deposit_creating(&id, OwnershipDeposit::get());
 
 		System::set_block_number(7);
 		assert_ok!(VanityRegistry::commit(Origin::signed(id.clone()), commit.clone()));
@@ -64,7 +94,7 @@ fn straight_forward_renew() {
 fn straight_forward_unregister() {
 	new_test_ext().execute_with(|| {
 		let id: <Test as SystemConfig>::AccountId = 1;
-		let name = b"Alice".to_vec();
+		let name = b"alice".to_name();
 		let commit = VanityRegistry::hash_of(id.clone(), name.clone());
 
 		System::set_block_number(7);
@@ -90,7 +120,7 @@ fn on_finalize_expired_commits_are_removed() {
 		let alice_id: <Test as SystemConfig>::AccountId = 1;
 		let bob_id: <Test as SystemConfig>::AccountId = 2;
 		let dave_id: <Test as SystemConfig>::AccountId = 3;
-		let name = b"Alice".to_vec();
+		let name = b"alice".to_name();
 		let commit = VanityRegistry::hash_of(alice_id, name);
 
 		System::set_block_number(7);
@@ -119,7 +149,7 @@ fn fund_lock_upon_commit() {
 	new_test_ext().execute_with(|| {
 		let alice_id: <Test as SystemConfig>::AccountId = 1;
 		let bob_id: <Test as SystemConfig>::AccountId = 2;
-		let name = b"Alice".to_vec();
+		let name = b"alice".to_name();
 		let commit = VanityRegistry::hash_of(alice_id, name);
 
 		let alice_balance = FundToLock::get();
@@ -139,7 +169,7 @@ fn fund_unlock_upon_unregister() {
 	new_test_ext().execute_with(|| {
 		let alice_id: <Test as SystemConfig>::AccountId = 1;
 		let bob_id: <Test as SystemConfig>::AccountId = 2;
-		let name = b"Alice".to_vec();
+		let name = b"alice".to_name();
 		let commit = VanityRegistry::hash_of(alice_id, name.clone());
 
 		System::set_block_number(7);
@@ -163,8 +193,8 @@ fn fund_lock_increase_with_more_commits() {
 	new_test_ext().execute_with(|| {
 		let alice_id: <Test as SystemConfig>::AccountId = 1;
 		let bob_id: <Test as SystemConfig>::AccountId = 2;
-		let name1 = b"Alice".to_vec();
-		let name2 = b"AliceX".to_vec();
+		let name1 = b"alice".to_name();
+		let name2 = b"alicex".to_name();
 		let commit1 = VanityRegistry::hash_of(alice_id, name1);
 		let commit2 = VanityRegistry::hash_of(alice_id, name2);
 
@@ -191,8 +221,8 @@ fn fund_lock_decrease_with_expiry() {
 	new_test_ext().execute_with(|| {
 		let alice_id: <Test as SystemConfig>::AccountId = 1;
 		let bob_id: <Test as SystemConfig>::AccountId = 2;
-		let name1 = b"Alice".to_vec();
-		let name2 = b"AliceX".to_vec();
+		let name1 = b"alice".to_name();
+		let name2 = b"alicex".to_name();
 		let commit1 = VanityRegistry::hash_of(alice_id, name1);
 		let commit2 = VanityRegistry::hash_of(alice_id, name2);
 
@@ -230,7 +260,7 @@ fn fund_unlock_upon_expiry() {
 	new_test_ext().execute_with(|| {
 		let alice_id: <Test as SystemConfig>::AccountId = 1;
 		let bob_id: <Test as SystemConfig>::AccountId = 2;
-		let name = b"Alice".to_vec();
+		let name = b"alice".to_name();
 		let commit = VanityRegistry::hash_of(alice_id, name.clone());
 
 		System::set_block_number(7);
@@ -250,7 +280,7 @@ fn revealing_non_owning_name_fails() {
 	new_test_ext().execute_with(|| {
 		let alice_id: <Test as SystemConfig>::AccountId = 1;
 		let bob_id: <Test as SystemConfig>::AccountId = 2;
-		let alice_name = b"Alice".to_vec();
+		let alice_name = b"alice".to_name();
 		let alice_commit = VanityRegistry::hash_of(alice_id, alice_name.clone());
 
 		// If Bob wants to pay the price of committing on behalf of Alice, it's ok.
@@ -264,12 +294,95 @@ fn revealing_non_owning_name_fails() {
 	});
 }
 
+#[test]
+fn salted_commits_to_a_name_are_revealed_by_the_oldest() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		let first_commit = VanityRegistry::salted_hash_of(1, name.clone(), first);
+		let second_commit = VanityRegistry::salted_hash_of(1, name.clone(), second);
+		System::set_block_number(1);
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), first_commit));
+		System::set_block_number(2);
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), second_commit));
+		assert_eq!(LockPeriods::<Test>::iter_prefix(1).count(), 2);
+
+		System::set_block_number(3);
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(1), name.clone()),
+			Error::<Test>::CommitNotFound
+		);
+		let salts = BoundedVec::try_from(vec![second, first]).unwrap();
+		assert_ok!(VanityRegistry::reveal_salted(Origin::signed(1), name.clone(), salts));
+		let owner = VanityRegistry::owners(&name).unwrap();
+		assert_eq!((owner.commit, owner.lock_period.begin), (first_commit, 1));
+		assert!(LockPeriods::<Test>::contains_key(1, second_commit));
+	});
+}
+
+#[test]
+fn batch_commits_reveal_each_of_their_names_once() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (b"alice".to_name(), b"bob".to_name());
+		let salt = H256::repeat_byte(1);
+		let alice_leaf = VanityRegistry::salted_hash_of(1, alice.clone(), salt);
+		let bob_leaf = VanityRegistry::salted_hash_of(1, bob.clone(), salt);
+		let root = VanityRegistry::merkle_root(alice_leaf, &[bob_leaf]);
+		assert_eq!(VanityRegistry::merkle_root(bob_leaf, &[alice_leaf]), root);
+		let reveal = |who, name: &Name, sibling| {
+			let proof = BoundedVec::try_from(vec![sibling]).unwrap();
+			VanityRegistry::reveal_batched(Origin::signed(who), name.clone(), salt, root, proof)
+		};
+		System::set_block_number(1);
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), root));
+
+		assert_noop!(reveal(1, &alice, root), Error::<Test>::InvalidProof);
+		assert_noop!(reveal(2, &alice, bob_leaf), Error::<Test>::CommitNotFound);
+		assert_ok!(reveal(1, &alice, bob_leaf));
+		assert_ok!(reveal(1, &bob, alice_leaf));
+		assert_eq!(VanityRegistry::owner_of(&alice), Some(1));
+		assert_eq!(VanityRegistry::owner_of(&bob), Some(1));
+		assert_eq!(VanityRegistry::owners(&bob).unwrap().lock_period.begin, 1);
+		assert_eq!(CommitDeposits::<Test>::iter_prefix(1).count(), 1);
+		assert_noop!(reveal(1, &alice, bob_leaf), Error::<Test>::AlreadyRevealed);
+
+		VanityRegistry::on_finalize(1 + RegisterPeriod::get());
+		assert!(!LockPeriods::<Test>::contains_key(1, root));
+		assert_eq!(BatchReveals::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn blinded_commits_are_revealed_with_a_proof() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (b"alice".to_name(), b"bob".to_name());
+		let blinded = MockRevealVerifier::blinded_commit(&alice, b"blinding");
+		let reveal = |who, name: &Name, proof: &[u8]| {
+			let proof = BoundedVec::try_from(proof.to_vec()).unwrap();
+			VanityRegistry::reveal_with_proof(Origin::signed(who), name.clone(), proof)
+		};
+		System::set_block_number(1);
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), blinded));
+		let bob_commit = VanityRegistry::hash_of(1, bob.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), bob_commit));
+
+		assert_noop!(reveal(1, &alice, b"guessed"), Error::<Test>::CommitNotFound);
+		assert_noop!(reveal(2, &alice, b"blinding"), Error::<Test>::CommitNotFound);
+		assert_noop!(reveal(1, &alice, b""), Error::<Test>::CommitNotFound);
+		assert_ok!(reveal(1, &alice, b"blinding"));
+		assert_eq!(VanityRegistry::owners(&alice).unwrap().commit, blinded);
+		// Without a proof, the commits of `hash_of` are revealed like with `reveal`.
+		assert_ok!(reveal(1, &bob, b""));
+		assert_eq!(VanityRegistry::owner_of(&bob), Some(1));
+	});
+}
+
 #[test]
 fn front_running_is_revertible() {
 	new_test_ext().execute_with(|| {
 		let alice_id: <Test as SystemConfig>::AccountId = 1;
 		let bob_id: <Test as SystemConfig>::AccountId = 2;
-		let alice_name = b"Alice".to_vec();
+		let alice_name = b"alice".to_name();
 		let alice_commit = VanityRegistry::hash_of(alice_id, alice_name.clone());
 
 		System::set_block_number(1);
@@ -293,12 +406,38 @@ fn front_running_is_revertible() {
 	});
 }
 
+#[test]
+fn commits_of_the_same_age_are_drawn_regardless_of_reveal_order() {
+	let name = b"alice".to_name();
+	let winner = |seed, order: [u64; 2]| {
+		new_test_ext().execute_with(|| {
+			MockRandomness::set(seed);
+			System::set_block_number(1);
+			for who in [1, 2] {
+				let commit = VanityRegistry::hash_of(who, name.clone());
+				assert_ok!(VanityRegistry::commit(Origin::signed(who), commit));
+			}
+			System::set_block_number(2);
+			for who in order {
+				assert_ok!(VanityRegistry::reveal(Origin::signed(who), name.clone()));
+			}
+			VanityRegistry::owner_of(&name).unwrap()
+		})
+	};
+
+	let seeds = (0..16).map(H256::repeat_byte);
+	let winners: Vec<_> = seeds.map(|seed| (winner(seed, [1, 2]), winner(seed, [2, 1]))).collect();
+	assert!(winners.iter().all(|(first, second)| first == second));
+	assert!(winners.iter().any(|(first, _)| *first == 1));
+	assert!(winners.iter().any(|(first, _)| *first == 2));
+}
+
 #[test]
 fn revealing_an_already_taken_name_fails() {
 	new_test_ext().execute_with(|| {
 		let alice_id: <Test as SystemConfig>::AccountId = 1;
 		let bob_id: <Test as SystemConfig>::AccountId = 2;
-		let alice_name = b"Alice".to_vec();
+		let alice_name = b"alice".to_name();
 		let alice_commit = VanityRegistry::hash_of(alice_id, alice_name.clone());
 
 		System::set_block_number(1);
@@ -318,3 +457,3899 @@ fn revealing_an_already_taken_name_fails() {
 		assert_ok!(VanityRegistry::reveal(Origin::signed(bob_id), alice_name.clone()));
 	});
 }
+
+#[test]
+fn reveal_charges_registration_fee_at_feed_price() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let name = b"alice".to_name();
+		let commit = VanityRegistry::hash_of(alice_id, name.clone());
+
+		RegistrationFee::set(3);
+		MockPriceFeed::set(Some(2));
+		let _ = Balances::deposit_creating(&alice_id, 100);
+
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit));
+		assert_ok!(VanityRegistry::reveal(Origin::signed(alice_id), name));
+
+		assert_eq!(Balances::free_balance(&alice_id), 100 - 3 * 2);
+	});
+}
+
+#[test]
+fn renew_charges_renewal_fee_at_fallback_price() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let name = b"alice".to_name();
+		let commit = VanityRegistry::hash_of(alice_id, name.clone());
+
+		RenewalFee::set(5);
+		let _ = Balances::deposit_creating(&alice_id, 100);
+		assert_ok!(VanityRegistry::set_fallback_price(Origin::root(), Some(4)));
+
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit));
+		assert_ok!(VanityRegistry::reveal(Origin::signed(alice_id), name.clone()));
+		assert_eq!(Balances::free_balance(&alice_id), 100);
+
+		assert_ok!(VanityRegistry::renew(Origin::signed(alice_id), name));
+		assert_eq!(Balances::free_balance(&alice_id), 100 - 5 * 4);
+	});
+}
+
+#[test]
+fn reveal_without_any_price_fails() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let name = b"alice".to_name();
+		let commit = VanityRegistry::hash_of(alice_id, name.clone());
+
+		RegistrationFee::set(3);
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit));
+
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(alice_id), name),
+			Error::<Test>::PriceUnavailable
+		);
+	});
+}
+
+#[test]
+fn only_admin_can_set_fallback_price() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			VanityRegistry::set_fallback_price(Origin::signed(1), Some(4)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VanityRegistry::set_fallback_price(Origin::root(), Some(4)));
+		assert_eq!(VanityRegistry::fallback_price(), Some(4));
+	});
+}
+
+#[test]
+fn renewal_fees_are_capped_by_the_ceiling() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		RenewalFee::set(5);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(VanityRegistry::set_fallback_price(Origin::root(), Some(4)));
+		register(1, b"alice");
+
+		assert_noop!(
+			VanityRegistry::set_renewal_fee_ceiling(Origin::signed(1), Some(8)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VanityRegistry::set_renewal_fee_ceiling(Origin::root(), Some(8)));
+		assert_eq!(VanityRegistry::renewal_fee_ceiling(), Some(8));
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_eq!(Balances::free_balance(&1), 100 - 8);
+
+		assert_ok!(VanityRegistry::set_renewal_fee_ceiling(Origin::root(), None));
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name));
+		assert_eq!(Balances::free_balance(&1), 100 - 8 - 5 * 4);
+	});
+}
+
+fn register(id: <Test as SystemConfig>::AccountId, name: &[u8]) {
+	let commit = VanityRegistry::hash_of(id, name.to_name());
+	assert_ok!(VanityRegistry::commit(Origin::signed(id), commit));
+	assert_ok!(VanityRegistry::reveal(Origin::signed(id), name.to_name()));
+}
+
+#[test]
+fn clones_of_names_look_alike() {
+	assert!(VanityRegistry::looks_alike(b"alice", b"a1ice"));
+	assert!(VanityRegistry::looks_alike(b"alice", b"ALICE"));
+	assert!(VanityRegistry::looks_alike(b"alice", b"alicee"));
+	assert!(VanityRegistry::looks_alike(b"alice", b"alce"));
+	assert!(VanityRegistry::looks_alike(b"corn", b"com"));
+	assert!(!VanityRegistry::looks_alike(b"alice", b"bob"));
+	assert!(!VanityRegistry::looks_alike(b"alice", b"malicea"));
+}
+
+#[test]
+fn the_offchain_worker_reports_clones_of_earlier_names() {
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.execute_with(|| {
+		UintAuthorityId::set_all_keys(vec![7]);
+		System::set_block_number(1);
+		register(1, b"alice");
+		System::set_block_number(2);
+		register(2, b"a1ice");
+		register(3, b"bob");
+
+		VanityRegistry::offchain_worker(SquattingScanInterval::get() + 1);
+		assert!(pool_state.read().transactions.is_empty());
+
+		VanityRegistry::offchain_worker(SquattingScanInterval::get());
+		let transactions = &pool_state.read().transactions;
+		assert_eq!(transactions.len(), 1);
+		let xt = TestXt::<Call, ()>::decode(&mut &transactions[0][..]).unwrap();
+		assert_eq!(xt.signature, Some((0, ())));
+		assert_eq!(
+			xt.call,
+			Call::VanityRegistry(crate::Call::report_squatting {
+				suspect: b"a1ice".to_name(),
+				original: b"alice".to_name(),
+			})
+		);
+	});
+}
+
+#[test]
+fn upheld_squatting_report_frees_the_name_and_returns_the_bond() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let mallory_id: <Test as SystemConfig>::AccountId = 2;
+		let dave_id: <Test as SystemConfig>::AccountId = 3;
+
+		System::set_block_number(1);
+		register(alice_id, b"alice");
+		register(mallory_id, b"a1ice");
+
+		let _ = Balances::deposit_creating(&dave_id, 100);
+		assert_ok!(VanityRegistry::report_squatting(
+			Origin::signed(dave_id),
+			b"a1ice".to_name(),
+			b"alice".to_name()
+		));
+		assert_eq!(
+			Balances::reserved_balance_named(&VanityRegistryId::get(), &dave_id),
+			ReportBond::get()
+		);
+
+		assert_ok!(VanityRegistry::resolve_report(Origin::root(), b"a1ice".to_name(), true));
+
+		assert_eq!(Balances::reserved_balance(&dave_id), 0);
+		assert_eq!(Balances::free_balance(&dave_id), 100);
+		assert!(!Owners::<Test>::contains_key(b"a1ice".to_name()));
+		assert!(!SquattingReports::<Test>::contains_key(b"a1ice".to_name()));
+	});
+}
+
+#[test]
+fn rejected_squatting_report_slashes_the_bond() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let bob_id: <Test as SystemConfig>::AccountId = 2;
+		let dave_id: <Test as SystemConfig>::AccountId = 3;
+
+		System::set_block_number(1);
+		register(alice_id, b"alice");
+		register(bob_id, b"alicia");
+
+		let _ = Balances::deposit_creating(&dave_id, 100);
+		assert_ok!(VanityRegistry::report_squatting(
+			Origin::signed(dave_id),
+			b"alicia".to_name(),
+			b"alice".to_name()
+		));
+		assert_ok!(VanityRegistry::resolve_report(Origin::root(), b"alicia".to_name(), false));
+
+		assert_eq!(Balances::reserved_balance(&dave_id), 0);
+		assert_eq!(Balances::free_balance(&dave_id), 100 - ReportBond::get());
+		assert!(Owners::<Test>::contains_key(b"alicia".to_name()));
+	});
+}
+
+#[test]
+fn squatting_reports_are_rate_limited() {
+	new_test_ext().execute_with(|| {
+		let dave_id: <Test as SystemConfig>::AccountId = 3;
+
+		System::set_block_number(1);
+		register(1, b"alice");
+		register(2, b"a1ice");
+		register(4, b"bob");
+		register(5, b"b0b");
+
+		let _ = Balances::deposit_creating(&dave_id, 100);
+		assert_ok!(VanityRegistry::report_squatting(
+			Origin::signed(dave_id),
+			b"a1ice".to_name(),
+			b"alice".to_name()
+		));
+		assert_noop!(
+			VanityRegistry::report_squatting(
+				Origin::signed(dave_id),
+				b"b0b".to_name(),
+				b"bob".to_name()
+			),
+			Error::<Test>::ReportTooSoon
+		);
+
+		System::set_block_number(1 + ReportCooldown::get());
+		assert_ok!(VanityRegistry::report_squatting(
+			Origin::signed(dave_id),
+			b"b0b".to_name(),
+			b"bob".to_name()
+		));
+	});
+}
+
+#[test]
+fn name_events_are_indexed_by_name_topic() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let name = b"alice".to_name();
+
+		System::set_block_number(1);
+		register(alice_id, &name);
+		assert_ok!(VanityRegistry::unregister(Origin::signed(alice_id), name.clone()));
+
+		let topic = VanityRegistry::name_hash(&name);
+		assert_eq!(topic, H256::from(sp_io::hashing::blake2_256(b"alice")));
+		let indexed: Vec<Event> = System::events()
+			.into_iter()
+			.filter(|record| record.topics == vec![topic])
+			.map(|record| record.event)
+			.collect();
+		assert_eq!(
+			indexed,
+			vec![
+				Event::VanityRegistry(crate::Event::NameOwned(name.clone(), alice_id)),
+				Event::VanityRegistry(crate::Event::NameFreed(name, FreeReason::Unregistered)),
+			]
+		);
+	});
+}
+
+#[test]
+fn storage_keys_locate_commits_owners_and_records() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let commit = VanityRegistry::hash_of(1, name.clone());
+		System::set_block_number(1);
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		let key = VanityRegistry::lock_period_key(&1, &commit);
+		let lock_period = unhashed::get::<LockPeriod<u64>>(&key);
+		assert_eq!(lock_period, VanityRegistry::lock_periods(1, commit));
+
+		assert_ok!(VanityRegistry::reveal(Origin::signed(1), name.clone()));
+		assert_ok!(VanityRegistry::set_role(Origin::signed(1), name.clone(), role(b"payout"), 2));
+		let owner = unhashed::get_raw(&VanityRegistry::owner_key(&b"ALICE".to_name()));
+		assert_eq!(owner, Owners::<Test>::get(&name).map(|owner| owner.encode()));
+		let records = unhashed::get_raw(&VanityRegistry::records_key(&name));
+		assert_eq!(records, Some(NameRoles::<Test>::get(&name).encode()));
+	});
+}
+
+#[test]
+fn owner_trie_mirrors_commits_and_names() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let name = b"alice".to_name();
+		let commit = VanityRegistry::hash_of(alice_id, name.clone());
+		let commit_key = OwnerTrieKey::<H256, Name>::Commit(commit).encode();
+		let name_key = OwnerTrieKey::<H256, Name>::Name(name.clone()).encode();
+		let trie = VanityRegistry::owner_trie(&alice_id);
+
+		System::set_block_number(7);
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit));
+		assert_eq!(
+			child::get::<LockPeriod<u64>>(&trie, &commit_key),
+			VanityRegistry::lock_periods(alice_id, commit)
+		);
+
+		assert_ok!(VanityRegistry::reveal(Origin::signed(alice_id), name.clone()));
+		assert!(child::get::<LockPeriod<u64>>(&trie, &commit_key).is_none());
+		assert!(child::get_raw(&trie, &name_key).is_some());
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(alice_id), name));
+		assert!(child::get_raw(&trie, &name_key).is_none());
+	});
+}
+
+#[test]
+fn owner_trie_follows_the_name_to_the_new_owner() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let bob_id: <Test as SystemConfig>::AccountId = 2;
+		let name = b"alice".to_name();
+		let name_key = OwnerTrieKey::<H256, Name>::Name(name.clone()).encode();
+
+		System::set_block_number(1);
+		let alice_commit = VanityRegistry::hash_of(alice_id, name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), alice_commit));
+
+		System::set_block_number(2);
+		register(bob_id, &name);
+		assert!(child::get_raw(&VanityRegistry::owner_trie(&bob_id), &name_key).is_some());
+
+		assert_ok!(VanityRegistry::reveal(Origin::signed(alice_id), name));
+		assert!(child::get_raw(&VanityRegistry::owner_trie(&bob_id), &name_key).is_none());
+		assert!(child::get_raw(&VanityRegistry::owner_trie(&alice_id), &name_key).is_some());
+	});
+}
+
+#[test]
+fn names_are_listed_page_by_page() {
+	new_test_ext().execute_with(|| {
+		register(1, b"alice");
+		register(2, b"bob");
+		register(3, b"charlie");
+
+		let first = VanityRegistry::list_names(None, 2);
+		assert_eq!(first.items.len(), 2);
+		assert!(first.next.is_some());
+
+		let second = VanityRegistry::list_names(first.next, 2);
+		assert_eq!(second.items.len(), 1);
+		assert_eq!(second.next, None);
+
+		let mut listed: Vec<(Name, u64)> = first.items.into_iter().chain(second.items).collect();
+		listed.sort_by_key(|(name, _)| name.to_vec());
+		assert_eq!(
+			listed,
+			vec![(b"alice".to_name(), 1), (b"bob".to_name(), 2), (b"charlie".to_name(), 3)]
+		);
+	});
+}
+
+#[test]
+fn names_of_an_account_are_listed_page_by_page() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		register(alice_id, b"alice");
+		register(alice_id, b"alicex");
+		register(2, b"bob");
+
+		let first = VanityRegistry::list_names_of(&alice_id, None, 1);
+		assert_eq!(first.items.len(), 1);
+		assert!(first.next.is_some());
+
+		let second = VanityRegistry::list_names_of(&alice_id, first.next, 5);
+		assert_eq!(second.items.len(), 1);
+		assert_eq!(second.next, None);
+
+		let mut listed: Vec<Name> = first.items.into_iter().chain(second.items).collect();
+		listed.sort_by_key(|name| name.to_vec());
+		assert_eq!(listed, vec![b"alice".to_name(), b"alicex".to_name()]);
+	});
+}
+
+#[test]
+fn calls_bound_to_fail_are_rejected_at_validation() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let bob_id: <Test as SystemConfig>::AccountId = 2;
+		let name = b"alice".to_name();
+		let check = CheckRegistryCall::<Test>::new();
+		let info = Default::default();
+		let invalid = |error: ValidityError| -> TransactionValidity {
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(error.into())))
+		};
+
+		let reveal = Call::VanityRegistry(crate::Call::reveal { name: name.clone() });
+		assert_eq!(
+			check.validate(&alice_id, &reveal, &info, 0),
+			invalid(ValidityError::CommitNotFound)
+		);
+
+		let commit = VanityRegistry::hash_of(alice_id, name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit));
+		assert_ok!(check.validate(&alice_id, &reveal, &info, 0));
+		assert_ok!(VanityRegistry::reveal(Origin::signed(alice_id), name.clone()));
+
+		let renew = Call::VanityRegistry(crate::Call::renew { name: name.clone() });
+		let unregister = Call::VanityRegistry(crate::Call::unregister { name });
+		assert_ok!(check.validate(&alice_id, &renew, &info, 0));
+		assert_ok!(check.validate(&alice_id, &unregister, &info, 0));
+		assert_eq!(check.validate(&bob_id, &renew, &info, 0), invalid(ValidityError::NameNotOwned));
+		assert_eq!(
+			check.validate(&bob_id, &unregister, &info, 0),
+			invalid(ValidityError::NameNotOwned)
+		);
+	});
+}
+
+#[test]
+fn locked_fund_can_still_pay_for_transactions() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let commit = VanityRegistry::hash_of(alice_id, b"alice".to_name());
+
+		let _ = Balances::deposit_creating(&alice_id, FundToLock::get() + 1);
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit));
+
+		assert_noop!(
+			Balances::transfer(Origin::signed(alice_id), 2, 2),
+			BalancesError::<Test, _>::LiquidityRestrictions
+		);
+		assert_ok!(Balances::withdraw(
+			&alice_id,
+			2,
+			WithdrawReasons::TRANSACTION_PAYMENT,
+			ExistenceRequirement::KeepAlive
+		));
+	});
+}
+
+fn use_deposit_backend(backend: DepositBackend) {
+	MockDepositBackend::set(backend);
+	Migrate::<Test>::on_runtime_upgrade();
+	assert_eq!(VanityRegistry::active_backend(), backend);
+}
+
+#[test]
+fn reserve_backend_reserves_per_commit() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let commit1 = VanityRegistry::hash_of(alice_id, b"alice".to_name());
+		let commit2 = VanityRegistry::hash_of(alice_id, b"alicex".to_name());
+		use_deposit_backend(DepositBackend::Reserve);
+
+		let _ = Balances::deposit_creating(&alice_id, 3 * FundToLock::get());
+
+		System::set_block_number(7);
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit1));
+		System::set_block_number(8);
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit2));
+		assert_eq!(Balances::reserved_balance(&alice_id), 2 * FundToLock::get());
+		assert_eq!(
+			Balances::reserved_balance_named(&VanityRegistryId::get(), &alice_id),
+			2 * FundToLock::get()
+		);
+		assert!(Balances::locks(&alice_id).is_empty());
+
+		VanityRegistry::on_finalize(7 + RegisterPeriod::get());
+		assert_eq!(Balances::reserved_balance(&alice_id), FundToLock::get());
+
+		VanityRegistry::on_finalize(8 + RegisterPeriod::get());
+		assert_eq!(Balances::reserved_balance(&alice_id), 0);
+		assert_eq!(VanityRegistry::reserved_deposits(alice_id), 0);
+	});
+}
+
+#[test]
+fn commit_fails_when_the_deposit_can_not_be_reserved() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let commit = VanityRegistry::hash_of(alice_id, b"alice".to_name());
+		use_deposit_backend(DepositBackend::Reserve);
+
+		let _ = Balances::deposit_creating(&alice_id, FundToLock::get() - 1);
+
+		assert_noop!(
+			VanityRegistry::commit(Origin::signed(alice_id), commit),
+			BalancesError::<Test, _>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn deposits_migrate_between_backends() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let commit = VanityRegistry::hash_of(alice_id, b"alice".to_name());
+
+		let _ = Balances::deposit_creating(&alice_id, 2 * FundToLock::get());
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit));
+		assert_eq!(Balances::locks(&alice_id).len(), 1);
+
+		use_deposit_backend(DepositBackend::Reserve);
+		assert!(Balances::locks(&alice_id).is_empty());
+		assert_eq!(Balances::reserved_balance(&alice_id), FundToLock::get());
+
+		use_deposit_backend(DepositBackend::Lock);
+		assert_eq!(Balances::reserved_balance(&alice_id), 0);
+		assert_eq!(Balances::locks(&alice_id)[0].amount, FundToLock::get());
+	});
+}
+
+#[test]
+fn fee_may_not_push_the_account_below_existential_deposit() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let name = b"alice".to_name();
+
+		ExistentialDeposit::set(10);
+		use_deposit_backend(DepositBackend::Reserve);
+		RenewalFee::set(1);
+		MockPriceFeed::set(Some(91));
+		let _ = Balances::deposit_creating(&alice_id, 100 + OwnershipDeposit::get());
+		register(alice_id, &name);
+		assert_eq!(Balances::free_balance(&alice_id), 100);
+
+		assert_noop!(
+			VanityRegistry::renew(Origin::signed(alice_id), name.clone()),
+			Error::<Test>::BelowExistentialDeposit
+		);
+
+		MockPriceFeed::set(Some(90));
+		assert_ok!(VanityRegistry::renew(Origin::signed(alice_id), name.clone()));
+		assert_eq!(Balances::free_balance(&alice_id), ExistentialDeposit::get());
+		assert_eq!(MockFeeHandler::collected(), 90);
+
+		assert_noop!(
+			VanityRegistry::renew(Origin::signed(alice_id), name),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn slashed_bond_takes_the_dust_along() {
+	new_test_ext().execute_with(|| {
+		let dave_id: <Test as SystemConfig>::AccountId = 3;
+
+		ExistentialDeposit::set(10);
+		System::set_block_number(1);
+		register(1, b"alice");
+		register(2, b"alicia");
+
+		let _ = Balances::deposit_creating(&dave_id, ReportBond::get() + 5);
+		assert_ok!(VanityRegistry::report_squatting(
+			Origin::signed(dave_id),
+			b"alicia".to_name(),
+			b"alice".to_name()
+		));
+		assert_ok!(VanityRegistry::resolve_report(Origin::root(), b"alicia".to_name(), false));
+
+		assert_eq!(Balances::total_balance(&dave_id), 0);
+		assert_eq!(MockFeeHandler::collected(), ReportBond::get() + 5);
+	});
+}
+
+#[test]
+fn slashed_bond_leaves_balances_above_existential_deposit_alone() {
+	new_test_ext().execute_with(|| {
+		let dave_id: <Test as SystemConfig>::AccountId = 3;
+
+		ExistentialDeposit::set(10);
+		System::set_block_number(1);
+		register(1, b"alice");
+		register(2, b"alicia");
+
+		let _ = Balances::deposit_creating(&dave_id, ReportBond::get() + 10);
+		assert_ok!(VanityRegistry::report_squatting(
+			Origin::signed(dave_id),
+			b"alicia".to_name(),
+			b"alice".to_name()
+		));
+		assert_ok!(VanityRegistry::resolve_report(Origin::root(), b"alicia".to_name(), false));
+
+		assert_eq!(Balances::total_balance(&dave_id), 10);
+		assert_eq!(MockFeeHandler::collected(), ReportBond::get());
+	});
+}
+
+#[test]
+fn ownership_deposit_replaces_commit_deposit_upon_reveal() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let name = b"alice".to_name();
+		let commit = VanityRegistry::hash_of(alice_id, name.clone());
+		use_deposit_backend(DepositBackend::Reserve);
+
+		let _ = Balances::deposit_creating(&alice_id, 2 * FundToLock::get());
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit));
+		assert_eq!(Balances::reserved_balance(&alice_id), FundToLock::get());
+
+		assert_ok!(VanityRegistry::reveal(Origin::signed(alice_id), name.clone()));
+		assert_eq!(Balances::reserved_balance(&alice_id), OwnershipDeposit::get());
+		assert_eq!(VanityRegistry::ownership_deposits(alice_id), OwnershipDeposit::get());
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(alice_id), name));
+		assert_eq!(Balances::reserved_balance(&alice_id), 0);
+		assert_eq!(VanityRegistry::ownership_deposits(alice_id), 0);
+	});
+}
+
+#[test]
+fn ownership_deposit_is_released_upon_expiry() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&alice_id, FundToLock::get());
+		register(alice_id, b"alice");
+		assert_eq!(Balances::locks(&alice_id)[0].amount, OwnershipDeposit::get());
+
+		VanityRegistry::on_finalize(1 + RegisterPeriod::get());
+		assert!(Balances::locks(&alice_id).is_empty());
+		assert_eq!(VanityRegistry::ownership_deposits(alice_id), 0);
+	});
+}
+
+#[test]
+fn ownership_deposit_follows_the_name_to_the_new_owner() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let bob_id: <Test as SystemConfig>::AccountId = 2;
+		let name = b"alice".to_name();
+
+		System::set_block_number(1);
+		let alice_commit = VanityRegistry::hash_of(alice_id, name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), alice_commit));
+
+		System::set_block_number(2);
+		register(bob_id, &name);
+		assert_eq!(VanityRegistry::ownership_deposits(bob_id), OwnershipDeposit::get());
+
+		assert_ok!(VanityRegistry::reveal(Origin::signed(alice_id), name));
+		assert_eq!(VanityRegistry::ownership_deposits(alice_id), OwnershipDeposit::get());
+		assert_eq!(VanityRegistry::ownership_deposits(bob_id), 0);
+		assert!(Balances::locks(&bob_id).is_empty());
+	});
+}
+
+#[test]
+fn names_registered_before_v1_carry_no_ownership_deposit() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let name = b"alice".to_name();
+		let commit = VanityRegistry::hash_of(alice_id, name.clone());
+		let old_owner = (alice_id, commit, LockPeriod { begin: 1, end: 96 });
+
+		StorageVersion::new(0).put::<VanityRegistry>();
+		unhashed::put(&Owners::<Test>::hashed_key_for(&name), &old_owner);
+
+		Migrate::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			VanityRegistry::on_chain_storage_version(),
+			VanityRegistry::current_storage_version()
+		);
+		assert_eq!(VanityRegistry::owners(&name).unwrap().id, alice_id);
+		assert_eq!(VanityRegistry::owners(&name).unwrap().deposit, 0);
+		assert_eq!(VanityRegistry::ownership_deposits(alice_id), 0);
+		assert_eq!(VanityRegistry::list_names_of(&alice_id, None, 10).items, vec![name]);
+	});
+}
+
+#[test]
+fn long_names_hold_a_deposit_per_byte() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let name = b"alice".to_name();
+		use_deposit_backend(DepositBackend::Reserve);
+		NameByteDeposit::set(2);
+
+		let _ = Balances::deposit_creating(&alice_id, 2 * FundToLock::get());
+		register(alice_id, &name);
+		let deposit = OwnershipDeposit::get() + 2 * name.encoded_size() as u64;
+		assert_eq!(VanityRegistry::owners(&name).unwrap().deposit, deposit);
+		assert_eq!(Balances::reserved_balance(&alice_id), deposit);
+		assert_eq!(VanityRegistry::ownership_deposits(alice_id), deposit);
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(alice_id), name));
+		assert_eq!(Balances::reserved_balance(&alice_id), 0);
+		assert_eq!(VanityRegistry::ownership_deposits(alice_id), 0);
+	});
+}
+
+#[test]
+fn ownership_deposit_is_refunded_to_the_beneficiary() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let registrar_id: <Test as SystemConfig>::AccountId = 3;
+		let name = b"alice".to_name();
+
+		let _ = Balances::deposit_creating(&alice_id, FundToLock::get());
+		register(alice_id, &name);
+		assert_ok!(VanityRegistry::set_deposit_beneficiary(
+			Origin::signed(alice_id),
+			name.clone(),
+			registrar_id
+		));
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(alice_id), name.clone()));
+		assert_eq!(Balances::free_balance(&alice_id), FundToLock::get() - OwnershipDeposit::get());
+		assert_eq!(Balances::free_balance(&registrar_id), OwnershipDeposit::get());
+		assert_eq!(VanityRegistry::deposit_beneficiaries(name), None);
+	});
+}
+
+#[test]
+fn ownership_deposit_is_refunded_to_the_beneficiary_upon_expiry() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let registrar_id: <Test as SystemConfig>::AccountId = 3;
+		let name = b"alice".to_name();
+		use_deposit_backend(DepositBackend::Reserve);
+
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&alice_id, FundToLock::get());
+		register(alice_id, &name);
+		assert_ok!(VanityRegistry::set_deposit_beneficiary(
+			Origin::signed(alice_id),
+			name.clone(),
+			registrar_id
+		));
+
+		VanityRegistry::on_finalize(1 + RegisterPeriod::get());
+		assert_eq!(Balances::reserved_balance(&alice_id), 0);
+		assert_eq!(Balances::free_balance(&registrar_id), OwnershipDeposit::get());
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::VanityRegistry(crate::Event::DepositRefunded(
+				name.clone(),
+				registrar_id,
+				OwnershipDeposit::get()
+			))));
+	});
+}
+
+#[test]
+fn only_the_owner_sets_the_deposit_beneficiary() {
+	new_test_ext().execute_with(|| {
+		register(1, b"alice");
+
+		assert_noop!(
+			VanityRegistry::set_deposit_beneficiary(Origin::signed(2), b"alice".to_name(), 2),
+			Error::<Test>::NameNotOwned
+		);
+		assert_noop!(
+			VanityRegistry::set_deposit_beneficiary(Origin::signed(1), b"bob".to_name(), 2),
+			Error::<Test>::NameNotFound
+		);
+	});
+}
+
+#[test]
+fn commits_keep_the_deposit_recorded_at_commit_time() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let commit = VanityRegistry::hash_of(alice_id, b"alice".to_name());
+		use_deposit_backend(DepositBackend::Reserve);
+
+		let _ = Balances::deposit_creating(&alice_id, 3 * FundToLock::get());
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit));
+		assert_eq!(VanityRegistry::commit_deposits(alice_id, commit), Some(FundToLock::get()));
+
+		// Held under older terms, e.g. before the deposit parameter was raised.
+		CommitDeposits::<Test>::insert(alice_id, commit, 2 * FundToLock::get());
+		assert_eq!(Balances::reserved_balance(&alice_id), FundToLock::get());
+
+		assert_ok!(VanityRegistry::reconcile_lock(Origin::signed(2), alice_id));
+		assert_eq!(Balances::reserved_balance(&alice_id), 2 * FundToLock::get());
+	});
+}
+
+#[test]
+fn commits_made_before_v2_get_their_deposit_recorded() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let commit = VanityRegistry::hash_of(alice_id, b"alice".to_name());
+
+		StorageVersion::new(1).put::<VanityRegistry>();
+		LockPeriods::<Test>::insert(alice_id, commit, LockPeriod { begin: 1, end: 96 });
+
+		Migrate::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			VanityRegistry::on_chain_storage_version(),
+			VanityRegistry::current_storage_version()
+		);
+		assert_eq!(VanityRegistry::commit_deposits(alice_id, commit), Some(FundToLock::get()));
+	});
+}
+
+#[test]
+fn fund_lock_persists_while_the_name_is_owned() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let bob_id: <Test as SystemConfig>::AccountId = 2;
+		let name = b"alice".to_name();
+		let commit = VanityRegistry::hash_of(alice_id, name.clone());
+
+		let alice_balance = OwnershipDeposit::get() + 1;
+		let _ = Balances::deposit_creating(&alice_id, alice_balance);
+
+		System::set_block_number(7);
+		assert_ok!(VanityRegistry::commit(Origin::signed(alice_id), commit));
+		System::set_block_number(8);
+		assert_ok!(VanityRegistry::reveal(Origin::signed(alice_id), name.clone()));
+		assert!(LockPeriods::<Test>::get(alice_id, commit).is_none());
+
+		// The commit is consumed, yet the name keeps the ownership deposit locked
+		assert_ok!(Balances::transfer(Origin::signed(alice_id), bob_id, 1));
+		assert_noop!(
+			Balances::transfer(Origin::signed(alice_id), bob_id, 1),
+			BalancesError::<Test, _>::LiquidityRestrictions
+		);
+
+		// Even past the commit's lock period, as long as the name is renewed
+		System::set_block_number(7 + RegisterPeriod::get() - 1);
+		assert_ok!(VanityRegistry::renew(Origin::signed(alice_id), name.clone()));
+		VanityRegistry::on_finalize(8 + RegisterPeriod::get());
+		assert_noop!(
+			Balances::transfer(Origin::signed(alice_id), bob_id, 1),
+			BalancesError::<Test, _>::LiquidityRestrictions
+		);
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(alice_id), name));
+		assert_ok!(Balances::transfer(Origin::signed(alice_id), bob_id, OwnershipDeposit::get()));
+	});
+}
+
+#[test]
+fn fund_lock_grows_with_every_owned_name() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let bob_id: <Test as SystemConfig>::AccountId = 2;
+
+		let alice_balance = FundToLock::get() + OwnershipDeposit::get();
+		let _ = Balances::deposit_creating(&alice_id, alice_balance);
+
+		System::set_block_number(1);
+		register(alice_id, b"alice");
+		register(alice_id, b"alicex");
+		assert_eq!(Balances::locks(&alice_id)[0].amount, 2 * OwnershipDeposit::get());
+		assert_noop!(
+			Balances::transfer(
+				Origin::signed(alice_id),
+				bob_id,
+				alice_balance - 2 * OwnershipDeposit::get() + 1
+			),
+			BalancesError::<Test, _>::LiquidityRestrictions
+		);
+
+		VanityRegistry::on_finalize(1 + RegisterPeriod::get());
+		assert!(Balances::locks(&alice_id).is_empty());
+	});
+}
+
+#[test]
+fn forced_removal_slashes_a_share_of_the_ownership_deposit() {
+	new_test_ext().execute_with(|| {
+		let mallory_id: <Test as SystemConfig>::AccountId = 4;
+		let name = b"alice".to_name();
+		let slashed = ForcedRemovalSlash::get() * OwnershipDeposit::get();
+		let refunded = OwnershipDeposit::get() - slashed;
+
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&mallory_id, FundToLock::get());
+		register(mallory_id, &name);
+
+		assert_noop!(
+			VanityRegistry::force_unregister(Origin::signed(mallory_id), name.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VanityRegistry::force_unregister(Origin::root(), name.clone()));
+
+		assert!(VanityRegistry::owners(&name).is_none());
+		assert!(Balances::locks(&mallory_id).is_empty());
+		assert_eq!(Balances::free_balance(&mallory_id), FundToLock::get() - slashed);
+		assert_eq!(MockFeeHandler::collected(), slashed);
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::VanityRegistry(crate::Event::NameForceUnregistered(
+				name.clone(),
+				mallory_id,
+				slashed,
+				refunded
+			))));
+
+		assert_noop!(
+			VanityRegistry::force_unregister(Origin::root(), name),
+			Error::<Test>::NameNotFound
+		);
+	});
+}
+
+#[test]
+fn forced_removal_refunds_the_remainder_to_the_beneficiary() {
+	new_test_ext().execute_with(|| {
+		let mallory_id: <Test as SystemConfig>::AccountId = 4;
+		let registrar_id: <Test as SystemConfig>::AccountId = 3;
+		let name = b"alice".to_name();
+		let slashed = ForcedRemovalSlash::get() * OwnershipDeposit::get();
+		use_deposit_backend(DepositBackend::Reserve);
+
+		let _ = Balances::deposit_creating(&mallory_id, FundToLock::get());
+		register(mallory_id, &name);
+		assert_ok!(VanityRegistry::set_deposit_beneficiary(
+			Origin::signed(mallory_id),
+			name.clone(),
+			registrar_id
+		));
+
+		assert_ok!(VanityRegistry::force_unregister(Origin::root(), name));
+		assert_eq!(Balances::reserved_balance(&mallory_id), 0);
+		assert_eq!(Balances::free_balance(&registrar_id), OwnershipDeposit::get() - slashed);
+		assert_eq!(
+			Balances::free_balance(&mallory_id),
+			FundToLock::get() - OwnershipDeposit::get()
+		);
+	});
+}
+
+fn delegate_deposit(
+	owner: <Test as SystemConfig>::AccountId,
+	depositor: <Test as SystemConfig>::AccountId,
+	name: &[u8],
+) {
+	assert_ok!(VanityRegistry::offer_deposit(Origin::signed(depositor), name.to_name()));
+	assert_ok!(VanityRegistry::accept_deposit_offer(Origin::signed(owner), name.to_name()));
+}
+
+#[test]
+fn delegated_deposit_is_held_from_the_depositor() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let depositor_id: <Test as SystemConfig>::AccountId = 5;
+		let name = b"alice".to_name();
+		use_deposit_backend(DepositBackend::Reserve);
+
+		let _ = Balances::deposit_creating(&alice_id, FundToLock::get());
+		let _ = Balances::deposit_creating(&depositor_id, OwnershipDeposit::get());
+		register(alice_id, &name);
+
+		assert_noop!(
+			VanityRegistry::offer_deposit(Origin::signed(alice_id), name.clone()),
+			Error::<Test>::SelfDelegation
+		);
+		assert_noop!(
+			VanityRegistry::accept_deposit_offer(Origin::signed(alice_id), name.clone()),
+			Error::<Test>::OfferNotFound
+		);
+
+		delegate_deposit(alice_id, depositor_id, &name);
+		assert_eq!(VanityRegistry::deposit_delegations(&name), Some(depositor_id));
+		assert_eq!(Balances::reserved_balance(&alice_id), 0);
+		assert_eq!(Balances::reserved_balance(&depositor_id), OwnershipDeposit::get());
+
+		// The depositor gains no right over the name
+		assert_noop!(
+			VanityRegistry::unregister(Origin::signed(depositor_id), name.clone()),
+			Error::<Test>::NameNotOwned
+		);
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(alice_id), name.clone()));
+		assert_eq!(Balances::reserved_balance(&depositor_id), 0);
+		assert_eq!(VanityRegistry::deposit_delegations(&name), None);
+	});
+}
+
+#[test]
+fn delegated_deposit_unwinds_upon_expiry() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let depositor_id: <Test as SystemConfig>::AccountId = 5;
+		let name = b"alice".to_name();
+
+		System::set_block_number(1);
+		register(alice_id, &name);
+		delegate_deposit(alice_id, depositor_id, &name);
+		assert!(Balances::locks(&alice_id).is_empty());
+		assert_eq!(Balances::locks(&depositor_id)[0].amount, OwnershipDeposit::get());
+
+		VanityRegistry::on_finalize(1 + RegisterPeriod::get());
+		assert!(Balances::locks(&depositor_id).is_empty());
+		assert_eq!(VanityRegistry::ownership_deposits(depositor_id), 0);
+		let ended = crate::Event::DepositDelegationEnded(name.clone(), depositor_id);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == Event::VanityRegistry(ended.clone())));
+	});
+}
+
+#[test]
+fn depositor_earns_a_share_of_the_renewal_fees() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let depositor_id: <Test as SystemConfig>::AccountId = 5;
+		let name = b"alice".to_name();
+
+		let _ = Balances::deposit_creating(&alice_id, 1_000);
+		let _ = Balances::deposit_creating(&depositor_id, OwnershipDeposit::get());
+		register(alice_id, &name);
+		delegate_deposit(alice_id, depositor_id, &name);
+
+		RenewalFee::set(1);
+		MockPriceFeed::set(Some(100));
+		assert_ok!(VanityRegistry::renew(Origin::signed(alice_id), name));
+
+		let share = DelegatorFeeShare::get() * 100;
+		assert_eq!(Balances::free_balance(&depositor_id), OwnershipDeposit::get() + share);
+		assert_eq!(MockFeeHandler::collected(), 100 - share);
+	});
+}
+
+#[test]
+fn either_party_ends_the_deposit_delegation() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let bob_id: <Test as SystemConfig>::AccountId = 2;
+		let depositor_id: <Test as SystemConfig>::AccountId = 5;
+		let name = b"alice".to_name();
+
+		register(alice_id, &name);
+		delegate_deposit(alice_id, depositor_id, &name);
+
+		assert_noop!(
+			VanityRegistry::end_deposit_delegation(Origin::signed(bob_id), name.clone()),
+			Error::<Test>::NotDelegationParty
+		);
+		assert_ok!(VanityRegistry::end_deposit_delegation(
+			Origin::signed(depositor_id),
+			name.clone()
+		));
+		assert!(Balances::locks(&depositor_id).is_empty());
+		assert_eq!(Balances::locks(&alice_id)[0].amount, OwnershipDeposit::get());
+
+		assert_noop!(
+			VanityRegistry::end_deposit_delegation(Origin::signed(alice_id), name),
+			Error::<Test>::NotDelegated
+		);
+	});
+}
+
+#[test]
+fn registered_names_are_indexed_by_hash() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let hash = VanityRegistry::name_hash(&name);
+
+		register(1, &name);
+		assert_eq!(VanityRegistry::name_hash_index(hash), Some(name.clone()));
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), name));
+		assert_eq!(VanityRegistry::name_hash_index(hash), None);
+	});
+}
+
+#[test]
+fn names_registered_before_v3_get_indexed_by_hash() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		register(1, &name);
+		NameHashIndex::<Test>::remove(VanityRegistry::name_hash(&name));
+
+		StorageVersion::new(2).put::<VanityRegistry>();
+		Migrate::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			VanityRegistry::on_chain_storage_version(),
+			VanityRegistry::current_storage_version()
+		);
+		assert_eq!(VanityRegistry::name_hash_index(VanityRegistry::name_hash(&name)), Some(name));
+	});
+}
+
+#[test]
+fn owners_are_resolved_by_name_or_hash() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let hash = VanityRegistry::name_hash(&name);
+		assert_eq!(VanityRegistry::owner_of_hash(hash), None);
+
+		register(1, &name);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		assert_eq!(VanityRegistry::owner_of_hash(hash), Some(1));
+		let unknown = VanityRegistry::name_hash(&b"bob".to_name());
+		assert_eq!(VanityRegistry::owner_of_hash(unknown), None);
+	});
+}
+
+#[test]
+fn commits_are_listed_page_by_page() {
+	new_test_ext().execute_with(|| {
+		for id in 1..=3 {
+			let commit = VanityRegistry::hash_of(id, b"alice".to_name());
+			assert_ok!(VanityRegistry::commit(Origin::signed(id), commit));
+		}
+
+		let first = VanityRegistry::list_commits(None, 2);
+		assert_eq!(first.items.len(), 2);
+		assert!(first.next.is_some());
+
+		let second = VanityRegistry::list_commits(first.next, 2);
+		assert_eq!(second.items.len(), 1);
+		assert_eq!(second.next, None);
+
+		let mut accounts: Vec<u64> =
+			first.items.into_iter().chain(second.items).map(|(id, _, _)| id).collect();
+		accounts.sort();
+		assert_eq!(accounts, vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn pages_are_capped_at_the_maximum_size() {
+	new_test_ext().execute_with(|| {
+		for id in 0..u64::from(MAX_PAGE_SIZE) + 1 {
+			let commit = VanityRegistry::hash_of(id, b"alice".to_name());
+			assert_ok!(VanityRegistry::commit(Origin::signed(id), commit));
+		}
+
+		let page = VanityRegistry::list_commits(None, u32::MAX);
+		assert_eq!(page.items.len(), MAX_PAGE_SIZE as usize);
+		assert!(page.next.is_some());
+	});
+}
+
+#[test]
+fn owners_attest_their_names_with_a_challenge() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let name = b"alice".to_name();
+		let challenge = BoundedVec::try_from(b"login:4242".to_vec()).unwrap();
+
+		System::set_block_number(1);
+		register(alice_id, &name);
+
+		assert_noop!(
+			VanityRegistry::attest_ownership(Origin::signed(2), name.clone(), challenge.clone()),
+			Error::<Test>::NameNotOwned
+		);
+		assert_ok!(VanityRegistry::attest_ownership(
+			Origin::signed(alice_id),
+			name.clone(),
+			challenge.clone()
+		));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			Event::VanityRegistry(crate::Event::OwnershipAttested(name, alice_id, challenge))
+		);
+	});
+}
+
+#[test]
+fn name_length_is_measured_in_characters() {
+	new_test_ext().execute_with(|| {
+		let max = NameMaxLen::get() as usize;
+		let accented = "é".repeat(max).as_bytes().to_name();
+		assert_eq!(accented.len(), 2 * max);
+		assert_eq!(VanityRegistry::name_len(&accented), NameMaxLen::get());
+		register(1, &accented);
+
+		let too_long = "é".repeat(max + 1).as_bytes().to_name();
+		let commit = VanityRegistry::hash_of(2, too_long.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(2), commit));
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(2), too_long),
+			Error::<Test>::NameTooLong
+		);
+
+		// Bytes which are not UTF-8 count one each
+		assert_eq!(VanityRegistry::name_len(&[0xff; 3].to_name()), 3);
+	});
+}
+
+#[test]
+fn names_are_displayed_as_text() {
+	let name = "Zoë".as_bytes().to_name();
+	assert_eq!(VanityRegistry::display_name(&name).to_string(), "Zoë");
+	assert_eq!(format!("{:.2}", VanityRegistry::display_name(&name)), "Zo");
+	assert_eq!(format!("{:>4}", VanityRegistry::display_name(&name)), " Zoë");
+
+	let invalid = [b'A', 0xff, b'B'].to_name();
+	assert_eq!(VanityRegistry::display_name(&invalid).to_string(), "A\u{FFFD}B");
+}
+
+#[test]
+fn names_differing_in_casing_are_the_same_name() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let bob_id: <Test as SystemConfig>::AccountId = 2;
+
+		System::set_block_number(1);
+		register(alice_id, b"Alice");
+		assert_eq!(VanityRegistry::owner_of(&b"ALICE".to_name()), Some(alice_id));
+		assert_eq!(VanityRegistry::revealed_name(&b"alice".to_name()), b"Alice".to_name());
+
+		System::set_block_number(2);
+		register(bob_id, b"alice");
+		assert_eq!(
+			System::events().last().unwrap().event,
+			Event::VanityRegistry(crate::Event::RevealDiscredited(b"alice".to_name(), bob_id))
+		);
+		assert_eq!(VanityRegistry::owner_of(&b"alice".to_name()), Some(alice_id));
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(alice_id), b"ALICE".to_name()));
+		assert_eq!(VanityRegistry::owner_of(&b"alice".to_name()), None);
+		assert_eq!(VanityRegistry::name_casings(b"alice".to_name()), None);
+	});
+}
+
+#[test]
+fn earlier_commit_in_another_casing_takes_the_name() {
+	new_test_ext().execute_with(|| {
+		let alice_id: <Test as SystemConfig>::AccountId = 1;
+		let bob_id: <Test as SystemConfig>::AccountId = 2;
+
+		System::set_block_number(1);
+		let commit = VanityRegistry::hash_of(bob_id, b"alice".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(bob_id), commit));
+
+		System::set_block_number(2);
+		register(alice_id, b"Alice");
+
+		assert_ok!(VanityRegistry::reveal(Origin::signed(bob_id), b"alice".to_name()));
+		assert_eq!(VanityRegistry::owner_of(&b"Alice".to_name()), Some(bob_id));
+		assert_eq!(VanityRegistry::revealed_name(&b"alice".to_name()), b"alice".to_name());
+	});
+}
+
+#[test]
+fn names_registered_before_v4_are_rekeyed_by_their_normalized_form() {
+	new_test_ext().execute_with(|| {
+		let owner = |id, begin| Owner {
+			id,
+			commit: H256::zero(),
+			lock_period: LockPeriod { begin, end: begin + RegisterPeriod::get() },
+			deposit: 0,
+			acquisition: Acquisition::Reveal,
+			acquired_at: begin,
+			renewals: 0,
+		};
+		Owners::<Test>::insert(b"Alice".to_name(), owner(1, 1));
+		Owners::<Test>::insert(b"ALICE".to_name(), owner(2, 2));
+
+		StorageVersion::new(3).put::<VanityRegistry>();
+		Migrate::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			VanityRegistry::on_chain_storage_version(),
+			VanityRegistry::current_storage_version()
+		);
+		let name = b"alice".to_name();
+		assert_eq!(Owners::<Test>::iter_keys().collect::<Vec<_>>(), vec![name.clone()]);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		assert_eq!(VanityRegistry::revealed_name(&name), b"Alice".to_name());
+		assert_eq!(VanityRegistry::name_hash_index(VanityRegistry::name_hash(&name)), Some(name));
+	});
+}
+
+#[test]
+#[cfg(feature = "prefix-index")]
+fn names_are_found_by_prefix() {
+	new_test_ext().execute_with(|| {
+		register(1, b"alice");
+		register(2, b"Alicia");
+		register(3, b"albert");
+
+		// Each prefix holds the first `MaxNamesPerPrefix` names only
+		assert_eq!(
+			VanityRegistry::names_with_prefix(b"AL".to_vec(), 10),
+			vec![b"alice".to_name(), b"alicia".to_name()]
+		);
+		assert_eq!(
+			VanityRegistry::names_with_prefix(b"alb".to_vec(), 10),
+			vec![b"albert".to_name()]
+		);
+		assert_eq!(VanityRegistry::names_with_prefix(b"al".to_vec(), 1), vec![b"alice".to_name()]);
+		// Prefixes longer than `MaxPrefixLen` are matched in full
+		assert_eq!(
+			VanityRegistry::names_with_prefix(b"alici".to_vec(), 10),
+			vec![b"alicia".to_name()]
+		);
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), b"alice".to_name()));
+		assert_eq!(
+			VanityRegistry::names_with_prefix(b"ali".to_vec(), 10),
+			vec![b"alicia".to_name()]
+		);
+		assert_eq!(VanityRegistry::names_with_prefix(b"alice".to_vec(), 10), Vec::<Name>::new());
+	});
+}
+
+#[test]
+fn availability_is_probed_by_bucket() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let bucket = VanityRegistry::name_bucket(&name);
+		assert!(VanityRegistry::is_available(&name));
+
+		register(1, b"Alice");
+		assert!(!VanityRegistry::is_available(&b"ALICE".to_name()));
+		assert_eq!(VanityRegistry::name_bucket_count(bucket), 1);
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), name.clone()));
+		assert!(VanityRegistry::is_available(&name));
+		assert!(!NameBuckets::<Test>::contains_key(bucket));
+	});
+}
+
+#[test]
+fn names_registered_before_v5_get_counted_by_bucket() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let bucket = VanityRegistry::name_bucket(&name);
+		register(1, &name);
+		NameBuckets::<Test>::remove(bucket);
+
+		StorageVersion::new(4).put::<VanityRegistry>();
+		Migrate::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			VanityRegistry::on_chain_storage_version(),
+			VanityRegistry::current_storage_version()
+		);
+		assert_eq!(VanityRegistry::name_bucket_count(bucket), 1);
+		assert!(!VanityRegistry::is_available(&name));
+	});
+}
+
+fn set_joint_owners(owner: u64, name: &[u8], members: Vec<u64>, threshold: u32) {
+	assert_ok!(VanityRegistry::set_joint_owners(
+		Origin::signed(owner),
+		name.to_name(),
+		BoundedVec::try_from(members).unwrap(),
+		threshold
+	));
+}
+
+#[test]
+fn jointly_owned_names_are_unregistered_once_approved() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		register(1, &name);
+		set_joint_owners(1, &name, vec![1, 2, 3], 2);
+
+		assert_noop!(
+			VanityRegistry::unregister(Origin::signed(1), name.clone()),
+			Error::<Test>::JointlyOwned
+		);
+		assert_noop!(
+			VanityRegistry::approve_action(
+				Origin::signed(4),
+				name.clone(),
+				JointAction::Unregister
+			),
+			Error::<Test>::NotJointOwner
+		);
+
+		assert_ok!(VanityRegistry::approve_action(
+			Origin::signed(2),
+			name.clone(),
+			JointAction::Unregister
+		));
+		assert_noop!(
+			VanityRegistry::approve_action(
+				Origin::signed(2),
+				name.clone(),
+				JointAction::Unregister
+			),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+
+		assert_ok!(VanityRegistry::approve_action(
+			Origin::signed(3),
+			name.clone(),
+			JointAction::Unregister
+		));
+		assert_eq!(VanityRegistry::owner_of(&name), None);
+		assert_eq!(VanityRegistry::joint_owners(&name), None);
+		assert!(Balances::locks(&1).is_empty());
+	});
+}
+
+#[test]
+fn approvals_expire_after_the_approval_period() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		System::set_block_number(1);
+		register(1, &name);
+		set_joint_owners(1, &name, vec![1, 2], 2);
+
+		assert_ok!(VanityRegistry::approve_action(
+			Origin::signed(1),
+			name.clone(),
+			JointAction::Dissolve
+		));
+		System::set_block_number(1 + ApprovalPeriod::get());
+		assert_ok!(VanityRegistry::approve_action(
+			Origin::signed(2),
+			name.clone(),
+			JointAction::Dissolve
+		));
+		assert!(VanityRegistry::joint_owners(&name).is_some());
+
+		assert_ok!(VanityRegistry::approve_action(
+			Origin::signed(1),
+			name.clone(),
+			JointAction::Dissolve
+		));
+		assert_eq!(VanityRegistry::joint_owners(&name), None);
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), name));
+	});
+}
+
+#[test]
+fn jointly_owned_names_are_transferred_and_given_roles_once_approved() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		System::set_block_number(1);
+		register(1, &name);
+		set_joint_owners(1, &name, vec![1, 2, 3], 2);
+		let approve = |who: u64, action: JointAction<u64, BoundedVec<u8, MaxRoleLen>, u64>| {
+			VanityRegistry::approve_action(Origin::signed(who), name.clone(), action)
+		};
+
+		assert_noop!(
+			VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 4, 10),
+			Error::<Test>::JointlyOwned
+		);
+		assert_noop!(
+			VanityRegistry::set_role(Origin::signed(1), name.clone(), role(b"payout"), 4),
+			Error::<Test>::JointlyOwned
+		);
+		let set_role = JointAction::SetRole { role: role(b"payout"), account: 4 };
+		assert_ok!(approve(1, set_role.clone()));
+		assert_eq!(VanityRegistry::role_of(&name, b"payout"), None);
+		assert_ok!(approve(2, set_role));
+		assert_eq!(VanityRegistry::role_of(&name, b"payout"), Some(4));
+
+		assert_noop!(
+			VanityRegistry::clear_role(Origin::signed(1), name.clone(), role(b"payout")),
+			Error::<Test>::JointlyOwned
+		);
+		let clear_role = JointAction::ClearRole { role: role(b"payout") };
+		assert_ok!(approve(2, clear_role.clone()));
+		assert_ok!(approve(3, clear_role));
+		assert_eq!(VanityRegistry::role_of(&name, b"payout"), None);
+
+		let transfer = JointAction::ScheduleTransfer { to: 4, at: 10 };
+		assert_ok!(approve(1, transfer.clone()));
+		assert!(VanityRegistry::scheduled_transfers(&name).is_none());
+		assert_ok!(approve(3, transfer));
+		assert_eq!(
+			VanityRegistry::scheduled_transfers(&name),
+			Some(ScheduledTransfer { to: 4, at: 10 })
+		);
+	});
+}
+
+#[test]
+fn joint_owners_need_a_reachable_threshold() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		register(1, &name);
+
+		for threshold in [0, 3] {
+			assert_noop!(
+				VanityRegistry::set_joint_owners(
+					Origin::signed(1),
+					name.clone(),
+					BoundedVec::try_from(vec![2, 3, 2]).unwrap(),
+					threshold
+				),
+				Error::<Test>::InvalidThreshold
+			);
+		}
+		assert_noop!(
+			VanityRegistry::set_joint_owners(
+				Origin::signed(2),
+				name.clone(),
+				BoundedVec::try_from(vec![2, 3]).unwrap(),
+				1
+			),
+			Error::<Test>::NameNotOwned
+		);
+	});
+}
+
+#[test]
+fn shares_of_a_fractionalized_name_are_transferred() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		register(1, &name);
+		assert_ok!(VanityRegistry::fractionalize(Origin::signed(1), name.clone(), 10));
+
+		assert_ok!(VanityRegistry::transfer_shares(Origin::signed(1), name.clone(), 2, 4));
+		assert_ok!(VanityRegistry::transfer_shares(Origin::signed(2), name.clone(), 3, 4));
+		assert_eq!(VanityRegistry::name_shares(&name).unwrap().into_inner(), vec![(1, 6), (3, 4)]);
+
+		assert_noop!(
+			VanityRegistry::transfer_shares(Origin::signed(3), name.clone(), 2, 5),
+			Error::<Test>::InsufficientShares
+		);
+		assert_noop!(
+			VanityRegistry::unregister(Origin::signed(1), name.clone()),
+			Error::<Test>::Fractionalized
+		);
+		assert_noop!(
+			VanityRegistry::renew(Origin::signed(1), name.clone()),
+			Error::<Test>::Fractionalized
+		);
+
+		assert_ok!(VanityRegistry::force_unregister(Origin::root(), name.clone()));
+		assert_eq!(VanityRegistry::name_shares(&name), None);
+	});
+}
+
+#[test]
+fn revenue_is_distributed_pro_rata() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		register(1, &name);
+		assert_ok!(VanityRegistry::fractionalize(Origin::signed(1), name.clone(), 4));
+		assert_ok!(VanityRegistry::transfer_shares(Origin::signed(1), name.clone(), 2, 1));
+
+		let _ = Balances::deposit_creating(&5, 1_000);
+		assert_ok!(VanityRegistry::distribute_revenue(Origin::signed(5), name.clone(), 100));
+		assert_eq!(Balances::free_balance(&1), 75);
+		assert_eq!(Balances::free_balance(&2), 25);
+		assert_eq!(Balances::free_balance(&5), 900);
+	});
+}
+
+#[test]
+fn fractionalized_names_are_renewed_by_majority_vote() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let _ = Balances::deposit_creating(&1, OwnershipDeposit::get());
+		register(1, &name);
+		assert_ok!(VanityRegistry::fractionalize(Origin::signed(1), name.clone(), 4));
+		assert_ok!(VanityRegistry::transfer_shares(Origin::signed(1), name.clone(), 2, 2));
+		assert_ok!(VanityRegistry::transfer_shares(Origin::signed(1), name.clone(), 3, 1));
+
+		System::set_block_number(9);
+		assert_ok!(VanityRegistry::vote_renewal(Origin::signed(2), name.clone()));
+		assert_noop!(
+			VanityRegistry::vote_renewal(Origin::signed(2), name.clone()),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_noop!(
+			VanityRegistry::vote_renewal(Origin::signed(4), name.clone()),
+			Error::<Test>::InsufficientShares
+		);
+		assert_eq!(VanityRegistry::owners(&name).unwrap().lock_period.end, RegisterPeriod::get());
+
+		assert_ok!(VanityRegistry::vote_renewal(Origin::signed(3), name.clone()));
+		assert_eq!(
+			VanityRegistry::owners(&name).unwrap().lock_period.end,
+			9 + RegisterPeriod::get()
+		);
+		assert!(VanityRegistry::renewal_votes(&name).is_empty());
+	});
+}
+
+#[test]
+fn short_names_need_a_judged_identity() {
+	new_test_ext().execute_with(|| {
+		let name = b"al".to_name();
+		let commit = VanityRegistry::hash_of(1, name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(1), name.clone()),
+			Error::<Test>::IdentityRequired
+		);
+
+		MockIdentityVerifier::set(vec![1]);
+		assert_ok!(VanityRegistry::reveal(Origin::signed(1), name.clone()));
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+
+		// Names of `ShortNameLen` characters are open to everyone
+		register(2, "zoë".as_bytes());
+	});
+}
+
+#[test]
+fn reversed_takedowns_return_the_name() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 1_000);
+		register(1, &name);
+		assert_ok!(VanityRegistry::force_unregister(Origin::root(), name.clone()));
+
+		let commit = VanityRegistry::hash_of(2, name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(2), commit));
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(2), name.clone()),
+			Error::<Test>::NameTakenDown
+		);
+		assert_noop!(
+			VanityRegistry::appeal_takedown(Origin::signed(2), name.clone()),
+			Error::<Test>::NotFormerOwner
+		);
+
+		System::set_block_number(11);
+		assert_ok!(VanityRegistry::appeal_takedown(Origin::signed(1), name.clone()));
+		assert_eq!(Balances::reserved_balance(&1), AppealBond::get());
+		assert_ok!(VanityRegistry::resolve_appeal(Origin::root(), name.clone(), true));
+
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		let owner = VanityRegistry::owners(&name).unwrap();
+		assert_eq!(owner.id, 1);
+		assert_eq!(owner.lock_period.end, 1 + RegisterPeriod::get() + 10);
+		assert_eq!(Balances::locks(&1)[0].amount, OwnershipDeposit::get());
+		assert_eq!(VanityRegistry::takedowns(&name), None);
+	});
+}
+
+#[test]
+fn confirmed_takedowns_slash_the_appeal_bond() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let _ = Balances::deposit_creating(&1, 1_000);
+		register(1, &name);
+		assert_ok!(VanityRegistry::force_unregister(Origin::root(), name.clone()));
+		let slashed = MockFeeHandler::collected();
+
+		assert_ok!(VanityRegistry::appeal_takedown(Origin::signed(1), name.clone()));
+		assert_noop!(
+			VanityRegistry::appeal_takedown(Origin::signed(1), name.clone()),
+			Error::<Test>::AlreadyAppealed
+		);
+		assert_ok!(VanityRegistry::resolve_appeal(Origin::root(), name.clone(), false));
+
+		assert_eq!(MockFeeHandler::collected(), slashed + AppealBond::get());
+		assert_eq!(VanityRegistry::owner_of(&name), None);
+		register(2, &name);
+	});
+}
+
+#[test]
+fn undecided_appeals_return_the_bond_once_the_window_passes() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let _ = Balances::deposit_creating(&1, 1_000);
+		register(1, &name);
+		assert_ok!(VanityRegistry::force_unregister(Origin::root(), name.clone()));
+
+		System::set_block_number(5);
+		assert_ok!(VanityRegistry::appeal_takedown(Origin::signed(1), name.clone()));
+
+		// The appeal has moved the deadline to 5 + `AppealWindow`
+		VanityRegistry::on_finalize(AppealWindow::get());
+		assert!(VanityRegistry::takedowns(&name).is_some());
+
+		VanityRegistry::on_finalize(5 + AppealWindow::get());
+		assert_eq!(VanityRegistry::takedowns(&name), None);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_noop!(
+			VanityRegistry::resolve_appeal(Origin::root(), name, true),
+			Error::<Test>::TakedownNotFound
+		);
+	});
+}
+
+#[test]
+fn reveals_are_capped_per_block() {
+	new_test_ext().execute_with(|| {
+		MaxRevealsPerBlock::set(2);
+		let check = CheckRegistryCall::<Test>::new();
+		let info = Default::default();
+		register(1, b"alice");
+		register(2, b"bob");
+
+		let name = b"charlie".to_name();
+		let commit = VanityRegistry::hash_of(3, name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(3), commit));
+		let reveal = Call::VanityRegistry(crate::Call::reveal { name: name.clone() });
+		assert_eq!(
+			check.validate(&3, &reveal, &info, 0),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::ExhaustsResources))
+		);
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(3), name.clone()),
+			Error::<Test>::TooManyReveals
+		);
+
+		VanityRegistry::on_finalize(0);
+		assert_eq!(VanityRegistry::reveal_count(), 0);
+		assert_ok!(check.validate(&3, &reveal, &info, 0));
+		assert_ok!(VanityRegistry::reveal(Origin::signed(3), name));
+	});
+}
+
+#[test]
+fn expiries_beyond_the_cap_carry_over_to_the_next_block() {
+	new_test_ext().execute_with(|| {
+		MaxExpiriesPerBlock::set(3);
+		System::set_block_number(1);
+		register(1, b"alice");
+		register(2, b"bobby");
+		register(3, b"carol");
+
+		let end = 1 + RegisterPeriod::get();
+		for n in 1..end {
+			VanityRegistry::on_finalize(n);
+		}
+		assert_eq!(Owners::<Test>::iter().count(), 3);
+
+		VanityRegistry::on_finalize(end);
+		assert_eq!(Owners::<Test>::iter().count(), 1);
+		assert_eq!(VanityRegistry::expiry_cursor(), end);
+
+		VanityRegistry::on_finalize(end + 1);
+		assert_eq!(Owners::<Test>::iter().count(), 0);
+		assert_eq!(VanityRegistry::expiry_cursor(), end + 2);
+	});
+}
+
+#[test]
+fn renewed_names_skip_their_former_expiry() {
+	new_test_ext().execute_with(|| {
+		let _ = Balances::deposit_creating(&1, OwnershipDeposit::get());
+		System::set_block_number(1);
+		register(1, b"alice");
+
+		System::set_block_number(50);
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), b"alice".to_name()));
+
+		VanityRegistry::on_finalize(1 + RegisterPeriod::get());
+		assert_eq!(VanityRegistry::owner_of(&b"alice".to_name()), Some(1));
+
+		VanityRegistry::on_finalize(50 + RegisterPeriod::get());
+		assert_eq!(VanityRegistry::owner_of(&b"alice".to_name()), None);
+	});
+}
+
+#[test]
+fn names_registered_before_v6_get_queued_for_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"alice");
+		ExpiryQueue::<Test>::remove_all(None);
+
+		System::set_block_number(50);
+		StorageVersion::new(5).put::<VanityRegistry>();
+		Migrate::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			VanityRegistry::on_chain_storage_version(),
+			VanityRegistry::current_storage_version()
+		);
+		assert_eq!(VanityRegistry::expiry_cursor(), 50);
+		VanityRegistry::on_finalize(1 + RegisterPeriod::get());
+		assert_eq!(VanityRegistry::owner_of(&b"alice".to_name()), None);
+	});
+}
+
+#[test]
+fn commits_fail_when_their_lock_period_would_overflow() {
+	new_test_ext().execute_with(|| {
+		let commit = VanityRegistry::hash_of(1, b"alice".to_name());
+
+		System::set_block_number(u64::MAX - RegisterPeriod::get() + 1);
+		assert_noop!(
+			VanityRegistry::commit(Origin::signed(1), commit),
+			Error::<Test>::ArithmeticOverflow
+		);
+
+		System::set_block_number(u64::MAX - RegisterPeriod::get());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		assert_eq!(VanityRegistry::lock_periods(1, commit).unwrap().end, u64::MAX);
+	});
+}
+
+#[test]
+fn renewals_fail_when_the_lock_period_would_overflow() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"alice");
+
+		System::set_block_number(u64::MAX);
+		assert_noop!(
+			VanityRegistry::renew(Origin::signed(1), b"alice".to_name()),
+			Error::<Test>::ArithmeticOverflow
+		);
+	});
+}
+
+#[test]
+fn denied_names_can_not_be_revealed() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let hash = VanityRegistry::name_hash(&name);
+		assert_noop!(
+			VanityRegistry::set_name_denied(Origin::signed(1), hash, true),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VanityRegistry::set_name_denied(Origin::root(), hash, true));
+		assert!(DeniedNames::<Test>::contains_key(hash));
+
+		let commit = VanityRegistry::hash_of(1, b"ALICE".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(1), b"ALICE".to_name()),
+			Error::<Test>::NameNotAllowed
+		);
+
+		assert_ok!(VanityRegistry::set_name_denied(Origin::root(), hash, false));
+		assert_ok!(VanityRegistry::reveal(Origin::signed(1), b"ALICE".to_name()));
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+	});
+}
+
+#[test]
+fn commitments_are_domain_separated() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let mut preimage = 1u64.encode();
+		preimage.extend_from_slice(&name.encode());
+		let undomained = <Test as SystemConfig>::Hashing::hash_of(&preimage);
+		assert_ne!(VanityRegistry::hash_of(1, name.clone()), undomained);
+
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), undomained));
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(1), name),
+			Error::<Test>::CommitNotFound
+		);
+	});
+}
+
+#[test]
+fn commitments_are_tagged_with_their_hashing() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let domain = b"vanity-registry/commit/blake2-256".to_vec();
+		assert_eq!(VanityRegistry::commit_domain(), domain);
+		let mut preimage = domain;
+		preimage.push(COMMIT_VERSION);
+		preimage.extend_from_slice(&1u64.encode());
+		preimage.extend_from_slice(&name.encode());
+		assert_eq!(VanityRegistry::hash_of(1, name.clone()), BlakeTwo256::hash(&preimage));
+		let shared = vanity_registry_primitives::commit_preimage(b"blake2-256", &1u64, &name);
+		assert_eq!(shared, preimage);
+
+		let keccak: H256 = <Keccak256 as crate::CommitHashing<H256>>::hash(&preimage);
+		assert_ne!(VanityRegistry::hash_of(1, name), keccak);
+	});
+}
+
+#[test]
+fn renewals_fail_once_the_deposit_is_no_longer_covered() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let _ = Balances::deposit_creating(&1, OwnershipDeposit::get());
+		register(1, &name);
+
+		let _ = Balances::slash(&1, 1);
+		assert_noop!(
+			VanityRegistry::renew(Origin::signed(1), name.clone()),
+			Error::<Test>::InsufficientBalance
+		);
+
+		let _ = Balances::deposit_creating(&1, 1);
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name));
+	});
+}
+
+#[test]
+fn renewals_top_up_the_ownership_deposit() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let _ = Balances::deposit_creating(&1, 100);
+		register(1, &name);
+		Owners::<Test>::mutate(&name, |owner| owner.as_mut().unwrap().deposit = 10);
+		OwnershipDeposits::<Test>::insert(1, 10);
+		assert_ok!(VanityRegistry::reconcile_lock(Origin::signed(2), 1));
+		assert_eq!(Balances::locks(&1)[0].amount, 10);
+
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_eq!(VanityRegistry::owners(&name).unwrap().deposit, OwnershipDeposit::get());
+		assert_eq!(VanityRegistry::ownership_deposits(1), OwnershipDeposit::get());
+		assert_eq!(Balances::locks(&1)[0].amount, OwnershipDeposit::get());
+	});
+}
+
+#[test]
+fn disputed_names_are_suspended_until_the_report_is_resolved() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"alice");
+		register(2, b"alicia");
+		assert_eq!(VanityRegistry::resolve(&b"ALICIA".to_name()), Some(Resolution::Owner(2)));
+
+		let _ = Balances::deposit_creating(&3, ReportBond::get());
+		assert_ok!(VanityRegistry::report_squatting(
+			Origin::signed(3),
+			b"alicia".to_name(),
+			b"alice".to_name()
+		));
+		assert_eq!(VanityRegistry::resolve(&b"alicia".to_name()), Some(Resolution::Suspended));
+		assert_noop!(
+			VanityRegistry::unregister(Origin::signed(2), b"alicia".to_name()),
+			Error::<Test>::NameDisputed
+		);
+		assert_noop!(
+			VanityRegistry::set_deposit_beneficiary(Origin::signed(2), b"alicia".to_name(), 4),
+			Error::<Test>::NameDisputed
+		);
+
+		assert_ok!(VanityRegistry::resolve_report(Origin::root(), b"alicia".to_name(), false));
+		assert_eq!(VanityRegistry::resolve(&b"alicia".to_name()), Some(Resolution::Owner(2)));
+		assert_ok!(VanityRegistry::unregister(Origin::signed(2), b"alicia".to_name()));
+		assert_eq!(VanityRegistry::resolve(&b"alicia".to_name()), None);
+	});
+}
+
+#[test]
+fn quotes_convert_the_fees_at_the_current_price() {
+	new_test_ext().execute_with(|| {
+		RegistrationFee::set(5);
+		RenewalFee::set(3);
+		assert_eq!(VanityRegistry::quote(), None);
+
+		MockPriceFeed::set(Some(2));
+		assert_eq!(
+			VanityRegistry::quote(),
+			Some(Quote {
+				commit_deposit: FundToLock::get(),
+				ownership_deposit: OwnershipDeposit::get(),
+				name_byte_deposit: 0,
+				registration_fee: 10,
+				renewal_fee: 6,
+			})
+		);
+	});
+}
+
+#[test]
+fn names_of_lists_the_names_of_an_account() {
+	new_test_ext().execute_with(|| {
+		register(1, b"alice");
+		register(1, b"alicia");
+		register(2, b"bobby");
+
+		let mut names = VanityRegistry::names_of(&1);
+		names.sort();
+		assert_eq!(names, vec![b"alice".to_name(), b"alicia".to_name()]);
+		assert!(VanityRegistry::names_of(&3).is_empty());
+	});
+}
+
+#[test]
+fn scheduled_unregister_releases_the_name_at_its_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"alice");
+		let name = b"alice".to_name();
+
+		assert_noop!(
+			VanityRegistry::schedule_action(
+				Origin::signed(1),
+				name.clone(),
+				ScheduledAction::Unregister,
+				1
+			),
+			Error::<Test>::ScheduleInPast
+		);
+		assert_noop!(
+			VanityRegistry::schedule_action(
+				Origin::signed(2),
+				name.clone(),
+				ScheduledAction::Unregister,
+				5
+			),
+			Error::<Test>::NameNotOwned
+		);
+		assert_ok!(VanityRegistry::schedule_action(
+			Origin::signed(1),
+			b"ALICE".to_name(),
+			ScheduledAction::Unregister,
+			5
+		));
+		assert_eq!(VanityRegistry::scheduled_actions(&name, ScheduledAction::Unregister), Some(5));
+
+		MockScheduler::run(4);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		MockScheduler::run(5);
+		assert_eq!(VanityRegistry::owner_of(&name), None);
+		assert_eq!(VanityRegistry::scheduled_actions(&name, ScheduledAction::Unregister), None);
+	});
+}
+
+#[test]
+fn scheduling_an_action_again_replaces_it() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"alice");
+		let name = b"alice".to_name();
+
+		assert_ok!(VanityRegistry::schedule_action(
+			Origin::signed(1),
+			name.clone(),
+			ScheduledAction::Renew,
+			10
+		));
+		assert_ok!(VanityRegistry::schedule_action(
+			Origin::signed(1),
+			name.clone(),
+			ScheduledAction::Renew,
+			20
+		));
+		let scheduled = MockScheduler::scheduled();
+		assert_eq!(scheduled.len(), 1);
+		assert_eq!(scheduled[0].1, 20);
+	});
+}
+
+#[test]
+fn scheduled_actions_are_cancelled_when_the_name_changes_hands() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"alice");
+		let name = b"alice".to_name();
+		assert_ok!(VanityRegistry::schedule_action(
+			Origin::signed(1),
+			name.clone(),
+			ScheduledAction::Renew,
+			50
+		));
+		assert_eq!(MockScheduler::scheduled().len(), 1);
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), name.clone()));
+		assert!(MockScheduler::scheduled().is_empty());
+		assert_eq!(VanityRegistry::scheduled_actions(&name, ScheduledAction::Renew), None);
+
+		// A new owner is not bound by the schedules of the former one.
+		register(2, b"alice");
+		MockScheduler::run(50);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(2));
+	});
+}
+
+#[test]
+fn scheduled_actions_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"alice");
+		let name = b"alice".to_name();
+		assert_ok!(VanityRegistry::schedule_action(
+			Origin::signed(1),
+			name.clone(),
+			ScheduledAction::Unregister,
+			5
+		));
+
+		assert_ok!(VanityRegistry::cancel_scheduled_action(
+			Origin::signed(1),
+			name.clone(),
+			ScheduledAction::Unregister
+		));
+		assert!(MockScheduler::scheduled().is_empty());
+		assert_noop!(
+			VanityRegistry::cancel_scheduled_action(
+				Origin::signed(1),
+				name.clone(),
+				ScheduledAction::Unregister
+			),
+			Error::<Test>::NotScheduled
+		);
+	});
+}
+
+#[test]
+fn renewal_fees_are_drawn_from_the_renewal_pool_first() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"faucet");
+		let name = b"faucet".to_name();
+		let _ = Balances::deposit_creating(&1, OwnershipDeposit::get() + 10);
+		let _ = Balances::deposit_creating(&2, 10);
+		let _ = Balances::deposit_creating(&3, 10);
+		RenewalFee::set(3);
+		MockPriceFeed::set(Some(2));
+
+		let faucet = b"FAUCET".to_name();
+		assert_ok!(VanityRegistry::contribute_to_renewal(Origin::signed(2), faucet, 4));
+		assert_ok!(VanityRegistry::contribute_to_renewal(Origin::signed(3), name.clone(), 5));
+		assert_eq!(Balances::reserved_balance_named(&VanityRegistryId::get(), &2), 4);
+
+		// The pool covers the fee of 6, the first contributor being drawn from first.
+		let free = Balances::free_balance(&1);
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_eq!(Balances::free_balance(&1), free);
+		assert_eq!(MockFeeHandler::collected(), 6);
+		assert_eq!(Balances::reserved_balance_named(&VanityRegistryId::get(), &2), 0);
+		assert_eq!(Balances::reserved_balance_named(&VanityRegistryId::get(), &3), 3);
+		assert_eq!(VanityRegistry::renewal_pools(&name).unwrap().into_inner(), vec![(3, 3)]);
+
+		// What the pool can not cover is charged to whoever renews.
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_eq!(Balances::free_balance(&1), free - 3);
+		assert_eq!(MockFeeHandler::collected(), 12);
+		assert_eq!(VanityRegistry::renewal_pools(&name), None);
+	});
+}
+
+#[test]
+fn renewal_pools_are_refunded_when_the_name_is_released() {
+	new_test_ext().execute_with(|| {
+		register(1, b"faucet");
+		let name = b"faucet".to_name();
+		let _ = Balances::deposit_creating(&2, 10);
+
+		assert_ok!(VanityRegistry::contribute_to_renewal(Origin::signed(2), name.clone(), 4));
+		assert_ok!(VanityRegistry::contribute_to_renewal(Origin::signed(2), name.clone(), 2));
+		assert_eq!(Balances::reserved_balance_named(&VanityRegistryId::get(), &2), 6);
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), name.clone()));
+		assert_eq!(Balances::reserved_balance_named(&VanityRegistryId::get(), &2), 0);
+		assert_eq!(Balances::free_balance(&2), 10);
+		assert_eq!(VanityRegistry::renewal_pools(&name), None);
+	});
+}
+
+#[test]
+fn renewal_pool_contributions_are_checked() {
+	new_test_ext().execute_with(|| {
+		register(1, b"faucet");
+		let name = b"faucet".to_name();
+		for id in 2..=4 {
+			let _ = Balances::deposit_creating(&id, 10);
+		}
+
+		assert_noop!(
+			VanityRegistry::contribute_to_renewal(Origin::signed(2), b"docs".to_name(), 1),
+			Error::<Test>::NameNotFound
+		);
+		assert_noop!(
+			VanityRegistry::contribute_to_renewal(Origin::signed(2), name.clone(), 0),
+			Error::<Test>::ZeroContribution
+		);
+		assert_noop!(
+			VanityRegistry::contribute_to_renewal(Origin::signed(2), name.clone(), 20),
+			BalancesError::<Test>::InsufficientBalance
+		);
+		assert_ok!(VanityRegistry::contribute_to_renewal(Origin::signed(2), name.clone(), 1));
+		assert_ok!(VanityRegistry::contribute_to_renewal(Origin::signed(3), name.clone(), 1));
+		assert_noop!(
+			VanityRegistry::contribute_to_renewal(Origin::signed(4), name.clone(), 1),
+			Error::<Test>::TooManyContributors
+		);
+	});
+}
+
+#[test]
+fn long_names_can_be_registered_without_a_commit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let name = b"public-goods".to_name();
+
+		assert_noop!(
+			VanityRegistry::register(Origin::signed(1), b"alice".to_name()),
+			Error::<Test>::NameTooShort
+		);
+		assert_ok!(VanityRegistry::register(Origin::signed(1), b"Public-Goods".to_name()));
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		assert_eq!(
+			VanityRegistry::owners(&name).unwrap().lock_period,
+			LockPeriod { begin: 3, end: 3 + RegisterPeriod::get() }
+		);
+		assert_eq!(Balances::locks(&1).len(), 1);
+
+		assert_noop!(
+			VanityRegistry::register(Origin::signed(2), name.clone()),
+			Error::<Test>::NameAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn earlier_commits_win_over_instant_registrations() {
+	new_test_ext().execute_with(|| {
+		let name = b"public-goods".to_name();
+
+		System::set_block_number(1);
+		let commit = VanityRegistry::hash_of(2, name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(2), commit));
+
+		System::set_block_number(2);
+		assert_ok!(VanityRegistry::register(Origin::signed(1), name.clone()));
+
+		System::set_block_number(3);
+		assert_ok!(VanityRegistry::reveal(Origin::signed(2), name.clone()));
+		assert_eq!(VanityRegistry::owner_of(&name), Some(2));
+	});
+}
+
+#[test]
+fn registration_details_sum_up_a_name() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		register(1, b"Alice");
+		let name = b"alice".to_name();
+		let _ = Balances::deposit_creating(&2, 10);
+		assert_ok!(VanityRegistry::contribute_to_renewal(Origin::signed(2), name.clone(), 4));
+
+		let owner = VanityRegistry::owners(&name).unwrap();
+		assert_eq!(owner.id(), &1);
+		assert_eq!(owner.commit(), &VanityRegistry::hash_of(1, b"Alice".to_name()));
+		assert_eq!(owner.deposit(), &OwnershipDeposit::get());
+		assert_eq!(owner.lock_period().blocks_left(10), 2 + RegisterPeriod::get() - 10);
+
+		assert_eq!(
+			VanityRegistry::registration_details(&b"ALICE".to_name()),
+			Some(RegistrationDetails {
+				owner: 1,
+				display_name: b"Alice".to_name(),
+				lock_period: LockPeriod { begin: 2, end: 2 + RegisterPeriod::get() },
+				deposit: OwnershipDeposit::get(),
+				acquisition: Acquisition::Reveal,
+				acquired_at: 2,
+				renewals: 0,
+				deposit_holder: 1,
+				renewal_pool: 4,
+				jointly_owned: false,
+				fractionalized: false,
+				disputed: false,
+			})
+		);
+		assert_eq!(VanityRegistry::registration_details(&b"bob".to_name()), None);
+	});
+}
+
+#[test]
+fn registration_gathers_a_name_in_one_query() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		GracePeriod::set(10);
+		System::set_block_number(1);
+		register(1, &name);
+		assert_ok!(VanityRegistry::set_role(Origin::signed(1), name.clone(), role(b"payout"), 2));
+		assert_ok!(VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 3, 10));
+
+		let info = VanityRegistry::registration(&b"ALICE".to_name()).unwrap();
+		assert_eq!(Some(info.details), VanityRegistry::registration_details(&name));
+		assert_eq!(info.status, NameStatus::PendingTransfer);
+		assert_eq!(info.expires_at, Some(1 + RegisterPeriod::get() + 10));
+		assert_eq!(info.records, 1);
+		assert_eq!(info.pending_transfer, Some(ScheduledTransfer { to: 3, at: 10 }));
+
+		assert_ok!(VanityRegistry::freeze_name(Origin::root(), name.clone()));
+		let info = VanityRegistry::registration(&name).unwrap();
+		assert_eq!(info.status, NameStatus::Frozen);
+		assert_eq!(info.expires_at, None);
+		assert_eq!(info.pending_transfer, None);
+		assert_eq!(VanityRegistry::registration(&b"bob".to_name()), None);
+	});
+}
+
+#[test]
+fn ens_metadata_lays_out_the_records_of_a_name() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		GracePeriod::set(10);
+		System::set_block_number(1);
+		register(1, b"Alice");
+		assert_ok!(VanityRegistry::set_role(Origin::signed(1), name.clone(), role(b"payout"), 2));
+
+		let end = 1 + RegisterPeriod::get();
+		let metadata = VanityRegistry::ens_metadata(&b"ALICE".to_name()).unwrap();
+		assert_eq!(
+			metadata,
+			EnsMetadata {
+				name: b"Alice".to_name(),
+				owner: 1,
+				expiry: Some(end + 10),
+				addr: Some(1),
+				addresses: vec![(b"payout".to_vec(), 2)],
+			}
+		);
+
+		System::set_block_number(end);
+		let metadata = VanityRegistry::ens_metadata(&name).unwrap();
+		assert_eq!((metadata.owner, metadata.addr), (1, None));
+		assert_eq!(VanityRegistry::ens_metadata(&b"bob".to_name()), None);
+	});
+}
+
+#[test]
+fn name_history_counts_transfers_and_renewals_until_freed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		let _ = Balances::deposit_creating(&2, OwnershipDeposit::get());
+		assert_eq!(VanityRegistry::name_history(&name), NameHistory::default());
+
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_ok!(VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 2, 10));
+		VanityRegistry::on_finalize(10);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(2));
+		assert_eq!(VanityRegistry::name_history(&name), NameHistory { transfers: 1, renewals: 1 });
+		assert_eq!(VanityRegistry::registration(&name).unwrap().history.transfers, 1);
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(2), name.clone()));
+		assert!(!NameHistories::<Test>::contains_key(&name));
+	});
+}
+
+#[test]
+fn owners_record_how_and_when_they_came_by_their_names() {
+	new_test_ext().execute_with(|| {
+		let _ = Balances::deposit_creating(&1, OwnershipDeposit::get());
+		System::set_block_number(2);
+		let commit = VanityRegistry::hash_of(1, b"alice".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		System::set_block_number(4);
+		assert_ok!(VanityRegistry::reveal(Origin::signed(1), b"alice".to_name()));
+		assert_ok!(VanityRegistry::register(Origin::signed(2), b"public-goods".to_name()));
+
+		let alice = VanityRegistry::owners(&b"alice".to_name()).unwrap();
+		assert_eq!(alice.committed_at(), 2);
+		assert_eq!(alice.acquired_at(), 4);
+		assert_eq!(alice.acquisition(), Acquisition::Reveal);
+		let goods = VanityRegistry::owners(&b"public-goods".to_name()).unwrap();
+		assert_eq!(goods.acquisition(), Acquisition::Register);
+
+		System::set_block_number(6);
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), b"alice".to_name()));
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), b"alice".to_name()));
+		let alice = VanityRegistry::owners(&b"alice".to_name()).unwrap();
+		assert_eq!(alice.renewals(), 2);
+		assert_eq!(alice.acquired_at(), 4);
+	});
+}
+
+#[test]
+fn owners_stored_before_v7_are_recorded_as_revealed_at_their_commit() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let taken = b"bob".to_name();
+		let lock_period = LockPeriod { begin: 3, end: 3 + RegisterPeriod::get() };
+		let old_owner = |id: u64| (id, H256::zero(), lock_period.clone(), 43u64);
+		unhashed::put(&Owners::<Test>::hashed_key_for(&name), &old_owner(1));
+		let old_takedown = (old_owner(2), 5u64, 25u64, Option::<u64>::None);
+		unhashed::put(&Takedowns::<Test>::hashed_key_for(&taken), &old_takedown);
+
+		StorageVersion::new(6).put::<VanityRegistry>();
+		Migrate::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			VanityRegistry::on_chain_storage_version(),
+			VanityRegistry::current_storage_version()
+		);
+		let expected = Owner {
+			id: 1,
+			commit: H256::zero(),
+			lock_period: lock_period.clone(),
+			deposit: 43,
+			acquisition: Acquisition::Reveal,
+			acquired_at: 3,
+			renewals: 0,
+		};
+		assert_eq!(VanityRegistry::owners(&name), Some(expected.clone()));
+		let key = OwnerTrieKey::<H256, Name>::Name(name).encode();
+		assert_eq!(child::get(&VanityRegistry::owner_trie(&1), &key), Some(expected));
+		assert_eq!(VanityRegistry::takedowns(&taken).unwrap().owner.id, 2);
+	});
+}
+
+#[test]
+fn all_storage_is_bounded() {
+	for info in VanityRegistry::storage_info() {
+		let storage = String::from_utf8_lossy(&info.storage_name).into_owned();
+		assert!(info.max_size.is_some(), "{} has no bound", storage);
+	}
+}
+
+type Name8 = VanityName<ConstU32<8>>;
+
+#[test]
+fn vanity_names_are_validated() {
+	assert_eq!(Name8::try_from(b"Alice".to_vec()).unwrap().as_str(), "Alice");
+	assert_eq!(Name8::try_from("zoë".as_bytes().to_vec()).unwrap().as_bytes(), "zoë".as_bytes());
+	assert_eq!(Name8::try_from(Vec::new()), Err(InvalidName::Empty));
+	assert_eq!(Name8::try_from(b"alice-bob".to_vec()), Err(InvalidName::TooLong));
+	assert_eq!(Name8::try_from(vec![0xff, 0xfe]), Err(InvalidName::NotUtf8));
+	assert_eq!(Name8::try_from(b"al ice".to_vec()), Err(InvalidName::ForbiddenChar));
+	assert_eq!(Name8::try_from(b"alice\n".to_vec()), Err(InvalidName::ForbiddenChar));
+}
+
+#[test]
+fn vanity_names_encode_like_bytes_and_reject_invalid_ones_on_decode() {
+	let name = Name8::try_from(b"Alice".to_vec()).unwrap();
+	assert_eq!(name.encode(), b"Alice".to_vec().encode());
+	assert_eq!(Name8::decode(&mut &name.encode()[..]), Ok(name));
+	assert!(Name8::decode(&mut &b"al ice".to_vec().encode()[..]).is_err());
+	assert!(Name8::decode(&mut &vec![0xffu8].encode()[..]).is_err());
+	assert!(Name8::decode(&mut &b"alice-bob".to_vec().encode()[..]).is_err());
+}
+
+#[test]
+fn vanity_names_compare_by_casing_unless_asked_not_to() {
+	let name = Name8::try_from(b"Alice".to_vec()).unwrap();
+	let lowercase = Name8::try_from(b"alice".to_vec()).unwrap();
+	assert_ne!(name, lowercase);
+	assert!(name.eq_ignore_case(&lowercase));
+	assert_eq!(name.normalized(), lowercase);
+	assert_eq!(Vec::<u8>::from(name), b"Alice".to_vec());
+}
+
+#[test]
+fn registrations_which_ran_out_are_hidden_before_their_expiry_is_processed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"alice");
+		let name = b"alice".to_name();
+		let commit = VanityRegistry::hash_of(2, b"bob".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(2), commit));
+
+		System::set_block_number(RegisterPeriod::get());
+		assert_eq!(VanityRegistry::resolve(&name), Some(Resolution::Owner(1)));
+		assert!(VanityRegistry::active_commit(&2, &commit).is_some());
+
+		System::set_block_number(1 + RegisterPeriod::get());
+		assert!(VanityRegistry::owners(&name).is_some());
+		assert_eq!(VanityRegistry::active_owner(&name), None);
+		assert_eq!(VanityRegistry::resolve(&name), None);
+		assert!(VanityRegistry::lock_periods(&2, commit).is_some());
+		assert_eq!(VanityRegistry::active_commit(&2, &commit), None);
+	});
+}
+
+#[test]
+fn other_pallets_use_the_registry_through_its_traits() {
+	new_test_ext().execute_with(|| {
+		register(1, b"alice");
+		let owner_of = <VanityRegistry as NameProvider<u64, Name>>::owner_of;
+		assert_eq!(owner_of(&b"ALICE".to_name()), Some(1));
+		let names_of = <VanityRegistry as NameProvider<u64, Name>>::names_of;
+		assert_eq!(names_of(&1), vec![b"alice".to_name()]);
+		assert_eq!(<VanityRegistry as NamePricer<u64>>::quote(), VanityRegistry::quote());
+
+		let validate = <VanityRegistry as NameValidator<u64, Name>>::validate;
+		assert_eq!(validate(&2, &b"Bob".to_name()), Ok(b"bob".to_name()));
+		assert_eq!(validate(&2, &b"bo".to_name()), Err(Error::<Test>::IdentityRequired.into()));
+		// Unlike a reveal, validating a name does not count towards `MaxRevealsPerBlock`
+		assert_eq!(VanityRegistry::reveal_count(), 1);
+	});
+}
+
+fn role(raw: &[u8]) -> BoundedVec<u8, MaxRoleLen> {
+	BoundedVec::try_from(raw.to_vec()).unwrap()
+}
+
+#[test]
+fn owners_attach_roles_to_their_names() {
+	new_test_ext().execute_with(|| {
+		register(1, b"alice");
+		let name = b"alice".to_name();
+		assert_noop!(
+			VanityRegistry::set_role(Origin::signed(2), name.clone(), role(b"payout"), 2),
+			Error::<Test>::NameNotOwned
+		);
+
+		assert_ok!(VanityRegistry::set_role(Origin::signed(1), name.clone(), role(b"payout"), 2));
+		let shouting = b"ALICE".to_name();
+		assert_ok!(VanityRegistry::set_role(Origin::signed(1), shouting, role(b"ops"), 3));
+		assert_ok!(VanityRegistry::set_role(Origin::signed(1), name.clone(), role(b"payout"), 4));
+		assert_eq!(VanityRegistry::role_of(&name, b"payout"), Some(4));
+		assert_eq!(VanityRegistry::role_of(&b"Alice".to_name(), b"ops"), Some(3));
+		let role_of = <VanityRegistry as NameProvider<u64, Name>>::role_of;
+		assert_eq!(role_of(&name, b"payout"), Some(4));
+		assert_eq!(VanityRegistry::name_roles(&name).len(), 2);
+
+		assert_noop!(
+			VanityRegistry::set_role(Origin::signed(1), name.clone(), role(b"treasury"), 5),
+			Error::<Test>::TooManyRoles
+		);
+		assert_noop!(
+			VanityRegistry::clear_role(Origin::signed(1), name.clone(), role(b"treasury")),
+			Error::<Test>::RoleNotFound
+		);
+
+		assert_ok!(VanityRegistry::clear_role(Origin::signed(1), name.clone(), role(b"payout")));
+		assert_eq!(VanityRegistry::role_of(&name, b"payout"), None);
+		assert_ok!(VanityRegistry::clear_role(Origin::signed(1), name.clone(), role(b"ops")));
+		assert!(!NameRoles::<Test>::contains_key(&name));
+	});
+}
+
+#[test]
+fn roles_are_removed_with_the_registration() {
+	new_test_ext().execute_with(|| {
+		register(1, b"alice");
+		let name = b"alice".to_name();
+		assert_ok!(VanityRegistry::set_role(Origin::signed(1), name.clone(), role(b"payout"), 2));
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), name.clone()));
+		assert_eq!(VanityRegistry::role_of(&name, b"payout"), None);
+		assert!(!NameRoles::<Test>::contains_key(&name));
+	});
+}
+
+fn set_guardians(owner: u64, name: &[u8], guardians: Vec<u64>, threshold: u32) {
+	assert_ok!(VanityRegistry::set_guardians(
+		Origin::signed(owner),
+		name.to_name(),
+		BoundedVec::try_from(guardians).unwrap(),
+		threshold
+	));
+}
+
+#[test]
+fn guardians_recover_names_to_new_accounts_after_a_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		assert_noop!(
+			VanityRegistry::set_guardians(
+				Origin::signed(1),
+				name.clone(),
+				BoundedVec::try_from(vec![2, 3]).unwrap(),
+				3
+			),
+			Error::<Test>::InvalidThreshold
+		);
+		set_guardians(1, &name, vec![2, 3, 4], 2);
+
+		assert_noop!(
+			VanityRegistry::vote_recovery(Origin::signed(5), name.clone(), 6),
+			Error::<Test>::NotGuardian
+		);
+		assert_ok!(VanityRegistry::vote_recovery(Origin::signed(2), name.clone(), 6));
+		assert_noop!(
+			VanityRegistry::vote_recovery(Origin::signed(2), name.clone(), 6),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_noop!(
+			VanityRegistry::vote_recovery(Origin::signed(3), name.clone(), 7),
+			Error::<Test>::RecoveryMismatch
+		);
+		assert_noop!(
+			VanityRegistry::execute_recovery(Origin::signed(9), name.clone()),
+			Error::<Test>::RecoveryNotDue
+		);
+
+		assert_ok!(VanityRegistry::vote_recovery(Origin::signed(3), name.clone(), 6));
+		let executable_at = 1 + RecoveryDelay::get();
+		assert_eq!(VanityRegistry::recoveries(&name).unwrap().executable_at, Some(executable_at));
+		System::set_block_number(executable_at - 1);
+		assert_noop!(
+			VanityRegistry::execute_recovery(Origin::signed(9), name.clone()),
+			Error::<Test>::RecoveryNotDue
+		);
+
+		System::set_block_number(executable_at);
+		assert_ok!(VanityRegistry::execute_recovery(Origin::signed(9), name.clone()));
+		assert_eq!(VanityRegistry::owner_of(&name), Some(6));
+		assert_eq!(VanityRegistry::owners(&name).unwrap().acquisition(), Acquisition::Recovery);
+		assert!(VanityRegistry::names_of(&1).is_empty());
+		assert_eq!(VanityRegistry::names_of(&6), vec![name.clone()]);
+		assert!(VanityRegistry::recoveries(&name).is_none());
+		assert_eq!(VanityRegistry::guardians(&name).unwrap().threshold, 2);
+	});
+}
+
+#[test]
+fn jointly_owned_and_fractionalized_names_are_not_recovered() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		set_guardians(1, &name, vec![2], 1);
+		set_joint_owners(1, &name, vec![1, 3], 2);
+		assert_noop!(
+			VanityRegistry::set_guardians(
+				Origin::signed(1),
+				name.clone(),
+				BoundedVec::try_from(vec![4]).unwrap(),
+				1
+			),
+			Error::<Test>::JointlyOwned
+		);
+
+		assert_ok!(VanityRegistry::vote_recovery(Origin::signed(2), name.clone(), 4));
+		System::set_block_number(1 + RecoveryDelay::get());
+		assert_noop!(
+			VanityRegistry::execute_recovery(Origin::signed(4), name.clone()),
+			Error::<Test>::JointlyOwned
+		);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+
+		let name = b"bob".to_name();
+		register(1, &name);
+		assert_ok!(VanityRegistry::fractionalize(Origin::signed(1), name.clone(), 10));
+		assert_noop!(
+			VanityRegistry::set_guardians(
+				Origin::signed(1),
+				name.clone(),
+				BoundedVec::try_from(vec![4]).unwrap(),
+				1
+			),
+			Error::<Test>::Fractionalized
+		);
+	});
+}
+
+#[test]
+fn owners_veto_the_recovery_of_their_names() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		set_guardians(1, &name, vec![2, 3], 1);
+		assert_ok!(VanityRegistry::vote_recovery(Origin::signed(2), name.clone(), 6));
+
+		assert_noop!(
+			VanityRegistry::veto_recovery(Origin::signed(2), name.clone()),
+			Error::<Test>::NameNotOwned
+		);
+		assert_ok!(VanityRegistry::veto_recovery(Origin::signed(1), name.clone()));
+		System::set_block_number(1 + RecoveryDelay::get());
+		assert_noop!(
+			VanityRegistry::execute_recovery(Origin::signed(6), name.clone()),
+			Error::<Test>::RecoveryNotFound
+		);
+		assert_noop!(
+			VanityRegistry::veto_recovery(Origin::signed(1), name.clone()),
+			Error::<Test>::RecoveryNotFound
+		);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+
+		set_guardians(1, &name, vec![], 0);
+		assert!(VanityRegistry::guardians(&name).is_none());
+		assert_noop!(
+			VanityRegistry::vote_recovery(Origin::signed(2), name.clone(), 6),
+			Error::<Test>::NotGuardian
+		);
+	});
+}
+
+#[test]
+fn votes_on_a_recovery_lapse_unless_approved() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		set_guardians(1, &name, vec![2, 3], 2);
+		assert_ok!(VanityRegistry::vote_recovery(Origin::signed(2), name.clone(), 6));
+
+		System::set_block_number(1 + ApprovalPeriod::get());
+		assert!(VanityRegistry::active_recovery(&name).is_none());
+		assert_ok!(VanityRegistry::vote_recovery(Origin::signed(3), name.clone(), 7));
+		let recovery = VanityRegistry::recoveries(&name).unwrap();
+		assert_eq!(recovery.new_owner, 7);
+		assert_eq!(recovery.executable_at, None);
+
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), name.clone()));
+		assert!(VanityRegistry::guardians(&name).is_none());
+		assert!(VanityRegistry::recoveries(&name).is_none());
+	});
+}
+
+#[test]
+fn heirs_claim_the_names_of_inactive_owners() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		let too_short = MinInactivityPeriod::get() - 1;
+		assert_noop!(
+			VanityRegistry::set_heir(Origin::signed(1), name.clone(), 2, too_short),
+			Error::<Test>::InactivityPeriodTooShort
+		);
+		assert_ok!(VanityRegistry::set_heir(Origin::signed(1), name.clone(), 2, 20));
+		assert_eq!(VanityRegistry::heirs(&name).unwrap().claimable_at(), 21);
+
+		System::set_block_number(11);
+		assert_ok!(VanityRegistry::attest_ownership(
+			Origin::signed(1),
+			name.clone(),
+			BoundedVec::default()
+		));
+		System::set_block_number(21);
+		assert_noop!(
+			VanityRegistry::claim_inheritance(Origin::signed(2), name.clone()),
+			Error::<Test>::OwnerStillActive
+		);
+
+		System::set_block_number(31);
+		assert_noop!(
+			VanityRegistry::claim_inheritance(Origin::signed(3), name.clone()),
+			Error::<Test>::NotHeir
+		);
+		assert_ok!(VanityRegistry::claim_inheritance(Origin::signed(2), name.clone()));
+		assert_eq!(VanityRegistry::owner_of(&name), Some(2));
+		assert_eq!(VanityRegistry::owners(&name).unwrap().acquisition(), Acquisition::Inheritance);
+		assert!(VanityRegistry::heirs(&name).is_none());
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::VanityRegistry(crate::Event::InheritanceClaimed(name.clone(), 2))));
+	});
+}
+
+#[test]
+fn jointly_owned_and_fractionalized_names_are_not_inherited() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		assert_ok!(VanityRegistry::set_heir(Origin::signed(1), name.clone(), 2, 20));
+		set_joint_owners(1, &name, vec![1, 3], 2);
+		assert_noop!(
+			VanityRegistry::set_heir(Origin::signed(1), name.clone(), 4, 20),
+			Error::<Test>::JointlyOwned
+		);
+
+		System::set_block_number(21);
+		assert_noop!(
+			VanityRegistry::claim_inheritance(Origin::signed(2), name.clone()),
+			Error::<Test>::JointlyOwned
+		);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+
+		let name = b"bob".to_name();
+		register(1, &name);
+		assert_ok!(VanityRegistry::fractionalize(Origin::signed(1), name.clone(), 10));
+		assert_noop!(
+			VanityRegistry::set_heir(Origin::signed(1), name.clone(), 4, 20),
+			Error::<Test>::Fractionalized
+		);
+	});
+}
+
+#[test]
+fn owners_remove_their_heirs() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		register(1, &name);
+		assert_noop!(
+			VanityRegistry::remove_heir(Origin::signed(1), name.clone()),
+			Error::<Test>::HeirNotFound
+		);
+		assert_noop!(
+			VanityRegistry::set_heir(Origin::signed(2), name.clone(), 2, 10),
+			Error::<Test>::NameNotOwned
+		);
+		assert_ok!(VanityRegistry::set_heir(Origin::signed(1), name.clone(), 2, 10));
+		assert_ok!(VanityRegistry::remove_heir(Origin::signed(1), name.clone()));
+
+		System::set_block_number(20);
+		assert_noop!(
+			VanityRegistry::claim_inheritance(Origin::signed(2), name.clone()),
+			Error::<Test>::HeirNotFound
+		);
+	});
+}
+
+#[test]
+fn scheduled_transfers_hand_names_over_at_their_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		let _ = Balances::deposit_creating(&2, OwnershipDeposit::get());
+		assert_noop!(
+			VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 2, 1),
+			Error::<Test>::ScheduleInPast
+		);
+		assert_noop!(
+			VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 2, 1_000),
+			Error::<Test>::TransferAfterExpiry
+		);
+		assert_ok!(VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 2, 10));
+		assert_noop!(
+			VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 3, 10),
+			Error::<Test>::TransferPending
+		);
+
+		// The name is locked against any other transfer.
+		set_guardians(1, &name, vec![3], 1);
+		assert_ok!(VanityRegistry::vote_recovery(Origin::signed(3), name.clone(), 4));
+		System::set_block_number(1 + RecoveryDelay::get());
+		assert_noop!(
+			VanityRegistry::execute_recovery(Origin::signed(4), name.clone()),
+			Error::<Test>::TransferPending
+		);
+
+		VanityRegistry::on_finalize(9);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		VanityRegistry::on_finalize(10);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(2));
+		assert_eq!(VanityRegistry::owners(&name).unwrap().acquisition(), Acquisition::Transfer);
+		assert!(VanityRegistry::scheduled_transfers(&name).is_none());
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::VanityRegistry(crate::Event::NameTransferred(name.clone(), 1, 2))));
+	});
+}
+
+#[test]
+fn owners_cancel_scheduled_transfers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		assert_ok!(VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 2, 10));
+		assert_noop!(
+			VanityRegistry::cancel_transfer(Origin::signed(2), name.clone()),
+			Error::<Test>::NameNotOwned
+		);
+		assert_ok!(VanityRegistry::cancel_transfer(Origin::signed(1), name.clone()));
+		assert_noop!(
+			VanityRegistry::cancel_transfer(Origin::signed(1), name.clone()),
+			Error::<Test>::TransferNotScheduled
+		);
+
+		VanityRegistry::on_finalize(10);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+	});
+}
+
+#[test]
+fn scheduled_transfers_are_dropped_if_the_recipient_can_not_hold_the_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		assert_ok!(VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 2, 10));
+
+		VanityRegistry::on_finalize(10);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		assert!(VanityRegistry::scheduled_transfers(&name).is_none());
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::VanityRegistry(crate::Event::TransferFailed(name.clone(), 2))));
+	});
+}
+
+#[test]
+fn scheduled_transfers_are_cancelled_when_the_name_is_taken_down() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		register(1, &name);
+		assert_ok!(VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 2, 10));
+
+		assert_ok!(VanityRegistry::force_unregister(Origin::root(), name.clone()));
+		assert!(VanityRegistry::scheduled_transfers(&name).is_none());
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::VanityRegistry(crate::Event::TransferCancelled(name.clone()))));
+
+		VanityRegistry::on_finalize(10);
+		assert_eq!(VanityRegistry::owner_of(&name), None);
+	});
+}
+
+fn bundle(names: &[&[u8]]) -> BoundedVec<Name, MaxBundleLen> {
+	BoundedVec::try_from(names.iter().map(|name| name.to_name()).collect::<Vec<_>>()).unwrap()
+}
+
+#[test]
+fn bundles_of_names_are_claimed_as_a_whole() {
+	new_test_ext().execute_with(|| {
+		let names: [&[u8]; 3] = [b"alice", b"alicepay", b"alicelabs"];
+		for name in names {
+			let commit = VanityRegistry::hash_of(1, name.to_name());
+			assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		}
+		assert_noop!(
+			VanityRegistry::reveal_bundle(Origin::signed(1), bundle(&[])),
+			Error::<Test>::EmptyBundle
+		);
+
+		assert_ok!(VanityRegistry::reveal_bundle(Origin::signed(1), bundle(&names)));
+		for name in names {
+			assert_eq!(VanityRegistry::owner_of(&name.to_name()), Some(1));
+		}
+		assert_eq!(
+			System::events().last().unwrap().event,
+			Event::VanityRegistry(crate::Event::BundleRevealed(1, 3))
+		);
+	});
+}
+
+#[test]
+fn bundles_claim_nothing_unless_every_name_is_claimed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(2, b"alicelabs");
+
+		System::set_block_number(2);
+		let names: [&[u8]; 3] = [b"alice", b"alicepay", b"alicelabs"];
+		for name in names {
+			let commit = VanityRegistry::hash_of(1, name.to_name());
+			assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		}
+
+		assert_ok!(VanityRegistry::reveal_bundle(Origin::signed(1), bundle(&names)));
+		assert_eq!(VanityRegistry::owner_of(&b"alice".to_name()), None);
+		assert_eq!(VanityRegistry::owner_of(&b"alicepay".to_name()), None);
+		assert_eq!(VanityRegistry::owner_of(&b"alicelabs".to_name()), Some(2));
+		for name in names {
+			let commit = VanityRegistry::hash_of(1, name.to_name());
+			assert!(VanityRegistry::lock_periods(&1, commit).is_none());
+		}
+		assert!(Balances::locks(&1).is_empty());
+		assert_eq!(
+			System::events().last().unwrap().event,
+			Event::VanityRegistry(crate::Event::BundleFailed(
+				1,
+				Error::<Test>::BundleClaimLost.into()
+			))
+		);
+	});
+}
+
+#[test]
+fn locked_balance_backs_the_deposits_of_accounts_opting_in() {
+	new_test_ext().execute_with(|| {
+		use_deposit_backend(DepositBackend::Reserve);
+		let _ = Balances::deposit_creating(&1, FundToLock::get());
+		MockLockedBalances::set(1, 2 * FundToLock::get() - 1);
+		assert_ok!(VanityRegistry::set_lock_backing(Origin::signed(1), true));
+		assert!(VanityRegistry::is_lock_backed(&1));
+
+		let commit = VanityRegistry::hash_of(1, b"alice".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::locks(&1)[0].amount, FundToLock::get());
+
+		let commit = VanityRegistry::hash_of(1, b"bob".to_name());
+		assert_noop!(
+			VanityRegistry::commit(Origin::signed(1), commit),
+			Error::<Test>::InsufficientLockedBalance
+		);
+		MockLockedBalances::set(1, 2 * FundToLock::get());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		assert_eq!(Balances::locks(&1)[0].amount, 2 * FundToLock::get());
+
+		// Switching backends leaves the accounts backing their deposits with locks alone.
+		use_deposit_backend(DepositBackend::Lock);
+		use_deposit_backend(DepositBackend::Reserve);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::locks(&1)[0].amount, 2 * FundToLock::get());
+	});
+}
+
+#[test]
+fn accounts_opting_out_hold_their_deposits_with_the_active_backend() {
+	new_test_ext().execute_with(|| {
+		use_deposit_backend(DepositBackend::Reserve);
+		let _ = Balances::deposit_creating(&1, 2 * FundToLock::get());
+		let commit = VanityRegistry::hash_of(1, b"alice".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		assert_noop!(
+			VanityRegistry::set_lock_backing(Origin::signed(1), true),
+			Error::<Test>::InsufficientLockedBalance
+		);
+
+		MockLockedBalances::set(1, FundToLock::get());
+		assert_ok!(VanityRegistry::set_lock_backing(Origin::signed(1), true));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::locks(&1)[0].amount, FundToLock::get());
+
+		assert_ok!(VanityRegistry::set_lock_backing(Origin::signed(1), false));
+		assert!(!VanityRegistry::is_lock_backed(&1));
+		assert!(Balances::locks(&1).is_empty());
+		assert_eq!(Balances::reserved_balance(&1), FundToLock::get());
+	});
+}
+
+fn voucher(code: &[u8]) -> (H256, BoundedVec<u8, MaxVoucherCodeLen>) {
+	(<Test as SystemConfig>::Hashing::hash(code), BoundedVec::try_from(code.to_vec()).unwrap())
+}
+
+#[test]
+fn vouchers_register_their_reserved_name_fee_free() {
+	new_test_ext().execute_with(|| {
+		RegistrationFee::set(3);
+		MockPriceFeed::set(Some(2));
+		let _ = Balances::deposit_creating(&1, 100);
+		let (hash, code) = voucher(b"promo");
+		assert_noop!(
+			VanityRegistry::mint_voucher(Origin::signed(1), hash, None),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VanityRegistry::mint_voucher(Origin::root(), hash, Some(b"Alice".to_name())));
+
+		let commit = VanityRegistry::hash_of(2, b"alice".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(2), commit));
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(2), b"alice".to_name()),
+			Error::<Test>::NameReserved
+		);
+
+		let wrong_code = voucher(b"prom0").1;
+		assert_noop!(
+			VanityRegistry::redeem_voucher(Origin::signed(1), wrong_code, b"alice".to_name()),
+			Error::<Test>::VoucherNotFound
+		);
+		assert_noop!(
+			VanityRegistry::redeem_voucher(Origin::signed(1), code.clone(), b"bob".to_name()),
+			Error::<Test>::VoucherMismatch
+		);
+		assert_ok!(VanityRegistry::redeem_voucher(
+			Origin::signed(1),
+			code.clone(),
+			b"ALICE".to_name()
+		));
+		let owner = VanityRegistry::owners(b"alice".to_name()).unwrap();
+		assert_eq!((owner.id, owner.acquisition), (1, Acquisition::Voucher));
+		assert_eq!(Balances::free_balance(&1), 100);
+		assert!(!Vouchers::<Test>::contains_key(hash));
+
+		assert_noop!(
+			VanityRegistry::redeem_voucher(Origin::signed(1), code, b"bob".to_name()),
+			Error::<Test>::VoucherNotFound
+		);
+	});
+}
+
+#[test]
+fn revoked_vouchers_release_their_reserved_name() {
+	new_test_ext().execute_with(|| {
+		let (hash, code) = voucher(b"grant");
+		assert_ok!(VanityRegistry::mint_voucher(Origin::root(), hash, Some(b"alice".to_name())));
+		assert_noop!(
+			VanityRegistry::mint_voucher(Origin::root(), hash, None),
+			Error::<Test>::VoucherExists
+		);
+		let (other, _) = voucher(b"other");
+		assert_noop!(
+			VanityRegistry::mint_voucher(Origin::root(), other, Some(b"ALICE".to_name())),
+			Error::<Test>::NameReserved
+		);
+
+		assert_ok!(VanityRegistry::revoke_voucher(Origin::root(), hash));
+		assert_noop!(
+			VanityRegistry::redeem_voucher(Origin::signed(1), code, b"alice".to_name()),
+			Error::<Test>::VoucherNotFound
+		);
+		register(2, b"alice");
+		assert_eq!(VanityRegistry::owner_of(&b"alice".to_name()), Some(2));
+
+		// A voucher for any name may be redeemed for whichever name its bearer picks.
+		assert_ok!(VanityRegistry::mint_voucher(Origin::root(), other, None));
+		assert_ok!(VanityRegistry::redeem_voucher(
+			Origin::signed(1),
+			voucher(b"other").1,
+			b"bob".to_name()
+		));
+		assert_eq!(VanityRegistry::owner_of(&b"bob".to_name()), Some(1));
+	});
+}
+
+fn gift(
+	giver: <Test as SystemConfig>::AccountId,
+	name: &[u8],
+	to: <Test as SystemConfig>::AccountId,
+) {
+	let commit = VanityRegistry::hash_of(giver, name.to_name());
+	assert_ok!(VanityRegistry::commit(Origin::signed(giver), commit));
+	assert_ok!(VanityRegistry::reveal_gift(Origin::signed(giver), name.to_name(), to));
+}
+
+#[test]
+fn gifted_names_are_owned_by_their_recipient_and_backed_by_their_giver() {
+	new_test_ext().execute_with(|| {
+		RegistrationFee::set(3);
+		MockPriceFeed::set(Some(2));
+		let _ = Balances::deposit_creating(&1, 100);
+		let commit = VanityRegistry::hash_of(1, b"alice".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		assert_noop!(
+			VanityRegistry::reveal_gift(Origin::signed(1), b"alice".to_name(), 1),
+			Error::<Test>::GiftToSelf
+		);
+		assert_ok!(VanityRegistry::reveal_gift(Origin::signed(1), b"alice".to_name(), 2));
+
+		let name = b"alice".to_name();
+		let owner = VanityRegistry::owners(&name).unwrap();
+		assert_eq!((owner.id, owner.acquisition), (2, Acquisition::Gift));
+		assert_eq!(Balances::free_balance(&1), 100 - 3 * 2);
+		assert_eq!(VanityRegistry::deposit_delegations(&name), Some(1));
+		assert_eq!(VanityRegistry::ownership_deposits(&1), OwnershipDeposit::get());
+		assert_eq!(VanityRegistry::ownership_deposits(&2), 0);
+		assert!(Balances::locks(&2).is_empty());
+
+		System::set_block_number(GiftRejectionPeriod::get() + 1);
+		assert!(VanityRegistry::pending_gift(&name).is_none());
+		assert_noop!(
+			VanityRegistry::reject_gift(Origin::signed(2), name),
+			Error::<Test>::GiftNotFound
+		);
+	});
+}
+
+#[test]
+fn rejected_gifts_are_handed_back_to_their_giver() {
+	new_test_ext().execute_with(|| {
+		gift(1, b"alice", 2);
+		let name = b"alice".to_name();
+		assert_noop!(
+			VanityRegistry::reject_gift(Origin::signed(3), name.clone()),
+			Error::<Test>::NameNotOwned
+		);
+
+		assert_ok!(VanityRegistry::reject_gift(Origin::signed(2), name.clone()));
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		assert_eq!(VanityRegistry::deposit_delegations(&name), None);
+		assert_eq!(VanityRegistry::ownership_deposits(&1), OwnershipDeposit::get());
+		assert!(VanityRegistry::gifts(&name).is_none());
+		assert_noop!(
+			VanityRegistry::reject_gift(Origin::signed(2), name),
+			Error::<Test>::NameNotOwned
+		);
+	});
+}
+
+#[test]
+fn renewal_fees_are_drawn_from_renewal_credits_before_the_free_balance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"faucet");
+		let name = b"faucet".to_name();
+		let _ = Balances::deposit_creating(&1, OwnershipDeposit::get() + 20);
+		RenewalFee::set(3);
+		MockPriceFeed::set(Some(2));
+		assert_noop!(
+			VanityRegistry::buy_credits(Origin::signed(1), 0),
+			Error::<Test>::ZeroContribution
+		);
+		assert_ok!(VanityRegistry::buy_credits(Origin::signed(1), 10));
+		assert_eq!(Balances::reserved_balance_named(&VanityRegistryId::get(), &1), 10);
+
+		let free = Balances::free_balance(&1);
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_eq!(Balances::free_balance(&1), free);
+		assert_eq!(MockFeeHandler::collected(), 6);
+		assert_eq!(VanityRegistry::renewal_credits(&1), 4);
+		assert!(System::events().iter().any(
+			|record| record.event == Event::VanityRegistry(crate::Event::CreditsConsumed(1, 6))
+		));
+
+		// What the credits can not cover is charged from the free balance.
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name));
+		assert_eq!(Balances::free_balance(&1), free - 2);
+		assert_eq!(VanityRegistry::renewal_credits(&1), 0);
+		assert_eq!(Balances::reserved_balance_named(&VanityRegistryId::get(), &1), 0);
+	});
+}
+
+#[test]
+fn unused_renewal_credits_are_withdrawn() {
+	new_test_ext().execute_with(|| {
+		let _ = Balances::deposit_creating(&1, 20);
+		assert_ok!(VanityRegistry::buy_credits(Origin::signed(1), 10));
+		assert_noop!(
+			VanityRegistry::withdraw_credits(Origin::signed(1), 11),
+			Error::<Test>::InsufficientCredits
+		);
+		assert_ok!(VanityRegistry::withdraw_credits(Origin::signed(1), 10));
+		assert_eq!(VanityRegistry::renewal_credits(&1), 0);
+		assert_eq!(Balances::free_balance(&1), 20);
+	});
+}
+
+#[test]
+fn renewal_fees_are_discounted_by_tenure_milestones() {
+	new_test_ext().execute_with(|| {
+		MockTenureMilestones::set(vec![
+			(20, Perbill::from_percent(50)),
+			(10, Perbill::from_percent(25)),
+		]);
+		System::set_block_number(1);
+		register(1, b"faucet");
+		let name = b"faucet".to_name();
+		let _ = Balances::deposit_creating(&1, OwnershipDeposit::get() + 100);
+		RenewalFee::set(8);
+		MockPriceFeed::set(Some(1));
+		assert_eq!(VanityRegistry::renewal_quote(&b"FAUCET".to_name(), 1), Some(8));
+
+		System::set_block_number(11);
+		assert_eq!(VanityRegistry::renewal_quote(&name, 1), Some(6));
+		let free = Balances::free_balance(&1);
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_eq!(Balances::free_balance(&1), free - 6);
+
+		// Renewals do not interrupt the tenure.
+		System::set_block_number(21);
+		assert_eq!(VanityRegistry::renewal_quote(&name, 1), Some(4));
+		assert_eq!(VanityRegistry::renewal_quote(&b"other".to_name(), 1), None);
+	});
+}
+
+#[test]
+fn names_revealed_for_several_periods_pay_up_front_and_expire_at_the_last() {
+	new_test_ext().execute_with(|| {
+		RegistrationFee::set(4);
+		MockPriceFeed::set(Some(1));
+		let _ = Balances::deposit_creating(&1, 100);
+		System::set_block_number(1);
+		let name = b"alice".to_name();
+		let commit = VanityRegistry::hash_of(1, name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		for periods in [0, MaxRegistrationPeriods::get() + 1] {
+			assert_noop!(
+				VanityRegistry::reveal_for_periods(Origin::signed(1), name.clone(), periods),
+				Error::<Test>::InvalidPeriods
+			);
+		}
+
+		// Three periods cost as much as one and a half at the mock's 50% discount.
+		assert_ok!(VanityRegistry::reveal_for_periods(Origin::signed(1), name.clone(), 3));
+		assert_eq!(Balances::free_balance(&1), 100 - 6);
+		let end = 1 + 3 * RegisterPeriod::get();
+		assert_eq!(VanityRegistry::owners(&name).unwrap().lock_period.end, end);
+
+		VanityRegistry::on_finalize(1 + RegisterPeriod::get());
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		VanityRegistry::on_finalize(end);
+		assert_eq!(VanityRegistry::owner_of(&name), None);
+	});
+}
+
+#[test]
+fn quotes_match_the_fees_charged() {
+	new_test_ext().execute_with(|| {
+		RegistrationFee::set(4);
+		RenewalFee::set(2);
+		MockPriceFeed::set(Some(1));
+		let _ = Balances::deposit_creating(&1, 100);
+		let name = b"alice".to_name();
+		assert_eq!(VanityRegistry::registration_quote(&name, 0), None);
+		assert_eq!(VanityRegistry::registration_quote(&name, 2), Some(8));
+		assert_eq!(VanityRegistry::registration_quote(&name, 3), Some(6));
+		assert_eq!(VanityRegistry::renewal_quote(&name, 1), None);
+
+		let commit = VanityRegistry::hash_of(1, name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		assert_ok!(VanityRegistry::reveal_for_periods(Origin::signed(1), name.clone(), 3));
+		assert_eq!(Balances::free_balance(&1), 100 - 6);
+		assert_eq!(VanityRegistry::registration_quote(&b"ALICE".to_name(), 1), None);
+
+		assert_eq!(VanityRegistry::renewal_quote(&b"ALICE".to_name(), 3), Some(6));
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_eq!(Balances::free_balance(&1), 100 - 6 - 2);
+	});
+}
+
+#[test]
+fn watching_a_name_holds_a_deposit_until_unwatched() {
+	new_test_ext().execute_with(|| {
+		let hash = VanityRegistry::name_hash(&b"alice".to_name());
+		for id in 1..=3 {
+			let _ = Balances::deposit_creating(&id, 100);
+		}
+		assert_ok!(VanityRegistry::watch(Origin::signed(1), hash));
+		assert_noop!(
+			VanityRegistry::watch(Origin::signed(1), hash),
+			Error::<Test>::AlreadyWatching
+		);
+		assert_ok!(VanityRegistry::watch(Origin::signed(2), hash));
+		assert_noop!(
+			VanityRegistry::watch(Origin::signed(3), hash),
+			Error::<Test>::TooManyWatchers
+		);
+		assert_eq!(Balances::reserved_balance(&1), WatchDeposit::get());
+
+		assert_ok!(VanityRegistry::unwatch(Origin::signed(1), hash));
+		assert_noop!(VanityRegistry::unwatch(Origin::signed(1), hash), Error::<Test>::NotWatching);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(VanityRegistry::watchers(hash).into_inner(), vec![(2, WatchDeposit::get())]);
+	});
+}
+
+#[test]
+fn watchers_of_a_freed_name_are_notified_and_may_claim_it_first() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let hash = VanityRegistry::name_hash(&name);
+		for id in 1..=3 {
+			let _ = Balances::deposit_creating(&id, 100);
+		}
+		System::set_block_number(1);
+		register(1, b"alice");
+		assert_ok!(VanityRegistry::watch(Origin::signed(2), hash));
+
+		System::set_block_number(5);
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), name.clone()));
+		let topics = vec![hash, <Test as SystemConfig>::Hashing::hash_of(&2)];
+		let notified = System::events()
+			.into_iter()
+			.find(|record| record.topics == topics)
+			.map(|record| record.event);
+		assert_eq!(
+			notified,
+			Some(Event::VanityRegistry(crate::Event::WatchedNameAvailable(hash, 2)))
+		);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert!(VanityRegistry::watchers(hash).is_empty());
+
+		let commit = VanityRegistry::hash_of(3, name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(3), commit));
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(3), name.clone()),
+			Error::<Test>::ReservedForWatchers
+		);
+		System::set_block_number(6);
+		register(2, b"alice");
+		assert_eq!(VanityRegistry::owner_of(&name), Some(2));
+
+		// The earlier commit of a non-watcher does not win the name once the window is over.
+		System::set_block_number(5 + WatchPriorityPeriod::get());
+		assert_noop!(
+			VanityRegistry::reveal(Origin::signed(3), name.clone()),
+			Error::<Test>::ReservedForWatchers
+		);
+	});
+}
+
+#[test]
+fn permanent_names_never_expire_and_are_only_freed_by_governance() {
+	new_test_ext().execute_with(|| {
+		RenewalFee::set(1);
+		MockPriceFeed::set(Some(1));
+		let _ = Balances::deposit_creating(&1, 100);
+		let name = b"treasury".to_name();
+		System::set_block_number(1);
+		assert_noop!(
+			VanityRegistry::grant_permanent(Origin::signed(1), name.clone(), 1),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VanityRegistry::grant_permanent(Origin::root(), b"Treasury".to_name(), 1));
+		assert_noop!(
+			VanityRegistry::grant_permanent(Origin::root(), name.clone(), 2),
+			Error::<Test>::NameAlreadyRegistered
+		);
+		assert!(VanityRegistry::is_permanent(&name));
+		assert_eq!(VanityRegistry::ownership_deposits(&1), 0);
+		assert!(Balances::locks(&1).is_empty());
+
+		VanityRegistry::on_finalize(1 + 10 * RegisterPeriod::get());
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		assert_noop!(
+			VanityRegistry::renew(Origin::signed(1), name.clone()),
+			Error::<Test>::PermanentName
+		);
+		assert_noop!(
+			VanityRegistry::unregister(Origin::signed(1), name.clone()),
+			Error::<Test>::PermanentName
+		);
+		assert_eq!(VanityRegistry::renewal_quote(&name, 1), None);
+
+		assert_ok!(VanityRegistry::revoke_permanent(Origin::root(), name.clone()));
+		assert_eq!(VanityRegistry::owner_of(&name), None);
+		assert!(!VanityRegistry::is_permanent(&name));
+		assert_noop!(
+			VanityRegistry::revoke_permanent(Origin::root(), name),
+			Error::<Test>::NotPermanent
+		);
+	});
+}
+
+#[test]
+fn migrating_an_account_moves_its_names_deposits_and_credits() {
+	new_test_ext().execute_with(|| {
+		let _ = Balances::deposit_creating(&1, 200);
+		let _ = Balances::deposit_creating(&2, 200);
+		System::set_block_number(1);
+		register(1, b"alice");
+		register(1, b"carol");
+		let inactivity = MinInactivityPeriod::get();
+		assert_ok!(VanityRegistry::set_heir(Origin::signed(1), b"alice".to_name(), 3, inactivity));
+		assert_ok!(VanityRegistry::buy_credits(Origin::signed(1), 10));
+
+		assert_noop!(
+			VanityRegistry::migrate_account(Origin::signed(1), 2, 2),
+			Error::<Test>::MigrationNotConsented
+		);
+		assert_ok!(VanityRegistry::consent_to_migration(Origin::signed(2), Some(1)));
+		assert_noop!(
+			VanityRegistry::migrate_account(Origin::signed(1), 2, 1),
+			Error::<Test>::TooManyNames
+		);
+		let commit = VanityRegistry::hash_of(1, b"bob".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		assert_noop!(
+			VanityRegistry::migrate_account(Origin::signed(1), 2, 2),
+			Error::<Test>::PendingCommits
+		);
+		assert_ok!(VanityRegistry::reveal(Origin::signed(1), b"bob".to_name()));
+
+		assert_ok!(VanityRegistry::migrate_account(Origin::signed(1), 2, 3));
+		assert!(VanityRegistry::names_of(&1).is_empty());
+		assert_eq!(VanityRegistry::names_of(&2).len(), 3);
+		assert_eq!(VanityRegistry::owner_of(&b"alice".to_name()), Some(2));
+		assert!(VanityRegistry::heirs(&b"alice".to_name()).is_some());
+		assert_eq!(VanityRegistry::ownership_deposits(&1), 0);
+		assert_eq!(VanityRegistry::ownership_deposits(&2), 3 * OwnershipDeposit::get());
+		assert!(Balances::locks(&1).is_empty());
+		assert_eq!(VanityRegistry::renewal_credits(&2), 10);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert!(VanityRegistry::migration_consents(&2).is_none());
+	});
+}
+
+#[test]
+fn rekeying_a_name_keeps_its_expiry_deposit_and_history_once_accepted() {
+	new_test_ext().execute_with(|| {
+		let _ = Balances::deposit_creating(&1, 200);
+		let _ = Balances::deposit_creating(&2, 200);
+		let name = b"alice".to_name();
+		System::set_block_number(1);
+		register(1, b"alice");
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_ok!(VanityRegistry::schedule_action(
+			Origin::signed(1),
+			name.clone(),
+			ScheduledAction::Renew,
+			20
+		));
+		let before = VanityRegistry::owners(&name).unwrap();
+
+		assert_noop!(
+			VanityRegistry::rekey_owner(Origin::signed(2), name.clone(), 3),
+			Error::<Test>::NameNotOwned
+		);
+		assert_noop!(
+			VanityRegistry::rekey_owner(Origin::signed(1), name.clone(), 1),
+			Error::<Test>::RekeyToSelf
+		);
+		assert_ok!(VanityRegistry::rekey_owner(Origin::signed(1), name.clone(), 2));
+		assert_eq!(VanityRegistry::pending_rekeys(&name), Some(2));
+		assert_noop!(
+			VanityRegistry::accept_rekey(Origin::signed(3), name.clone()),
+			Error::<Test>::RekeyNotFound
+		);
+
+		assert_ok!(VanityRegistry::accept_rekey(Origin::signed(2), name.clone()));
+		let after = VanityRegistry::owners(&name).unwrap();
+		assert_eq!(after.id, 2);
+		assert_eq!(after.lock_period, before.lock_period);
+		assert_eq!(after.renewals, before.renewals);
+		assert_eq!(after.acquired_at, before.acquired_at);
+		assert_eq!(VanityRegistry::name_history(&name).renewals, 1);
+		assert_eq!(VanityRegistry::name_history(&name).transfers, 0);
+		assert_eq!(VanityRegistry::ownership_deposits(&1), 0);
+		assert_eq!(VanityRegistry::ownership_deposits(&2), before.deposit);
+		assert!(Balances::locks(&1).is_empty());
+		assert!(VanityRegistry::pending_rekeys(&name).is_none());
+		assert_eq!(VanityRegistry::names_of(&2), vec![name.clone()]);
+		let rekeyed = crate::Event::OwnerRekeyed(name.clone(), 1, 2);
+		assert_eq!(System::events().last().unwrap().event, Event::VanityRegistry(rekeyed));
+		assert_ok!(VanityRegistry::check_invariants());
+
+		// The scheduled renewal survives the rekey and is dispatched on behalf of the new key.
+		assert_eq!(VanityRegistry::scheduled_actions(&name, ScheduledAction::Renew), Some(20));
+		System::set_block_number(20);
+		MockScheduler::run(20);
+		let renewed = VanityRegistry::owners(&name).unwrap();
+		assert_eq!(renewed.renewals, before.renewals + 1);
+		assert_eq!(renewed.lock_period.end, 20 + RegisterPeriod::get());
+	});
+}
+
+#[test]
+fn payers_are_charged_the_renewal_fees_until_they_resign() {
+	new_test_ext().execute_with(|| {
+		RenewalFee::set(5);
+		MockPriceFeed::set(Some(1));
+		let _ = Balances::deposit_creating(&1, 100);
+		let _ = Balances::deposit_creating(&2, 100);
+		let name = b"alice".to_name();
+		System::set_block_number(1);
+		register(1, b"alice");
+
+		assert_noop!(
+			VanityRegistry::offer_payment(Origin::signed(1), name.clone()),
+			Error::<Test>::SelfPayment
+		);
+		assert_ok!(VanityRegistry::offer_payment(Origin::signed(2), name.clone()));
+		assert_noop!(
+			VanityRegistry::accept_payment_offer(Origin::signed(2), name.clone()),
+			Error::<Test>::NameNotOwned
+		);
+		assert_ok!(VanityRegistry::accept_payment_offer(Origin::signed(1), name.clone()));
+		assert_eq!(VanityRegistry::payers(&name), Some(2));
+
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_eq!(Balances::free_balance(&1), 100);
+		assert_eq!(Balances::free_balance(&2), 100 - 5);
+
+		assert_noop!(
+			VanityRegistry::end_payment(Origin::signed(3), name.clone()),
+			Error::<Test>::NotPaymentParty
+		);
+		assert_ok!(VanityRegistry::end_payment(Origin::signed(2), name.clone()));
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_eq!(Balances::free_balance(&1), 100 - 5);
+		assert_eq!(Balances::free_balance(&2), 100 - 5);
+	});
+}
+
+#[test]
+fn critical_names_are_only_unregistered_through_the_stronger_origin() {
+	new_test_ext().execute_with(|| {
+		register(1, b"treasury");
+		register(1, b"alice");
+
+		assert_noop!(
+			VanityRegistry::unregister(Origin::signed(1), b"TREASURY".to_name()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), b"alice".to_name()));
+	});
+
+	new_test_ext().execute_with(|| {
+		register(Council::get(), b"treasury");
+
+		let council = Origin::signed(Council::get());
+		assert_ok!(VanityRegistry::unregister(council, b"treasury".to_name()));
+		assert!(VanityRegistry::owner_of(&b"treasury".to_name()).is_none());
+	});
+}
+
+#[test]
+fn invariants_hold_until_the_state_is_corrupted() {
+	new_test_ext().execute_with(|| {
+		use_deposit_backend(DepositBackend::Reserve);
+		let _ = Balances::deposit_creating(&1, 3 * FundToLock::get());
+		register(1, b"alice");
+		register(1, b"bob");
+		let commit = VanityRegistry::hash_of(1, b"carol".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+		assert_ok!(VanityRegistry::unregister(Origin::signed(1), b"bob".to_name()));
+		assert_ok!(VanityRegistry::check_invariants());
+
+		OwnershipDeposits::<Test>::insert(1, 1);
+		assert_eq!(
+			VanityRegistry::check_invariants(),
+			Err("the ownership deposits of an account are miscounted")
+		);
+		OwnershipDeposits::<Test>::insert(1, OwnershipDeposit::get());
+		assert_ok!(VanityRegistry::check_invariants());
+
+		let end = VanityRegistry::owners(b"alice".to_name()).unwrap().lock_period.end;
+		ExpiryQueue::<Test>::remove(end, crate::Expiry::Name(b"alice".to_name()));
+		assert_eq!(VanityRegistry::check_invariants(), Err("a name never expires"));
+	});
+}
+
+#[test]
+fn lapsed_names_are_kept_for_the_grace_period() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		GracePeriod::set(10);
+		System::set_block_number(1);
+		register(1, &name);
+		register(2, b"bob");
+		assert_eq!(VanityRegistry::name_status(&name), Some(NameStatus::Active));
+
+		let end = 1 + RegisterPeriod::get();
+		VanityRegistry::on_finalize(end);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		assert_eq!(VanityRegistry::name_status(&name), Some(NameStatus::GracePeriod));
+		assert_noop!(
+			VanityRegistry::set_role(Origin::signed(1), name.clone(), role(b"payout"), 2),
+			Error::<Test>::NameInGracePeriod
+		);
+
+		System::set_block_number(end + 5);
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert_eq!(VanityRegistry::name_status(&name), Some(NameStatus::Active));
+		VanityRegistry::on_finalize(end + 10);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+		assert_eq!(VanityRegistry::owner_of(&b"bob".to_name()), None);
+		assert_eq!(VanityRegistry::name_status(&b"bob".to_name()), None);
+		let freed = crate::Event::NameFreed(b"bob".to_name(), FreeReason::Expired);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == Event::VanityRegistry(freed.clone())));
+	});
+}
+
+#[test]
+fn status_transitions_are_announced() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let announced = |event: crate::Event<Test>| {
+			System::events()
+				.iter()
+				.any(|record| record.event == Event::VanityRegistry(event.clone()))
+		};
+		GracePeriod::set(10);
+		System::set_block_number(1);
+		register(1, &name);
+		assert_ok!(VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 2, 10));
+		assert_ok!(VanityRegistry::freeze_name(Origin::root(), name.clone()));
+		assert!(announced(crate::Event::TransferCancelled(name.clone())));
+		assert!(announced(crate::Event::NameFrozen(name.clone())));
+		assert_ok!(VanityRegistry::unfreeze_name(Origin::root(), name.clone()));
+		assert!(announced(crate::Event::NameUnfrozen(name.clone())));
+
+		let end = 1 + RegisterPeriod::get();
+		VanityRegistry::on_finalize(end);
+		assert!(announced(crate::Event::NameInGracePeriod(name.clone(), end + 10)));
+
+		System::set_block_number(end + 5);
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		assert!(announced(crate::Event::NameLeftGracePeriod(name)));
+	});
+}
+
+#[test]
+fn reaped_accounts_leave_no_commits_and_lose_their_names_after_the_grace_period() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		let announced = |event: crate::Event<Test>| {
+			System::events()
+				.iter()
+				.any(|record| record.event == Event::VanityRegistry(event.clone()))
+		};
+		GracePeriod::set(10);
+		System::set_block_number(1);
+		register(1, &name);
+		let commit = VanityRegistry::hash_of(1, b"bob".to_name());
+		assert_ok!(VanityRegistry::commit(Origin::signed(1), commit));
+
+		System::set_block_number(5);
+		<VanityRegistry as frame_support::traits::OnKilledAccount<_>>::on_killed_account(&1);
+		// The cleanup is only queued until the expiries are processed.
+		assert!(LockPeriods::<Test>::contains_key(1, commit));
+		VanityRegistry::on_finalize(5);
+		assert!(!LockPeriods::<Test>::contains_key(1, commit));
+		assert!(!CommitDeposits::<Test>::contains_key(1, commit));
+		assert!(announced(crate::Event::CommitDropped(commit, 1)));
+		assert_eq!(VanityRegistry::name_status(&name), Some(NameStatus::GracePeriod));
+		assert_eq!(VanityRegistry::owners(&name).unwrap().lock_period.end, 5);
+		assert!(announced(crate::Event::NameInGracePeriod(name.clone(), 15)));
+		assert_ok!(VanityRegistry::check_invariants());
+
+		VanityRegistry::on_finalize(15);
+		assert!(!Owners::<Test>::contains_key(&name));
+	});
+}
+
+#[test]
+fn reaped_accounts_lose_their_names_right_away_without_a_grace_period() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		System::set_block_number(1);
+		register(1, &name);
+
+		System::set_block_number(5);
+		<VanityRegistry as frame_support::traits::OnKilledAccount<_>>::on_killed_account(&1);
+		VanityRegistry::on_finalize(5);
+		assert!(!Owners::<Test>::contains_key(&name));
+		assert!(System::events().iter().all(|record| !matches!(
+			record.event,
+			Event::VanityRegistry(crate::Event::NameInGracePeriod(..))
+		)));
+		let freed = crate::Event::NameFreed(name, FreeReason::Expired);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == Event::VanityRegistry(freed.clone())));
+		assert_ok!(VanityRegistry::check_invariants());
+	});
+}
+
+#[test]
+fn reaped_accounts_are_cleaned_up_within_the_expiry_budget() {
+	new_test_ext().execute_with(|| {
+		MaxExpiriesPerBlock::set(3);
+		System::set_block_number(1);
+		register(1, b"alice");
+		register(1, b"bobby");
+		register(1, b"carol");
+		for n in 1..5 {
+			VanityRegistry::on_finalize(n);
+		}
+
+		System::set_block_number(5);
+		<VanityRegistry as frame_support::traits::OnKilledAccount<_>>::on_killed_account(&1);
+		VanityRegistry::on_finalize(5);
+		assert_eq!(Owners::<Test>::iter().count(), 3);
+
+		for n in 6..12 {
+			System::set_block_number(n);
+			VanityRegistry::on_finalize(n);
+		}
+		assert_eq!(Owners::<Test>::iter().count(), 0);
+		assert!(ExpiryQueue::<Test>::iter()
+			.all(|(_, expiry, ())| !matches!(expiry, crate::Expiry::Account(..))));
+		assert_ok!(VanityRegistry::check_invariants());
+	});
+}
+
+#[test]
+fn renewals_are_announced_until_the_name_lapses() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		System::set_block_number(1);
+		register(1, &name);
+		let end = 1 + RegisterPeriod::get();
+
+		System::set_block_number(end - 1);
+		assert_ok!(VanityRegistry::renew(Origin::signed(1), name.clone()));
+		let renewed_end = end - 1 + RegisterPeriod::get();
+		let renewed = crate::Event::NameRenewed(name.clone(), 1, end, renewed_end);
+		assert_eq!(System::events().last().unwrap().event, Event::VanityRegistry(renewed));
+
+		// Not freed yet, as the expiry is only processed at the end of the block.
+		System::set_block_number(renewed_end);
+		assert_noop!(
+			VanityRegistry::renew(Origin::signed(1), name.clone()),
+			Error::<Test>::RegistrationLapsed
+		);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+	});
+}
+
+#[test]
+fn frozen_names_are_closed_to_their_owner_until_unfrozen() {
+	new_test_ext().execute_with(|| {
+		let name = b"alice".to_name();
+		System::set_block_number(1);
+		register(1, &name);
+		assert_ok!(VanityRegistry::schedule_transfer(Origin::signed(1), name.clone(), 2, 10));
+		assert_eq!(VanityRegistry::name_status(&name), Some(NameStatus::PendingTransfer));
+
+		assert_noop!(
+			VanityRegistry::unfreeze_name(Origin::root(), name.clone()),
+			Error::<Test>::TransferPending
+		);
+		assert_ok!(VanityRegistry::freeze_name(Origin::root(), name.clone()));
+		assert!(VanityRegistry::scheduled_transfers(&name).is_none());
+		assert_noop!(
+			VanityRegistry::set_role(Origin::signed(1), name.clone(), role(b"payout"), 2),
+			Error::<Test>::NameFrozen
+		);
+		assert_noop!(
+			VanityRegistry::freeze_name(Origin::root(), name.clone()),
+			Error::<Test>::NameFrozen
+		);
+
+		let end = 1 + RegisterPeriod::get();
+		VanityRegistry::on_finalize(end);
+		assert_eq!(VanityRegistry::owner_of(&name), Some(1));
+
+		System::set_block_number(end + 5);
+		assert_ok!(VanityRegistry::unfreeze_name(Origin::root(), name.clone()));
+		assert_eq!(VanityRegistry::name_status(&name), Some(NameStatus::Active));
+		assert_eq!(VanityRegistry::owners(&name).unwrap().lock_period.end, end + 5);
+		assert_noop!(
+			VanityRegistry::unfreeze_name(Origin::root(), name.clone()),
+			Error::<Test>::NameActive
+		);
+		VanityRegistry::on_finalize(end + 5);
+		assert_eq!(VanityRegistry::owner_of(&name), None);
+	});
+}
+
+#[test]
+fn reported_names_are_disputed_until_the_report_is_resolved() {
+	new_test_ext().execute_with(|| {
+		let suspect = b"a1ice".to_name();
+		System::set_block_number(1);
+		register(1, b"alice");
+		register(2, &suspect);
+		assert_ok!(VanityRegistry::schedule_transfer(Origin::signed(2), suspect.clone(), 4, 10));
+
+		let _ = Balances::deposit_creating(&3, 100);
+		assert_ok!(VanityRegistry::report_squatting(
+			Origin::signed(3),
+			suspect.clone(),
+			b"alice".to_name()
+		));
+		assert_eq!(VanityRegistry::name_status(&suspect), Some(NameStatus::Disputed));
+		assert!(VanityRegistry::scheduled_transfers(&suspect).is_none());
+		assert_noop!(
+			VanityRegistry::schedule_transfer(Origin::signed(2), suspect.clone(), 4, 10),
+			Error::<Test>::NameDisputed
+		);
+
+		assert_ok!(VanityRegistry::resolve_report(Origin::root(), suspect.clone(), false));
+		assert_eq!(VanityRegistry::name_status(&suspect), Some(NameStatus::Active));
+	});
+}
+
+#[test]
+fn names_get_a_status_upon_migrating_to_v8() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register(1, b"alice");
+		register(2, b"bob");
+		assert_ok!(VanityRegistry::schedule_transfer(Origin::signed(2), b"bob".to_name(), 1, 10));
+		crate::NameStatuses::<Test>::remove_all(None);
+
+		StorageVersion::new(7).put::<VanityRegistry>();
+		Migrate::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			VanityRegistry::on_chain_storage_version(),
+			VanityRegistry::current_storage_version()
+		);
+		assert_eq!(VanityRegistry::name_status(&b"alice".to_name()), Some(NameStatus::Active));
+		assert_eq!(
+			VanityRegistry::name_status(&b"bob".to_name()),
+			Some(NameStatus::PendingTransfer)
+		);
+	});
+}
+
+#[test]
+fn calls_keep_their_indices() {
+	// Signed transactions and clients refer to calls by index, so reordering them breaks both.
+	let expected = [
+		"commit",
+		"reveal",
+		"renew",
+		"unregister",
+		"set_fallback_price",
+		"report_squatting",
+		"resolve_report",
+		"set_deposit_beneficiary",
+		"reconcile_lock",
+		"force_unregister",
+		"offer_deposit",
+		"accept_deposit_offer",
+		"end_deposit_delegation",
+		"attest_ownership",
+		"set_joint_owners",
+		"approve_action",
+		"fractionalize",
+		"transfer_shares",
+		"distribute_revenue",
+		"vote_renewal",
+		"appeal_takedown",
+		"resolve_appeal",
+		"set_name_denied",
+		"schedule_action",
+		"cancel_scheduled_action",
+		"contribute_to_renewal",
+		"set_role",
+		"clear_role",
+		"set_guardians",
+		"vote_recovery",
+		"veto_recovery",
+		"execute_recovery",
+		"set_heir",
+		"remove_heir",
+		"claim_inheritance",
+		"schedule_transfer",
+		"cancel_transfer",
+		"reveal_bundle",
+		"set_lock_backing",
+		"mint_voucher",
+		"revoke_voucher",
+		"redeem_voucher",
+		"reveal_gift",
+		"reject_gift",
+		"buy_credits",
+		"withdraw_credits",
+		"reveal_for_periods",
+		"watch",
+		"unwatch",
+		"grant_permanent",
+		"revoke_permanent",
+		"consent_to_migration",
+		"migrate_account",
+		"offer_payment",
+		"accept_payment_offer",
+		"end_payment",
+		"freeze_name",
+		"unfreeze_name",
+		"reveal_salted",
+		"reveal_batched",
+		"reveal_with_proof",
+		"set_renewal_fee_ceiling",
+		"rekey_owner",
+		"accept_rekey",
+		"register",
+	];
+	let calls = match <crate::Call<Test> as scale_info::TypeInfo>::type_info().type_def() {
+		scale_info::TypeDef::Variant(calls) => calls
+			.variants()
+			.iter()
+			.map(|call| (call.index() as usize, *call.name()))
+			.collect::<Vec<_>>(),
+		_ => panic!("calls are an enum"),
+	};
+	assert_eq!(calls, expected.iter().copied().enumerate().collect::<Vec<_>>());
+}
@@ -0,0 +1,232 @@
+//! The traits through which the pallet is configured and other pallets make use of it.
+
+use super::*;
+use frame_support::traits::{Contains, EnsureOrigin};
+use sp_runtime::traits::{BlakeTwo256, Keccak256};
+
+/// Tells whether the identity of an account has been vouched for, e.g. by a positive
+/// `pallet-identity` judgement (`Reasonable` or `KnownGood`) from one of the registrars.
+pub trait IdentityVerifier<AccountId> {
+	/// Whether the identity of "who" has been positively judged.
+	fn has_positive_judgement(who: &AccountId) -> bool;
+}
+
+impl<AccountId> IdentityVerifier<AccountId> for () {
+	fn has_positive_judgement(_who: &AccountId) -> bool {
+		false
+	}
+}
+
+/// The hashing of commitments, independent of `frame_system::Config::Hashing`, so commitments
+/// can be made with the tooling of other ecosystems, e.g. Keccak-256 for Ethereum wallets.
+pub trait CommitHashing<Output> {
+	/// The tag of the algorithm, part of the domain of every commitment preimage so the same
+	/// preimage never commits under two algorithms, see `Pallet::commit_domain`.
+	const TAG: &'static [u8];
+
+	/// The commitment to "preimage".
+	fn hash(preimage: &[u8]) -> Output;
+}
+
+impl<Output: Decode> CommitHashing<Output> for BlakeTwo256 {
+	const TAG: &'static [u8] = b"blake2-256";
+
+	fn hash(preimage: &[u8]) -> Output {
+		let hash = sp_io::hashing::blake2_256(preimage);
+		Output::decode(&mut TrailingZeroInput::new(&hash[..]))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+}
+
+impl<Output: Decode> CommitHashing<Output> for Keccak256 {
+	const TAG: &'static [u8] = b"keccak-256";
+
+	fn hash(preimage: &[u8]) -> Output {
+		let hash = sp_io::hashing::keccak_256(preimage);
+		Output::decode(&mut TrailingZeroInput::new(&hash[..]))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+}
+
+/// Verifies that a reveal matches a commit, e.g. to accept a zero-knowledge proof against a
+/// blinded commitment whose preimage does not link the name to the committing account, see
+/// `Pallet::reveal_with_proof`.
+pub trait RevealVerifier<AccountId, Name, Hash> {
+	/// The commit of "who" that "proof" shows to be to "name", or None if it shows none.
+	/// "hashed" is the commit `Pallet::hash_of` expects.
+	fn commit_of(who: &AccountId, name: &Name, hashed: Hash, proof: &[u8]) -> Option<Hash>;
+
+	/// The weight of verifying a proof of "len" bytes.
+	fn weight(len: u32) -> Weight;
+}
+
+/// Accepts the commits of `Pallet::hash_of` only, ignoring the proof.
+impl<AccountId, Name, Hash> RevealVerifier<AccountId, Name, Hash> for () {
+	fn commit_of(_who: &AccountId, _name: &Name, hashed: Hash, _proof: &[u8]) -> Option<Hash> {
+		Some(hashed)
+	}
+
+	fn weight(_len: u32) -> Weight {
+		0
+	}
+}
+
+/// Decides which names may be registered, e.g. to keep offensive or impersonation-prone names
+/// off the registry. Consulted with the normalized name at reveal.
+pub trait NamePolicy<Name> {
+	/// Whether "name" may be registered.
+	fn is_allowed(name: &Name) -> bool;
+}
+
+impl<Name> NamePolicy<Name> for () {
+	fn is_allowed(_name: &Name) -> bool {
+		true
+	}
+}
+
+/// Denies the names whose hashes, see `Pallet::name_hash`, are on the `DeniedNames` list kept
+/// by the `AdminOrigin`.
+pub struct DenyList<T>(PhantomData<T>);
+
+impl<T: Config> NamePolicy<T::Name> for DenyList<T> {
+	fn is_allowed(name: &T::Name) -> bool {
+		!DeniedNames::<T>::contains_key(Pallet::<T>::name_hash(name))
+	}
+}
+
+/// Decides which origins may perform an operation on a name, e.g. `Config::UnregisterOrigin`,
+/// and which account acts for them. The pallet still checks that this account may act on the
+/// name, e.g. that it owns it. Consulted with the normalized name.
+pub trait NameOrigin<Origin, Name, AccountId> {
+	/// The account acting for "origin" on "name", or `BadOrigin` if it may not.
+	fn ensure_origin(origin: Origin, name: &Name) -> Result<AccountId, DispatchError>;
+}
+
+/// Any signed origin acts as its account, the default for every operation.
+pub struct SignedOrigin;
+
+impl<Origin, Name, AccountId> NameOrigin<Origin, Name, AccountId> for SignedOrigin
+where
+	Origin: Into<Result<frame_system::RawOrigin<AccountId>, Origin>>,
+{
+	fn ensure_origin(origin: Origin, _name: &Name) -> Result<AccountId, DispatchError> {
+		Ok(ensure_signed(origin)?)
+	}
+}
+
+/// Requires the stronger origin "Strong", e.g. `EnsureSignedBy` a council account, for the
+/// names "Critical" contains and a signed origin for the others.
+pub struct StrongerOriginFor<Critical, Strong>(PhantomData<(Critical, Strong)>);
+
+impl<Origin, Name, AccountId, Critical, Strong> NameOrigin<Origin, Name, AccountId>
+	for StrongerOriginFor<Critical, Strong>
+where
+	Origin: Into<Result<frame_system::RawOrigin<AccountId>, Origin>>,
+	Critical: Contains<Name>,
+	Strong: EnsureOrigin<Origin, Success = AccountId>,
+{
+	fn ensure_origin(origin: Origin, name: &Name) -> Result<AccountId, DispatchError> {
+		if Critical::contains(name) {
+			Strong::ensure_origin(origin).map_err(|_| DispatchError::BadOrigin)
+		} else {
+			SignedOrigin::ensure_origin(origin, name)
+		}
+	}
+}
+
+/// Provides the price of one reference unit (e.g. a USD cent) in the chain's currency.
+pub trait PriceFeed<Balance> {
+	/// The latest known price, or `None` if the feed has nothing reliable to offer.
+	fn price() -> Option<Balance>;
+}
+
+impl<Balance> PriceFeed<Balance> for () {
+	fn price() -> Option<Balance> {
+		None
+	}
+}
+
+/// Discounts the renewal fee of a name by its tenure, the number of blocks its owner has held it
+/// for without interruption.
+pub trait TenureDiscount<BlockNumber> {
+	/// The share of the renewal fee waived for a name held for "tenure" blocks.
+	fn discount(tenure: BlockNumber) -> Perbill;
+}
+
+impl<BlockNumber> TenureDiscount<BlockNumber> for () {
+	fn discount(_tenure: BlockNumber) -> Perbill {
+		Perbill::zero()
+	}
+}
+
+/// Grants the discount of the longest tenure milestone reached, given as pairs of a tenure in
+/// blocks and the discount from then on.
+pub struct TenureMilestones<Milestones>(PhantomData<Milestones>);
+
+impl<BlockNumber, Milestones> TenureDiscount<BlockNumber> for TenureMilestones<Milestones>
+where
+	BlockNumber: PartialOrd,
+	Milestones: Get<Vec<(BlockNumber, Perbill)>>,
+{
+	fn discount(tenure: BlockNumber) -> Perbill {
+		Milestones::get()
+			.into_iter()
+			.filter(|(milestone, _)| *milestone <= tenure)
+			.map(|(_, discount)| discount)
+			.max()
+			.unwrap_or_else(Perbill::zero)
+	}
+}
+
+/// Tells how much of the balance of an account is locked by another pallet, e.g. staked through
+/// `pallet-staking` or vesting through `pallet-vesting`.
+pub trait LockedBalanceInspector<AccountId, Balance> {
+	/// The balance of "who" locked by the inspected pallet.
+	fn locked_balance(who: &AccountId) -> Balance;
+}
+
+impl<AccountId, Balance: Zero> LockedBalanceInspector<AccountId, Balance> for () {
+	fn locked_balance(_who: &AccountId) -> Balance {
+		Zero::zero()
+	}
+}
+
+/// Inspects two pallets at once. As their locks overlap, the larger of the two locked balances
+/// is counted rather than their sum.
+impl<AccountId, Balance, A, B> LockedBalanceInspector<AccountId, Balance> for (A, B)
+where
+	Balance: Ord,
+	A: LockedBalanceInspector<AccountId, Balance>,
+	B: LockedBalanceInspector<AccountId, Balance>,
+{
+	fn locked_balance(who: &AccountId) -> Balance {
+		A::locked_balance(who).max(B::locked_balance(who))
+	}
+}
+
+/// Gives other pallets read access to the registry, e.g. to pay out to the owner of a name.
+pub trait NameProvider<AccountId, Name> {
+	/// The owner of "name" in any casing, if registered.
+	fn owner_of(name: &Name) -> Option<AccountId>;
+
+	/// What "name" in any casing resolves to, or None if it is not registered.
+	fn resolve(name: &Name) -> Option<Resolution<AccountId>>;
+
+	/// The names owned by "who".
+	fn names_of(who: &AccountId) -> Vec<Name>;
+
+	/// The account filling "role" under "name" in any casing, if any.
+	fn role_of(name: &Name, role: &[u8]) -> Option<AccountId>;
+}
+
+/// Prices the registration of names, e.g. for a pallet registering names on behalf of others.
+pub trait NamePricer<Balance> {
+	/// The current deposits and fees of a name, or None if they can not be converted.
+	fn quote() -> Option<Quote<Balance>>;
+}
+
+/// Tells whether a name may be claimed, e.g. to check a name before committing to it.
+pub trait NameValidator<AccountId, Name> {
+	/// The normalized form of "name" if "who" may claim it, why "who" may not otherwise.
+	fn validate(who: &AccountId, name: &Name) -> Result<Name, DispatchError>;
+}
@@ -0,0 +1,346 @@
+//! The types stored by the pallet. The types it returns and carries in its events live in
+//! `vanity-registry-primitives`, shared with off-chain tooling, and are re-exported here.
+
+use super::*;
+
+pub use vanity_registry_primitives::{
+	Acquisition, EnsMetadata, FreeReason, JointAction, LockPeriod, NameHistory, NameStatus, Page,
+	Quote, RegistrationDetails, RegistrationInfo, Resolution, ScheduledAction, ScheduledTransfer,
+	MAX_PAGE_SIZE,
+};
+
+/// An account with a commit
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub struct Owner<AccountId, Hash, BlockNumber, Balance> {
+	pub(crate) id: AccountId,
+	pub(crate) commit: Hash,
+	pub(crate) lock_period: LockPeriod<BlockNumber>,
+	/// The fund held from the owner for as long as the name is kept.
+	pub(crate) deposit: Balance,
+	/// How the owner came by the name.
+	pub(crate) acquisition: Acquisition,
+	/// The block at which the owner came by the name.
+	pub(crate) acquired_at: BlockNumber,
+	/// The number of times the owner has renewed the name.
+	pub(crate) renewals: u32,
+}
+
+impl<AccountId, Hash, BlockNumber: Copy, Balance> Owner<AccountId, Hash, BlockNumber, Balance> {
+	/// The account owning the name.
+	pub fn id(&self) -> &AccountId {
+		&self.id
+	}
+
+	/// The commit the name was revealed from.
+	pub fn commit(&self) -> &Hash {
+		&self.commit
+	}
+
+	/// The period from the commit of the name until it expires.
+	pub fn lock_period(&self) -> &LockPeriod<BlockNumber> {
+		&self.lock_period
+	}
+
+	/// The fund held for as long as the name is kept.
+	pub fn deposit(&self) -> &Balance {
+		&self.deposit
+	}
+
+	/// The block at which the name was committed to, i.e. the beginning of its lock period.
+	pub fn committed_at(&self) -> BlockNumber {
+		self.lock_period.begin
+	}
+
+	/// How the owner came by the name.
+	pub fn acquisition(&self) -> Acquisition {
+		self.acquisition
+	}
+
+	/// The block at which the owner came by the name, e.g. revealed it.
+	pub fn acquired_at(&self) -> BlockNumber {
+		self.acquired_at
+	}
+
+	/// The number of times the owner has renewed the name.
+	pub fn renewals(&self) -> u32 {
+		self.renewals
+	}
+}
+
+/// The keys of the entries in the child trie of an account, see `Pallet::owner_trie`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum OwnerTrieKey<Hash, Name> {
+	/// A pending commit, mapped to its `LockPeriod`.
+	#[codec(index = 0)]
+	Commit(Hash),
+	/// An owned name, mapped to its `Owner`.
+	#[codec(index = 1)]
+	Name(Name),
+}
+
+/// Something due to expire at a given block, see `Pallet::process_expiries`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum Expiry<AccountId, Hash, Name> {
+	/// The commit of an account.
+	#[codec(index = 0)]
+	Commit(AccountId, Hash),
+	/// A registered name.
+	#[codec(index = 1)]
+	Name(Name),
+	/// The scheduled transfer of a name, see `Pallet::schedule_transfer`.
+	#[codec(index = 2)]
+	Transfer(Name),
+	/// The cleanup after a reaped account, resuming after the given name of the account if any,
+	/// see `Pallet::reap_account`.
+	#[codec(index = 3)]
+	Account(AccountId, Option<Name>),
+}
+
+/// The first byte of the keys of all owned names in an account's child trie, i.e. the index of
+/// `OwnerTrieKey::Name`.
+pub(crate) const OWNER_TRIE_NAME_PREFIX: u8 = 1;
+
+/// The text of a name for display, see `Pallet::display_name`. Invalid UTF-8 sequences are shown
+/// as U+FFFD, and a precision (e.g. `{:.8}`) truncates valid names by characters, not bytes.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct DisplayName(pub(crate) Vec<u8>);
+
+impl fmt::Display for DisplayName {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let mut bytes = &self.0[..];
+		loop {
+			match sp_std::str::from_utf8(bytes) {
+				Ok(text) if text.len() == self.0.len() => return f.pad(text),
+				Ok(text) => return f.write_str(text),
+				Err(error) => {
+					let (valid, rest) = bytes.split_at(error.valid_up_to());
+					f.write_str(sp_std::str::from_utf8(valid).map_err(|_| fmt::Error)?)?;
+					f.write_str("\u{FFFD}")?;
+					bytes = &rest[error.error_len().unwrap_or(rest.len())..];
+				},
+			}
+		}
+	}
+}
+
+/// A bonded report claiming a name is a clone of an established name
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SquattingReport<AccountId, Name, Balance, BlockNumber> {
+	pub(crate) reporter: AccountId,
+	pub(crate) original: Name,
+	pub(crate) bond: Balance,
+	pub(crate) filed_at: BlockNumber,
+}
+
+/// A name taken down by `force_unregister`, which its former owner may appeal
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Takedown<Owner, Balance, BlockNumber> {
+	pub(crate) owner: Owner,
+	pub(crate) taken_at: BlockNumber,
+	/// The block by which an appeal must be filed, or decided once filed.
+	pub(crate) deadline: BlockNumber,
+	/// The bond reserved from the former owner once they appealed.
+	pub(crate) appeal_bond: Option<Balance>,
+}
+
+/// Accounts owning a name together, see `Pallet::set_joint_owners`.
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct JointOwnership<Members> {
+	/// The accounts which may approve actions on the name.
+	pub members: Members,
+	/// The number of approvals an action needs to be executed.
+	pub threshold: u32,
+}
+
+/// The approvals collected for a `JointAction` until it expires.
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PendingAction<Approvals, BlockNumber> {
+	/// The owners who approved the action so far.
+	pub approvals: Approvals,
+	/// The block from which the approvals are void.
+	pub expires_at: BlockNumber,
+}
+
+/// The accounts trusted to recover a name for its owner, see `Pallet::set_guardians`.
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Guardianship<Guardians> {
+	/// The accounts which may vote on a recovery of the name.
+	pub guardians: Guardians,
+	/// The number of votes a recovery needs to be approved.
+	pub threshold: u32,
+}
+
+/// The recovery of a name to a new account, voted on by its guardians.
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Recovery<AccountId, Votes, BlockNumber> {
+	/// The account the name is to be moved to.
+	pub new_owner: AccountId,
+	/// The guardians who voted for the recovery so far.
+	pub votes: Votes,
+	/// The block from which the votes are void unless the recovery was approved.
+	pub expires_at: BlockNumber,
+	/// The block from which the approved recovery may be executed, the owner may veto it
+	/// until then.
+	pub executable_at: Option<BlockNumber>,
+}
+
+/// The account inheriting a name once its owner has been inactive, see `Pallet::set_heir`.
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Heir<AccountId, BlockNumber> {
+	/// The account which may claim the name.
+	pub account: AccountId,
+	/// The number of blocks the owner must not act on the name for before it may be claimed.
+	pub inactivity_blocks: BlockNumber,
+	/// The block at which the owner last acted on the name.
+	pub last_active: BlockNumber,
+}
+
+impl<AccountId, BlockNumber: Copy + Saturating> Heir<AccountId, BlockNumber> {
+	/// The block from which the heir may claim the name, unless its owner acts on it before. It
+	/// does not depend on the expiry of the name, which may lapse first.
+	pub fn claimable_at(&self) -> BlockNumber {
+		self.last_active.saturating_add(self.inactivity_blocks)
+	}
+}
+
+/// A name registered as a gift, which its recipient may reject for a while, see
+/// `Pallet::reveal_gift`.
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Gift<AccountId, BlockNumber> {
+	/// The account which paid for the name and backs its ownership deposit.
+	pub giver: AccountId,
+	/// The last block at which the recipient may reject the name.
+	pub rejectable_until: BlockNumber,
+}
+
+/// The watchers of a freed name, who alone may claim it for a while, see `Pallet::watch`.
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PriorityWindow<Watchers, BlockNumber> {
+	/// The accounts watching the name when it was freed.
+	pub watchers: Watchers,
+	/// The first block at which anyone may claim the name again.
+	pub ends_at: BlockNumber,
+}
+
+/// What a voucher entitles its bearer to register without paying the registration fee, see
+/// `Pallet::mint_voucher`.
+#[derive(Decode, Encode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum Voucher<Name> {
+	/// Any name the bearer could register.
+	#[codec(index = 0)]
+	AnyName,
+	/// Only the given name, in normalized form, which nobody else may claim meanwhile.
+	#[codec(index = 1)]
+	Reserved(Name),
+}
+
+/// The way the pallet holds the funds backing commits.
+#[derive(Decode, Encode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum DepositBackend {
+	/// A currency lock, which may overlap with the other locks on the account.
+	Lock,
+	/// A reserve, which segregates the funds exactly.
+	Reserve,
+}
+
+impl Default for DepositBackend {
+	fn default() -> Self {
+		DepositBackend::Lock
+	}
+}
+
+/// Lock funds against everything except paying for transactions, so an account with all of its
+/// funds locked by the registry can still unregister or renew.
+pub struct DefaultLockReasons;
+impl Get<WithdrawReasons> for DefaultLockReasons {
+	fn get() -> WithdrawReasons {
+		WithdrawReasons::except(WithdrawReasons::TRANSACTION_PAYMENT)
+	}
+}
+
+pub(crate) type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub(crate) type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+pub(crate) type OwnerOf<T> = Owner<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::Hash,
+	<T as frame_system::Config>::BlockNumber,
+	BalanceOf<T>,
+>;
+pub(crate) type RegistrationDetailsOf<T> = RegistrationDetails<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::Name,
+	<T as frame_system::Config>::BlockNumber,
+	BalanceOf<T>,
+>;
+pub(crate) type RegistrationInfoOf<T> = RegistrationInfo<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::Name,
+	<T as frame_system::Config>::BlockNumber,
+	BalanceOf<T>,
+>;
+pub(crate) type EnsMetadataOf<T> = EnsMetadata<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::Name,
+	<T as frame_system::Config>::BlockNumber,
+>;
+pub(crate) type PrefixOf<T> = BoundedVec<u8, <T as Config>::MaxPrefixLen>;
+pub(crate) type MembersOf<T> =
+	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxJointOwners>;
+pub(crate) type ExpiryOf<T> = Expiry<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::Hash,
+	<T as Config>::Name,
+>;
+pub(crate) type TakedownOf<T> =
+	Takedown<OwnerOf<T>, BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+pub(crate) type SharesOf<T> =
+	BoundedVec<(<T as frame_system::Config>::AccountId, u32), <T as Config>::MaxShareholders>;
+pub(crate) type VotersOf<T> =
+	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxShareholders>;
+pub(crate) type ContributionsOf<T> = BoundedVec<
+	(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+	<T as Config>::MaxPoolContributors,
+>;
+pub(crate) type RoleOf<T> = BoundedVec<u8, <T as Config>::MaxRoleLen>;
+pub(crate) type JointActionOf<T> = JointAction<
+	<T as frame_system::Config>::AccountId,
+	RoleOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+pub(crate) type RolesOf<T> =
+	BoundedVec<(RoleOf<T>, <T as frame_system::Config>::AccountId), <T as Config>::MaxRoles>;
+pub(crate) type GuardiansOf<T> =
+	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxGuardians>;
+pub(crate) type GuardianshipOf<T> = Guardianship<GuardiansOf<T>>;
+pub(crate) type RecoveryOf<T> = Recovery<
+	<T as frame_system::Config>::AccountId,
+	GuardiansOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+pub(crate) type HeirOf<T> =
+	Heir<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+pub(crate) type ScheduledTransferOf<T> = ScheduledTransfer<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+>;
+pub(crate) type GiftOf<T> =
+	Gift<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+pub(crate) type WatchersOf<T> =
+	BoundedVec<(<T as frame_system::Config>::AccountId, BalanceOf<T>), <T as Config>::MaxWatchers>;
+pub(crate) type PriorityWindowOf<T> = PriorityWindow<
+	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxWatchers>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+pub(crate) type VoucherOf<T> = Voucher<<T as Config>::Name>;
+pub(crate) type VoucherCodeOf<T> = BoundedVec<u8, <T as Config>::MaxVoucherCodeLen>;
+pub(crate) type PendingActionOf<T> =
+	PendingAction<MembersOf<T>, <T as frame_system::Config>::BlockNumber>;
+pub(crate) type SquattingReportOf<T> = SquattingReport<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::Name,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
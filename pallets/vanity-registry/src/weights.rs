@@ -1,25 +1,8 @@
-//! Autogenerated weights for `vanity_registry`
+//! Weights for `vanity_registry`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2021-10-24, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: None, WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
-
-// Executed Command:
-// ./target/release/node-template
-// benchmark
-// --chain
-// dev
-// --pallet
-// vanity-registry
-// --extrinsic
-// *
-// --steps
-// 50
-// --repeat
-// 20
-// --output
-// pallets/vanity-registry/src/weights.rs
-
+//! `SubstrateWeight` takes the database costs from the runtime's `DbWeight`. The base weights
+//! have not been benchmarked yet, `scripts/benchmark.sh` regenerates this file from the node's
+//! benchmark command.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -31,62 +14,1314 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn commit() -> Weight;
 	fn reveal(l: usize) -> Weight;
+	fn register(l: usize) -> Weight;
 	fn renew() -> Weight;
 	fn unregister() -> Weight;
+	fn force_unregister() -> Weight;
+	fn set_deposit_beneficiary() -> Weight;
+	fn offer_deposit() -> Weight;
+	fn accept_deposit_offer() -> Weight;
+	fn end_deposit_delegation() -> Weight;
+	fn attest_ownership(c: u32) -> Weight;
+	fn set_joint_owners(m: u32) -> Weight;
+	fn approve_action() -> Weight;
+	fn fractionalize() -> Weight;
+	fn transfer_shares() -> Weight;
+	fn distribute_revenue(h: u32) -> Weight;
+	fn vote_renewal(h: u32) -> Weight;
+	fn reconcile_lock() -> Weight;
+	fn appeal_takedown() -> Weight;
+	fn resolve_appeal() -> Weight;
+	fn process_expiries(e: u32) -> Weight;
+	fn set_fallback_price() -> Weight;
+	fn set_name_denied() -> Weight;
+	fn report_squatting() -> Weight;
+	fn resolve_report() -> Weight;
+	fn schedule_action() -> Weight;
+	fn cancel_scheduled_action() -> Weight;
+	fn contribute_to_renewal() -> Weight;
+	fn set_role() -> Weight;
+	fn clear_role() -> Weight;
+	fn set_guardians(g: u32) -> Weight;
+	fn vote_recovery() -> Weight;
+	fn veto_recovery() -> Weight;
+	fn execute_recovery() -> Weight;
+	fn set_heir() -> Weight;
+	fn remove_heir() -> Weight;
+	fn claim_inheritance() -> Weight;
+	fn schedule_transfer() -> Weight;
+	fn cancel_transfer() -> Weight;
+	fn reveal_bundle(n: u32) -> Weight;
+	fn set_lock_backing() -> Weight;
+	fn mint_voucher() -> Weight;
+	fn revoke_voucher() -> Weight;
+	fn redeem_voucher(_l: usize) -> Weight;
+	fn reveal_gift(_l: usize) -> Weight;
+	fn reject_gift() -> Weight;
+	fn buy_credits() -> Weight;
+	fn withdraw_credits() -> Weight;
+	fn reveal_for_periods(l: usize) -> Weight;
+	fn watch() -> Weight;
+	fn unwatch() -> Weight;
+	fn grant_permanent() -> Weight;
+	fn revoke_permanent() -> Weight;
+	fn consent_to_migration() -> Weight;
+	fn migrate_account(n: u32) -> Weight;
+	fn offer_payment() -> Weight;
+	fn accept_payment_offer() -> Weight;
+	fn end_payment() -> Weight;
+	fn freeze_name() -> Weight;
+	fn unfreeze_name() -> Weight;
+	fn reveal_salted(l: usize, s: u32) -> Weight;
+	fn reveal_batched(l: usize, p: u32) -> Weight;
+	fn reveal_with_proof(l: usize) -> Weight;
+	fn set_renewal_fee_ceiling() -> Weight;
+	fn rekey_owner() -> Weight;
+	fn accept_rekey() -> Weight;
 }
 
 /// Weight functions for `vanity_registry`.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	// Storage: VanityRegistry LockPeriods (r:2 w:1)
+	// Storage: VanityRegistry LockPeriods (r:1 w:1)
+	// Storage: VanityRegistry CommitDeposits (r:2 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry LockBackedAccounts (r:1 w:0)
 	fn commit() -> Weight {
 		(57_300_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: VanityRegistry LockPeriods (r:1 w:1)
 	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry FallbackPrice (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Takedowns (r:1 w:0)
+	// Storage: VanityRegistry RevealCount (r:1 w:1)
+	// Storage: VanityRegistry DeniedNames (r:1 w:0)
+	// Storage: VanityRegistry VoucherReservations (r:1 w:0)
+	// Storage: VanityRegistry PriorityWindows (r:1 w:0)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry BatchReveals (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
 	fn reveal(_l: usize) -> Weight {
 		(24_625_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry FallbackPrice (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Takedowns (r:1 w:0)
+	// Storage: VanityRegistry RevealCount (r:1 w:1)
+	// Storage: VanityRegistry DeniedNames (r:1 w:0)
+	// Storage: VanityRegistry VoucherReservations (r:1 w:0)
+	// Storage: VanityRegistry PriorityWindows (r:1 w:0)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	fn register(_l: usize) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry FallbackPrice (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry DepositDelegations (r:1 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: VanityRegistry CommitDeposits (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	// Storage: VanityRegistry RenewalCredits (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: VanityRegistry PermanentNames (r:1 w:0)
+	// Storage: VanityRegistry Payers (r:1 w:0)
+	// Storage: VanityRegistry NameHistories (r:1 w:1)
+	// Storage: VanityRegistry RenewalFeeCeiling (r:1 w:0)
+	fn renew() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry LockPeriods (r:1 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry DepositBeneficiaries (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry ScheduledActions (r:1 w:1)
+	// Storage: VanityRegistry NameRoles (r:0 w:1)
+	// Storage: VanityRegistry Guardians (r:0 w:1)
+	// Storage: VanityRegistry Recoveries (r:0 w:1)
+	// Storage: VanityRegistry Heirs (r:0 w:1)
+	// Storage: VanityRegistry ScheduledTransfers (r:1 w:1)
+	// Storage: VanityRegistry Gifts (r:0 w:1)
+	// Storage: VanityRegistry Watchers (r:1 w:1)
+	// Storage: VanityRegistry PermanentNames (r:1 w:0)
+	// Storage: VanityRegistry PriorityWindows (r:0 w:1)
+	// Storage: VanityRegistry PaymentOffers (r:0 w:1)
+	// Storage: VanityRegistry Payers (r:0 w:1)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry NameHistories (r:0 w:1)
+	fn unregister() -> Weight {
+		(92_900_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(22 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry CommitDeposits (r:1 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry DepositBeneficiaries (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Takedowns (r:0 w:1)
+	// Storage: VanityRegistry TakedownDeadlines (r:0 w:1)
+	// Storage: VanityRegistry ScheduledActions (r:1 w:1)
+	// Storage: VanityRegistry NameRoles (r:0 w:1)
+	// Storage: VanityRegistry Guardians (r:0 w:1)
+	// Storage: VanityRegistry Recoveries (r:0 w:1)
+	// Storage: VanityRegistry Heirs (r:0 w:1)
+	// Storage: VanityRegistry ScheduledTransfers (r:1 w:1)
+	// Storage: VanityRegistry Gifts (r:0 w:1)
+	// Storage: VanityRegistry Watchers (r:1 w:1)
+	// Storage: VanityRegistry PriorityWindows (r:0 w:1)
+	// Storage: VanityRegistry PaymentOffers (r:0 w:1)
+	// Storage: VanityRegistry Payers (r:0 w:1)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry NameHistories (r:0 w:1)
+	fn force_unregister() -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(24 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry DepositBeneficiaries (r:0 w:1)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	fn set_deposit_beneficiary() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry DepositDelegations (r:1 w:0)
+	// Storage: VanityRegistry DepositOffers (r:0 w:1)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	fn offer_deposit() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry DepositDelegations (r:1 w:1)
+	// Storage: VanityRegistry DepositOffers (r:1 w:1)
+	// Storage: VanityRegistry DepositBeneficiaries (r:0 w:1)
+	// Storage: VanityRegistry CommitDeposits (r:2 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:2 w:2)
+	// Storage: Balances Locks (r:2 w:2)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	fn accept_deposit_offer() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry DepositDelegations (r:1 w:1)
+	// Storage: VanityRegistry DepositOffers (r:0 w:1)
+	// Storage: VanityRegistry CommitDeposits (r:2 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:2 w:2)
+	// Storage: Balances Locks (r:2 w:2)
+	fn end_deposit_delegation() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	fn attest_ownership(c: u32) -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry JointOwners (r:1 w:1)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	fn set_joint_owners(m: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Storage: VanityRegistry JointOwners (r:1 w:1)
+	// Storage: VanityRegistry PendingActions (r:1 w:2)
 	// Storage: VanityRegistry Owners (r:1 w:1)
-	fn renew() -> Weight {
-		(9_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	// Storage: VanityRegistry LockPeriods (r:1 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry DepositBeneficiaries (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry NameHistories (r:0 w:1)
+	fn approve_action() -> Weight {
+		(104_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry NameShares (r:1 w:1)
+	// Storage: VanityRegistry JointOwners (r:1 w:0)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	fn fractionalize() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: VanityRegistry NameShares (r:1 w:1)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	fn transfer_shares() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry NameShares (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	fn distribute_revenue(h: u32) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((34_000_000 as Weight).saturating_mul(h as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(h as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(h as Weight)))
+	}
+	// Storage: VanityRegistry NameShares (r:1 w:0)
+	// Storage: VanityRegistry RenewalVotes (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry FallbackPrice (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry DepositDelegations (r:1 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: VanityRegistry CommitDeposits (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry RenewalCredits (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: VanityRegistry NameHistories (r:1 w:1)
+	// Storage: VanityRegistry RenewalFeeCeiling (r:1 w:0)
+	fn vote_renewal(h: u32) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((250_000 as Weight).saturating_mul(h as Weight))
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: VanityRegistry CommitDeposits (r:1 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn reconcile_lock() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: VanityRegistry Takedowns (r:1 w:1)
+	// Storage: VanityRegistry TakedownDeadlines (r:0 w:2)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn appeal_takedown() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: VanityRegistry Takedowns (r:1 w:1)
+	// Storage: VanityRegistry TakedownDeadlines (r:0 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry DepositDelegations (r:1 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: VanityRegistry CommitDeposits (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	fn resolve_appeal() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: VanityRegistry ExpiryCursor (r:1 w:1)
+	// Storage: VanityRegistry ExpiryQueue (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry LockPeriods (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry ScheduledTransfers (r:1 w:1)
+	// Storage: VanityRegistry DepositBeneficiaries (r:1 w:1)
+	// Storage: VanityRegistry CommitDeposits (r:1 w:0)
+	// Storage: VanityRegistry Watchers (r:1 w:1)
+	// Storage: VanityRegistry PriorityWindows (r:0 w:1)
+	// Storage: VanityRegistry PaymentOffers (r:0 w:1)
+	// Storage: VanityRegistry Payers (r:0 w:1)
+	// Storage: VanityRegistry NameStatuses (r:1 w:1)
+	// Storage: VanityRegistry NameHistories (r:1 w:1)
+	// Storage: VanityRegistry BatchReveals (r:1 w:0)
+	fn process_expiries(e: u32) -> Weight {
+		(6_000_000 as Weight)
+			.saturating_add((68_000_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((13 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((20 as Weight).saturating_mul(e as Weight)))
+	}
+	// Storage: VanityRegistry FallbackPrice (r:0 w:1)
+	fn set_fallback_price() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry DeniedNames (r:0 w:1)
+	fn set_name_denied() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:2 w:0)
+	// Storage: VanityRegistry SquattingReports (r:1 w:1)
+	// Storage: VanityRegistry LastReportAt (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry NameStatuses (r:1 w:1)
+	// Storage: VanityRegistry ScheduledTransfers (r:1 w:1)
+	fn report_squatting() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: VanityRegistry SquattingReports (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	// Storage: VanityRegistry Owners (r:1 w:1)
 	// Storage: VanityRegistry LockPeriods (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
-	fn unregister() -> Weight {
-		(62_900_000 as Weight)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Watchers (r:1 w:1)
+	// Storage: VanityRegistry PriorityWindows (r:0 w:1)
+	// Storage: VanityRegistry PaymentOffers (r:0 w:1)
+	// Storage: VanityRegistry Payers (r:0 w:1)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry NameHistories (r:0 w:1)
+	fn resolve_report() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry ScheduledActions (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	fn schedule_action() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry ScheduledActions (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	fn cancel_scheduled_action() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry RenewalPools (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn contribute_to_renewal() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry NameRoles (r:1 w:1)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	// Storage: VanityRegistry JointOwners (r:1 w:0)
+	fn set_role() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry NameRoles (r:1 w:1)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	// Storage: VanityRegistry JointOwners (r:1 w:0)
+	fn clear_role() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry Recoveries (r:0 w:1)
+	// Storage: VanityRegistry Guardians (r:0 w:1)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	// Storage: VanityRegistry JointOwners (r:1 w:0)
+	// Storage: VanityRegistry NameShares (r:1 w:0)
+	fn set_guardians(g: u32) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((160_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: VanityRegistry Guardians (r:1 w:0)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry Recoveries (r:1 w:1)
+	fn vote_recovery() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry Recoveries (r:1 w:1)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	fn veto_recovery() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: VanityRegistry Recoveries (r:1 w:1)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry Guardians (r:1 w:2)
+	// Storage: VanityRegistry OwnershipDeposits (r:2 w:2)
+	// Storage: VanityRegistry DepositBeneficiaries (r:1 w:1)
+	// Storage: VanityRegistry JointOwners (r:0 w:1)
+	// Storage: VanityRegistry NameShares (r:0 w:1)
+	// Storage: VanityRegistry NameRoles (r:0 w:1)
+	// Storage: Balances Locks (r:2 w:2)
+	// Storage: VanityRegistry CommitDeposits (r:2 w:0)
+	// Storage: VanityRegistry ExpiryQueue (r:0 w:1)
+	// Storage: VanityRegistry Heirs (r:0 w:1)
+	// Storage: VanityRegistry ScheduledTransfers (r:1 w:1)
+	// Storage: VanityRegistry Gifts (r:0 w:1)
+	// Storage: VanityRegistry PaymentOffers (r:0 w:1)
+	// Storage: VanityRegistry Payers (r:0 w:1)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry NameHistories (r:1 w:1)
+	// Storage: VanityRegistry JointOwners (r:1 w:0)
+	// Storage: VanityRegistry NameShares (r:1 w:0)
+	fn execute_recovery() -> Weight {
+		(86_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(20 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:0 w:1)
+	// Storage: VanityRegistry JointOwners (r:1 w:0)
+	// Storage: VanityRegistry NameShares (r:1 w:0)
+	fn set_heir() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	fn remove_heir() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry Guardians (r:0 w:1)
+	// Storage: VanityRegistry Recoveries (r:0 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:2 w:2)
+	// Storage: VanityRegistry DepositBeneficiaries (r:1 w:1)
+	// Storage: VanityRegistry JointOwners (r:0 w:1)
+	// Storage: VanityRegistry NameShares (r:0 w:1)
+	// Storage: VanityRegistry NameRoles (r:0 w:1)
+	// Storage: Balances Locks (r:2 w:2)
+	// Storage: VanityRegistry CommitDeposits (r:2 w:0)
+	// Storage: VanityRegistry ExpiryQueue (r:0 w:1)
+	// Storage: VanityRegistry ScheduledTransfers (r:1 w:1)
+	// Storage: VanityRegistry Gifts (r:0 w:1)
+	// Storage: VanityRegistry PaymentOffers (r:0 w:1)
+	// Storage: VanityRegistry Payers (r:0 w:1)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry NameHistories (r:1 w:1)
+	// Storage: VanityRegistry JointOwners (r:1 w:0)
+	// Storage: VanityRegistry NameShares (r:1 w:0)
+	fn claim_inheritance() -> Weight {
+		(84_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(19 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	// Storage: VanityRegistry SquattingReports (r:1 w:0)
+	// Storage: VanityRegistry JointOwners (r:1 w:0)
+	// Storage: VanityRegistry NameShares (r:1 w:0)
+	// Storage: VanityRegistry ScheduledTransfers (r:1 w:1)
+	// Storage: VanityRegistry ExpiryCursor (r:1 w:0)
+	// Storage: VanityRegistry ExpiryQueue (r:1 w:1)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	fn schedule_transfer() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	// Storage: VanityRegistry ScheduledTransfers (r:1 w:1)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	fn cancel_transfer() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: VanityRegistry LockPeriods (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry FallbackPrice (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Takedowns (r:1 w:0)
+	// Storage: VanityRegistry RevealCount (r:1 w:1)
+	// Storage: VanityRegistry DeniedNames (r:1 w:0)
+	// Storage: VanityRegistry VoucherReservations (r:1 w:0)
+	// Storage: VanityRegistry PriorityWindows (r:1 w:0)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry BatchReveals (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	fn reveal_bundle(n: u32) -> Weight {
+		(8_000_000 as Weight)
+			.saturating_add((27_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((14 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((9 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: VanityRegistry CommitDeposits (r:1 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:0)
+	// Storage: VanityRegistry ReservedDeposits (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: VanityRegistry LockBackedAccounts (r:1 w:1)
+	// Storage: VanityRegistry ActiveBackend (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn set_lock_backing() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: VanityRegistry Vouchers (r:1 w:1)
+	// Storage: VanityRegistry VoucherReservations (r:1 w:1)
+	fn mint_voucher() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: VanityRegistry Vouchers (r:1 w:1)
+	// Storage: VanityRegistry VoucherReservations (r:0 w:1)
+	fn revoke_voucher() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: VanityRegistry Vouchers (r:1 w:1)
+	// Storage: VanityRegistry VoucherReservations (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Takedowns (r:1 w:0)
+	// Storage: VanityRegistry RevealCount (r:1 w:1)
+	// Storage: VanityRegistry DeniedNames (r:1 w:0)
+	// Storage: VanityRegistry PriorityWindows (r:1 w:0)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	fn redeem_voucher(_l: usize) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: VanityRegistry LockPeriods (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:2)
+	// Storage: VanityRegistry FallbackPrice (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:2 w:4)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Takedowns (r:1 w:0)
+	// Storage: VanityRegistry RevealCount (r:1 w:1)
+	// Storage: VanityRegistry DeniedNames (r:1 w:0)
+	// Storage: VanityRegistry VoucherReservations (r:1 w:0)
+	// Storage: VanityRegistry DepositDelegations (r:0 w:1)
+	// Storage: VanityRegistry Gifts (r:0 w:2)
+	// Storage: VanityRegistry PriorityWindows (r:1 w:0)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry BatchReveals (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	fn reveal_gift(_l: usize) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry Gifts (r:1 w:1)
+	// Storage: VanityRegistry DepositOffers (r:0 w:1)
+	// Storage: VanityRegistry DepositDelegations (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:2 w:2)
+	// Storage: VanityRegistry DepositBeneficiaries (r:1 w:1)
+	// Storage: VanityRegistry JointOwners (r:0 w:1)
+	// Storage: VanityRegistry NameShares (r:0 w:1)
+	// Storage: VanityRegistry NameRoles (r:0 w:1)
+	// Storage: Balances Locks (r:2 w:2)
+	// Storage: VanityRegistry CommitDeposits (r:2 w:0)
+	// Storage: VanityRegistry ExpiryQueue (r:0 w:1)
+	// Storage: VanityRegistry NameHistories (r:1 w:1)
+	fn reject_gift() -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: VanityRegistry RenewalCredits (r:1 w:1)
+	fn buy_credits() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: VanityRegistry RenewalCredits (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	fn withdraw_credits() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: VanityRegistry LockPeriods (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry FallbackPrice (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Takedowns (r:1 w:0)
+	// Storage: VanityRegistry RevealCount (r:1 w:1)
+	// Storage: VanityRegistry DeniedNames (r:1 w:0)
+	// Storage: VanityRegistry VoucherReservations (r:1 w:0)
+	// Storage: VanityRegistry PriorityWindows (r:1 w:0)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry BatchReveals (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	fn reveal_for_periods(_l: usize) -> Weight {
+		(25_125_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: VanityRegistry Watchers (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn watch() -> Weight {
+		(31_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: VanityRegistry Watchers (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unwatch() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry PermanentNames (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:0 w:1)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	fn grant_permanent() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: VanityRegistry PermanentNames (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry DepositBeneficiaries (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry ScheduledActions (r:1 w:1)
+	// Storage: VanityRegistry NameRoles (r:0 w:1)
+	// Storage: VanityRegistry Guardians (r:0 w:1)
+	// Storage: VanityRegistry Recoveries (r:0 w:1)
+	// Storage: VanityRegistry Heirs (r:0 w:1)
+	// Storage: VanityRegistry ScheduledTransfers (r:0 w:1)
+	// Storage: VanityRegistry Gifts (r:0 w:1)
+	// Storage: VanityRegistry Watchers (r:1 w:1)
+	// Storage: VanityRegistry PaymentOffers (r:0 w:1)
+	// Storage: VanityRegistry Payers (r:0 w:1)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry NameHistories (r:0 w:1)
+	fn revoke_permanent() -> Weight {
+		(90_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(22 as Weight))
+	}
+	// Storage: VanityRegistry MigrationConsents (r:0 w:1)
+	fn consent_to_migration() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry MigrationConsents (r:1 w:1)
+	// Storage: VanityRegistry LockPeriods (r:1 w:0)
+	// Storage: VanityRegistry JointOwners (r:1 w:0)
+	// Storage: VanityRegistry NameShares (r:1 w:0)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry DepositDelegations (r:1 w:0)
+	// Storage: VanityRegistry ScheduledActions (r:1 w:1)
+	// Storage: VanityRegistry PendingRekeys (r:0 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:2 w:2)
+	// Storage: VanityRegistry CommitDeposits (r:2 w:0)
+	// Storage: Balances Locks (r:2 w:2)
+	// Storage: VanityRegistry RenewalCredits (r:2 w:2)
+	// Storage: Balances Reserves (r:2 w:2)
+	fn migrate_account(n: u32) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((31_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry PaymentOffers (r:0 w:1)
+	fn offer_payment() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	// Storage: VanityRegistry PaymentOffers (r:1 w:1)
+	// Storage: VanityRegistry Payers (r:1 w:1)
+	fn accept_payment_offer() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry Payers (r:1 w:1)
+	fn end_payment() -> Weight {
+		(23_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry NameStatuses (r:1 w:1)
+	// Storage: VanityRegistry ScheduledTransfers (r:1 w:1)
+	fn freeze_name() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: VanityRegistry NameStatuses (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: VanityRegistry DepositDelegations (r:1 w:0)
+	// Storage: VanityRegistry ExpiryCursor (r:1 w:0)
+	// Storage: VanityRegistry ExpiryQueue (r:0 w:1)
+	fn unfreeze_name() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: VanityRegistry LockPeriods (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry FallbackPrice (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Takedowns (r:1 w:0)
+	// Storage: VanityRegistry RevealCount (r:1 w:1)
+	// Storage: VanityRegistry DeniedNames (r:1 w:0)
+	// Storage: VanityRegistry VoucherReservations (r:1 w:0)
+	// Storage: VanityRegistry PriorityWindows (r:1 w:0)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry BatchReveals (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	fn reveal_salted(_l: usize, s: u32) -> Weight {
+		(25_450_000 as Weight)
+			.saturating_add((1_650_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: VanityRegistry LockPeriods (r:1 w:0)
+	// Storage: VanityRegistry BatchReveals (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry FallbackPrice (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Takedowns (r:1 w:0)
+	// Storage: VanityRegistry RevealCount (r:1 w:1)
+	// Storage: VanityRegistry DeniedNames (r:1 w:0)
+	// Storage: VanityRegistry VoucherReservations (r:1 w:0)
+	// Storage: VanityRegistry PriorityWindows (r:1 w:0)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	fn reveal_batched(_l: usize, p: u32) -> Weight {
+		(27_100_000 as Weight)
+			.saturating_add((580_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: VanityRegistry LockPeriods (r:1 w:1)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry FallbackPrice (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VanityRegistry OwnershipDeposits (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: VanityRegistry NameHashIndex (r:0 w:1)
+	// Storage: VanityRegistry NameCasings (r:0 w:1)
+	// Storage: VanityRegistry NameBuckets (r:1 w:1)
+	// Storage: VanityRegistry Takedowns (r:1 w:0)
+	// Storage: VanityRegistry RevealCount (r:1 w:1)
+	// Storage: VanityRegistry DeniedNames (r:1 w:0)
+	// Storage: VanityRegistry VoucherReservations (r:1 w:0)
+	// Storage: VanityRegistry PriorityWindows (r:1 w:0)
+	// Storage: VanityRegistry NameStatuses (r:0 w:1)
+	// Storage: VanityRegistry BatchReveals (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	fn reveal_with_proof(_l: usize) -> Weight {
+		(25_800_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: VanityRegistry RenewalFeeCeiling (r:0 w:1)
+	fn set_renewal_fee_ceiling() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: VanityRegistry Owners (r:1 w:0)
+	// Storage: VanityRegistry Heirs (r:1 w:1)
+	// Storage: VanityRegistry NameStatuses (r:1 w:0)
+	// Storage: VanityRegistry JointOwners (r:1 w:0)
+	// Storage: VanityRegistry NameShares (r:1 w:0)
+	// Storage: VanityRegistry PendingRekeys (r:0 w:1)
+	fn rekey_owner() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Storage: VanityRegistry PendingRekeys (r:1 w:1)
+	// Storage: VanityRegistry NameStatuses (r:1 w:0)
+	// Storage: VanityRegistry Owners (r:1 w:1)
+	// Storage: VanityRegistry DepositDelegations (r:1 w:0)
+	// Storage: VanityRegistry ScheduledActions (r:1 w:2)
+	// Storage: Scheduler Lookup (r:2 w:2)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: VanityRegistry OwnershipDeposits (r:2 w:2)
+	// Storage: VanityRegistry CommitDeposits (r:2 w:0)
+	// Storage: Balances Locks (r:2 w:2)
+	// Storage: Balances Reserves (r:2 w:2)
+	fn accept_rekey() -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
 }
 
 impl WeightInfo for () {
 	fn commit() -> Weight {
 		(57_300_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	fn reveal(_l: usize) -> Weight {
 		(24_625_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn register(_l: usize) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn renew() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn unregister() -> Weight {
+		(92_900_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(22 as Weight))
+	}
+	fn force_unregister() -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(24 as Weight))
+	}
+	fn set_deposit_beneficiary() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn offer_deposit() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn accept_deposit_offer() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn end_deposit_delegation() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn attest_ownership(c: u32) -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_joint_owners(m: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
-	fn renew() -> Weight {
-		(9_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	fn approve_action() -> Weight {
+		(104_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
+	}
+	fn fractionalize() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn transfer_shares() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
-	fn unregister() -> Weight {
-		(62_900_000 as Weight)
+	fn distribute_revenue(h: u32) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((34_000_000 as Weight).saturating_mul(h as Weight))
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(h as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(h as Weight)))
+	}
+	fn vote_renewal(h: u32) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((250_000 as Weight).saturating_mul(h as Weight))
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn reconcile_lock() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn appeal_takedown() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn resolve_appeal() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn process_expiries(e: u32) -> Weight {
+		(6_000_000 as Weight)
+			.saturating_add((68_000_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((13 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((20 as Weight).saturating_mul(e as Weight)))
+	}
+	fn set_fallback_price() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_name_denied() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn report_squatting() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn resolve_report() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	fn schedule_action() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn cancel_scheduled_action() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn contribute_to_renewal() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn set_role() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn clear_role() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_guardians(g: u32) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((160_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn vote_recovery() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn veto_recovery() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn execute_recovery() -> Weight {
+		(86_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(20 as Weight))
+	}
+	fn set_heir() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_heir() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn claim_inheritance() -> Weight {
+		(84_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(19 as Weight))
+	}
+	fn schedule_transfer() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn cancel_transfer() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn reveal_bundle(n: u32) -> Weight {
+		(8_000_000 as Weight)
+			.saturating_add((27_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((14 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((9 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_lock_backing() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn mint_voucher() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn revoke_voucher() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn redeem_voucher(_l: usize) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn reveal_gift(_l: usize) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+	}
+	fn reject_gift() -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
+	}
+	fn buy_credits() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	fn withdraw_credits() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn reveal_for_periods(_l: usize) -> Weight {
+		(25_125_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn watch() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn unwatch() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn grant_permanent() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn revoke_permanent() -> Weight {
+		(90_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(22 as Weight))
+	}
+	fn consent_to_migration() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn migrate_account(n: u32) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((31_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	fn offer_payment() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn accept_payment_offer() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn end_payment() -> Weight {
+		(23_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn freeze_name() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn unfreeze_name() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn reveal_salted(_l: usize, s: u32) -> Weight {
+		(25_450_000 as Weight)
+			.saturating_add((1_650_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn reveal_batched(_l: usize, p: u32) -> Weight {
+		(27_100_000 as Weight)
+			.saturating_add((580_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn reveal_with_proof(_l: usize) -> Weight {
+		(25_800_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn set_renewal_fee_ceiling() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn rekey_owner() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn accept_rekey() -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
+	}
 }
@@ -6,6 +6,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use codec::{Decode, Encode, MaxEncodedLen};
 use pallet_grandpa::{
 	fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
 };
@@ -16,7 +17,7 @@ use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	ApplyExtrinsicResult, MultiSignature, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -26,12 +27,15 @@ use sp_version::RuntimeVersion;
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{KeyOwnerProofSystem, LockIdentifier, Randomness, StorageInfo},
+	traits::{
+		ConstU32, EqualPrivilegeOnly, InstanceFilter, KeyOwnerProofSystem, LockIdentifier,
+		Randomness, StorageInfo,
+	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	BoundedVec, PalletId, StorageValue,
 };
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
@@ -43,6 +47,12 @@ pub use sp_runtime::{Perbill, Permill};
 /// Import the template pallet.
 pub use vanity_registry;
 
+mod registry_profile;
+use registry_profile::{
+	MaxBundleLen, MaxExpiriesPerBlock, MaxGuardians, MaxJointOwners, MaxNamesPerPrefix,
+	MaxPoolContributors, MaxRevealsPerBlock, MaxRoles, MaxShareholders, SquattingScanInterval,
+};
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -56,6 +66,12 @@ pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::Account
 /// Balance of an account.
 pub type Balance = u128;
 
+/// The longest name the vanity registry accepts, in bytes.
+pub const MAX_NAME_LEN: u32 = 64;
+
+/// A name in the vanity registry.
+pub type RegistryName = vanity_registry::VanityName<ConstU32<MAX_NAME_LEN>>;
+
 /// Index of a transaction in the chain.
 pub type Index = u32;
 
@@ -96,12 +112,12 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
-	//   the compatible custom types.
-	spec_version: 100,
+	// It must be bumped with every upgrade for `Executive` to run the storage migrations.
+	spec_version: 149,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Bumped as the calls of the vanity registry pallet have been reordered.
+	transaction_version: 5,
 };
 
 /// This determines the average expected block time that we are targeting.
@@ -121,6 +137,10 @@ pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
 pub const HOURS: BlockNumber = MINUTES * 60;
 pub const DAYS: BlockNumber = HOURS * 24;
 
+// Currency is measured in units of 10^12 of the smallest denomination.
+pub const UNITS: Balance = 1_000_000_000_000;
+pub const MILLIUNITS: Balance = UNITS / 1_000;
+
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> NativeVersion {
@@ -182,7 +202,7 @@ impl frame_system::Config for Runtime {
 	/// What to do if a new account is created.
 	type OnNewAccount = ();
 	/// What to do if an account is fully reaped from the system.
-	type OnKilledAccount = ();
+	type OnKilledAccount = VanityRegistry;
 	/// The data to be stored in an account.
 	type AccountData = pallet_balances::AccountData<Balance>;
 	/// Weight information for the extrinsics of this pallet.
@@ -240,11 +260,12 @@ impl pallet_timestamp::Config for Runtime {
 parameter_types! {
 	pub const ExistentialDeposit: u128 = 500;
 	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
 }
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = MaxLocks;
-	type MaxReserves = ();
+	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
@@ -272,21 +293,294 @@ impl pallet_sudo::Config for Runtime {
 	type Call = Call;
 }
 
+parameter_types! {
+	pub const ProxyDepositBase: Balance = UNITS;
+	pub const ProxyDepositFactor: Balance = 100 * MILLIUNITS;
+	pub const MaxProxies: u32 = 32;
+	pub const MaxPending: u32 = 32;
+	pub const AnnouncementDepositBase: Balance = UNITS;
+	pub const AnnouncementDepositFactor: Balance = 200 * MILLIUNITS;
+}
+
+/// The kinds of calls an account may delegate to a proxy.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+)]
+pub enum ProxyType {
+	/// Every call.
+	Any,
+	/// Keeping names registered, managing their records and proving their ownership, e.g. from a
+	/// hot key. Names may be committed to and revealed through every reveal path, so no commit
+	/// deposit is stuck behind a reveal the proxy may not make. Calls which give away a name, its
+	/// deposit or its revenue are not permitted.
+	NameManagement,
+}
+
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+impl InstanceFilter<Call> for ProxyType {
+	fn filter(&self, c: &Call) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::NameManagement => matches!(
+				c,
+				Call::VanityRegistry(vanity_registry::Call::commit { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::reveal { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::reveal_bundle { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::reveal_for_periods { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::reveal_salted { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::reveal_batched { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::reveal_with_proof { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::renew { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::vote_renewal { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::attest_ownership { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::reconcile_lock { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::set_role { .. }) |
+					Call::VanityRegistry(vanity_registry::Call::clear_role { .. })
+			),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(ProxyType::Any, _) => true,
+			(x, y) => x == y,
+		}
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = MaxProxies;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = MaxPending;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+}
+
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: Balance = UNITS;
+	pub const SpendPeriod: BlockNumber = 7 * DAYS;
+	pub const Burn: Permill = Permill::zero();
+	pub const MaxApprovals: u32 = 100;
+}
+
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = frame_system::EnsureRoot<AccountId>;
+	type RejectOrigin = frame_system::EnsureRoot<AccountId>;
+	type Event = Event;
+	type OnSlash = Treasury;
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type MaxApprovals = MaxApprovals;
+}
+
 parameter_types! {
 	pub const VanityRegistryId: LockIdentifier = *b"registry";
-	pub const RegisterPeriod: BlockNumber = 7 * DAYS;
-	pub const FundToLock: Balance = 113;
-	pub const NameMaxLen: u32 = 255;
+	pub const RegisterPeriod: BlockNumber = 365 * DAYS;
+	pub const GracePeriod: BlockNumber = 30 * DAYS;
+	pub const FundToLock: Balance = 100 * MILLIUNITS;
+	pub const OwnershipDeposit: Balance = 10 * UNITS;
+	pub const NameByteDeposit: Balance = 10 * MILLIUNITS;
+	pub const ForcedRemovalSlash: Perbill = Perbill::from_percent(20);
+	pub const DelegatorFeeShare: Perbill = Perbill::from_percent(10);
+	pub const ApprovalPeriod: BlockNumber = 3 * DAYS;
+	pub const NameMaxLen: u32 = MAX_NAME_LEN;
+	// There is no identity pallet to vouch for registrants yet, so no name counts as short.
+	pub const ShortNameLen: u32 = 0;
+	// Squatters front-run short, memorable names rather than names this long.
+	pub const InstantRegistrationLen: u32 = 24;
+	pub const MaxChallengeLen: u32 = 64;
+	pub const MaxPrefixLen: u32 = 8;
+	pub const MaxRoleLen: u32 = 32;
+	pub const MaxVoucherCodeLen: u32 = 32;
+	pub const MaxCommitSalts: u32 = 8;
+	pub const MaxProofLen: u32 = 16;
+	// The default reveal verifier ignores proofs, so none are accepted.
+	pub const MaxRevealProofLen: u32 = 0;
+	pub const RecoveryDelay: BlockNumber = 7 * DAYS;
+	pub const MinInactivityPeriod: BlockNumber = 90 * DAYS;
+	pub const GiftRejectionPeriod: BlockNumber = 7 * DAYS;
+	pub const WatchDeposit: Balance = UNITS;
+	pub const MaxWatchers: u32 = 16;
+	pub const WatchPriorityPeriod: BlockNumber = HOURS;
+	pub const RegistryDepositBackend: vanity_registry::DepositBackend =
+		vanity_registry::DepositBackend::Lock;
+	/// Fees are defined in USD cents and converted at the price reported by the price feed.
+	pub const RegistrationFee: u32 = 500;
+	pub const RenewalFee: u32 = 300;
+	/// Names held for a year renew at a 10% discount, for three years at 25%.
+	pub RenewalDiscounts: Vec<(BlockNumber, Perbill)> =
+		vec![(365 * DAYS, Perbill::from_percent(10)), (3 * 365 * DAYS, Perbill::from_percent(25))];
+	pub const MaxRegistrationPeriods: u32 = 5;
+	/// Three periods registered at once cost as much as two and a half, five as much as four.
+	pub MultiPeriodDiscounts: Vec<(u32, Perbill)> =
+		vec![(3, Perbill::from_rational(1u32, 6u32)), (5, Perbill::from_percent(20))];
+	pub const ReportBond: Balance = 5 * UNITS;
+	pub const AppealBond: Balance = 20 * UNITS;
+	pub const AppealWindow: BlockNumber = 7 * DAYS;
+	pub const ReportCooldown: BlockNumber = HOURS;
 }
 impl vanity_registry::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type ModuleId = VanityRegistryId;
+	type LockReasons = vanity_registry::DefaultLockReasons;
+	type DepositBackend = RegistryDepositBackend;
+	// No pallet of this runtime locks balances, e.g. staking or vesting.
+	type LockedBalances = ();
 	type RegisterPeriod = RegisterPeriod;
+	type GracePeriod = GracePeriod;
 	type FundToLock = FundToLock;
-	type Name = Vec<u8>;
+	type OwnershipDeposit = OwnershipDeposit;
+	type NameByteDeposit = NameByteDeposit;
+	type ForcedRemovalSlash = ForcedRemovalSlash;
+	type Name = RegistryName;
 	type NameMaxLen = NameMaxLen;
-	type WeightInfo = ();
+	type MaxRevealsPerBlock = MaxRevealsPerBlock;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type ShortNameLen = ShortNameLen;
+	type InstantRegistrationLen = InstantRegistrationLen;
+	type IdentityVerifier = ();
+	type NamePolicy = vanity_registry::DenyList<Runtime>;
+	type CommitHashing = BlakeTwo256;
+	type Randomness = RandomnessCollectiveFlip;
+	type RevealVerifier = ();
+	type MaxChallengeLen = MaxChallengeLen;
+	type MaxPrefixLen = MaxPrefixLen;
+	type MaxNamesPerPrefix = MaxNamesPerPrefix;
+	// No oracle is wired yet, so fees are converted at the fallback price set through sudo.
+	type PriceFeed = ();
+	type RegistrationFee = RegistrationFee;
+	type RenewalFee = RenewalFee;
+	type TenureDiscount = vanity_registry::TenureMilestones<RenewalDiscounts>;
+	type MaxRegistrationPeriods = MaxRegistrationPeriods;
+	type MultiPeriodDiscounts = MultiPeriodDiscounts;
+	// Fees and slashes, e.g. of forced removals and rejected reports, fund the treasury.
+	type FeeHandler = Treasury;
+	type DelegatorFeeShare = DelegatorFeeShare;
+	type MaxJointOwners = MaxJointOwners;
+	type ApprovalPeriod = ApprovalPeriod;
+	type MaxShareholders = MaxShareholders;
+	type MaxPoolContributors = MaxPoolContributors;
+	type MaxRoles = MaxRoles;
+	type MaxRoleLen = MaxRoleLen;
+	type MaxGuardians = MaxGuardians;
+	type RecoveryDelay = RecoveryDelay;
+	type MinInactivityPeriod = MinInactivityPeriod;
+	type MaxBundleLen = MaxBundleLen;
+	type MaxCommitSalts = MaxCommitSalts;
+	type MaxProofLen = MaxProofLen;
+	type MaxRevealProofLen = MaxRevealProofLen;
+	type MaxVoucherCodeLen = MaxVoucherCodeLen;
+	type GiftRejectionPeriod = GiftRejectionPeriod;
+	type WatchDeposit = WatchDeposit;
+	type MaxWatchers = MaxWatchers;
+	type WatchPriorityPeriod = WatchPriorityPeriod;
+	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+	type RecordOrigin = vanity_registry::SignedOrigin;
+	type TransferOrigin = vanity_registry::SignedOrigin;
+	type UnregisterOrigin = vanity_registry::SignedOrigin;
+	type AuthorityId = vanity_registry::crypto::ReporterAuthId;
+	type ReportBond = ReportBond;
+	type AppealBond = AppealBond;
+	type AppealWindow = AppealWindow;
+	type ReportCooldown = ReportCooldown;
+	type SquattingScanInterval = SquattingScanInterval;
+	// Owners schedule renewals and releases of their names through the scheduler.
+	type RegistryCall = Call;
+	type PalletsOrigin = OriginCaller;
+	type Scheduler = Scheduler;
+	type WeightInfo = vanity_registry::weights::SubstrateWeight<Runtime>;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	Call: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: Call,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Index,
+	) -> Option<(Call, <UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload)> {
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let extra: SignedExtra = (
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+			vanity_registry::CheckRegistryCall::<Runtime>::new(),
+		);
+		let raw_payload = SignedPayload::new(call, extra).ok()?;
+		let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+		let (call, extra, _) = raw_payload.deconstruct();
+		Some((call, (sp_runtime::MultiAddress::Id(account), signature, extra)))
+	}
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	Call: From<C>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = UncheckedExtrinsic;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -306,6 +600,9 @@ construct_runtime!(
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>},
 		// Include the custom logic from the pallet-template in the runtime.
 		VanityRegistry: vanity_registry::{Pallet, Call, Storage, Event<T>},
+		Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>},
+		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
+		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -324,9 +621,12 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	vanity_registry::CheckRegistryCall<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
+/// The payload being signed in transactions.
+pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -334,6 +634,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPallets,
+	vanity_registry::migrations::Migrate<Runtime>,
 >;
 
 impl_runtime_apis! {
@@ -467,6 +768,95 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl vanity_registry_runtime_api::VanityRegistryApi<
+		Block,
+		AccountId,
+		RegistryName,
+		Hash,
+		BlockNumber,
+		Balance,
+	> for Runtime {
+		fn list_names(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> vanity_registry::Page<(RegistryName, AccountId)> {
+			VanityRegistry::list_names(start_key, limit)
+		}
+
+		fn list_names_of(
+			account: AccountId,
+			start: Option<Vec<u8>>,
+			limit: u32,
+		) -> vanity_registry::Page<RegistryName> {
+			VanityRegistry::list_names_of(&account, start, limit)
+		}
+
+		fn names_of(account: AccountId) -> Vec<RegistryName> {
+			VanityRegistry::names_of(&account)
+		}
+
+		fn list_commits(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> vanity_registry::Page<(AccountId, Hash, vanity_registry::LockPeriod<BlockNumber>)> {
+			VanityRegistry::list_commits(start_key, limit)
+		}
+
+		fn owner_of(name: RegistryName) -> Option<AccountId> {
+			VanityRegistry::owner_of(&name)
+		}
+
+		fn owner_of_hash(hash: Hash) -> Option<AccountId> {
+			VanityRegistry::owner_of_hash(hash)
+		}
+
+		fn resolve(name: RegistryName) -> Option<vanity_registry::Resolution<AccountId>> {
+			VanityRegistry::resolve(&name)
+		}
+
+		fn registration_details(
+			name: RegistryName,
+		) -> Option<
+			vanity_registry::RegistrationDetails<AccountId, RegistryName, BlockNumber, Balance>,
+		> {
+			VanityRegistry::registration_details(&name)
+		}
+
+		fn registration(
+			name: RegistryName,
+		) -> Option<
+			vanity_registry::RegistrationInfo<AccountId, RegistryName, BlockNumber, Balance>,
+		> {
+			VanityRegistry::registration(&name)
+		}
+
+		fn ens_metadata(
+			name: RegistryName,
+		) -> Option<vanity_registry::EnsMetadata<AccountId, RegistryName, BlockNumber>> {
+			VanityRegistry::ens_metadata(&name)
+		}
+
+		fn is_available(name: RegistryName) -> bool {
+			VanityRegistry::is_available(&name)
+		}
+
+		fn quote() -> Option<vanity_registry::Quote<Balance>> {
+			VanityRegistry::quote()
+		}
+
+		fn quote_register(name: RegistryName, periods: u32) -> Option<Balance> {
+			VanityRegistry::registration_quote(&name, periods)
+		}
+
+		fn quote_renew(name: RegistryName, periods: u32) -> Option<Balance> {
+			VanityRegistry::renewal_quote(&name, periods)
+		}
+
+		fn names_with_prefix(prefix: Vec<u8>, limit: u32) -> Vec<RegistryName> {
+			VanityRegistry::names_with_prefix(prefix, limit)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -483,6 +873,7 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_balances, Balances);
 			list_benchmark!(list, extra, pallet_timestamp, Timestamp);
 			list_benchmark!(list, extra, vanity_registry, VanityRegistry);
+			list_benchmark!(list, extra, pallet_treasury, Treasury);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -517,9 +908,130 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
 			add_benchmark!(params, batches, vanity_registry, VanityRegistry);
+			add_benchmark!(params, batches, pallet_treasury, Treasury);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::{
+		assert_ok,
+		traits::{Get, OnInitialize},
+	};
+	use sp_std::convert::TryFrom;
+
+	fn alice() -> AccountId {
+		AccountId::from([1u8; 32])
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut storage =
+			frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+		pallet_balances::GenesisConfig::<Runtime> { balances: vec![(alice(), 1_000 * UNITS)] }
+			.assimilate_storage(&mut storage)
+			.unwrap();
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(VanityRegistry::set_fallback_price(Origin::root(), Some(MILLIUNITS)));
+		});
+		ext
+	}
+
+	fn register(who: AccountId, name: &[u8]) -> RegistryName {
+		let name = RegistryName::try_from(name.to_vec()).unwrap();
+		let commit = VanityRegistry::hash_of(who.clone(), name.clone());
+		assert_ok!(VanityRegistry::commit(Origin::signed(who.clone()), commit));
+		assert_ok!(VanityRegistry::reveal(Origin::signed(who), name.clone()));
+		name
+	}
+
+	fn treasury_balance() -> Balance {
+		Balances::free_balance(&Treasury::account_id())
+	}
+
+	#[test]
+	fn name_management_proxies_may_only_keep_names() {
+		let name = RegistryName::try_from(b"alice".to_vec()).unwrap();
+		let renew = Call::VanityRegistry(vanity_registry::Call::renew { name: name.clone() });
+		let reveal = Call::VanityRegistry(vanity_registry::Call::reveal_for_periods {
+			name: name.clone(),
+			periods: 2,
+		});
+		let reveal_bundle = Call::VanityRegistry(vanity_registry::Call::reveal_bundle {
+			names: Default::default(),
+		});
+		let set_role = Call::VanityRegistry(vanity_registry::Call::set_role {
+			name: name.clone(),
+			role: Default::default(),
+			account: alice(),
+		});
+		let clear_role = Call::VanityRegistry(vanity_registry::Call::clear_role {
+			name: name.clone(),
+			role: Default::default(),
+		});
+		let unregister = Call::VanityRegistry(vanity_registry::Call::unregister { name });
+		let transfer = Call::Balances(BalancesCall::transfer { dest: alice().into(), value: 1 });
+
+		assert!(ProxyType::NameManagement.filter(&renew));
+		assert!(ProxyType::NameManagement.filter(&reveal));
+		assert!(ProxyType::NameManagement.filter(&reveal_bundle));
+		assert!(ProxyType::NameManagement.filter(&set_role));
+		assert!(ProxyType::NameManagement.filter(&clear_role));
+		assert!(!ProxyType::NameManagement.filter(&unregister));
+		assert!(!ProxyType::NameManagement.filter(&transfer));
+		assert!(ProxyType::Any.filter(&unregister));
+		assert!(ProxyType::Any.is_superset(&ProxyType::NameManagement));
+		assert!(!ProxyType::NameManagement.is_superset(&ProxyType::Any));
+	}
+
+	#[test]
+	fn registry_fees_fund_the_treasury() {
+		new_test_ext().execute_with(|| {
+			let registration_fee = RegistrationFee::get() as Balance * MILLIUNITS;
+			let renewal_fee = RenewalFee::get() as Balance * MILLIUNITS;
+
+			let name = register(alice(), b"alice");
+			assert_eq!(treasury_balance(), registration_fee);
+
+			assert_ok!(VanityRegistry::renew(Origin::signed(alice()), name));
+			assert_eq!(treasury_balance(), registration_fee + renewal_fee);
+		});
+	}
+
+	#[test]
+	fn registry_slashes_fund_the_treasury() {
+		new_test_ext().execute_with(|| {
+			let registration_fee = RegistrationFee::get() as Balance * MILLIUNITS;
+			let name = register(alice(), b"alice");
+
+			assert_ok!(VanityRegistry::force_unregister(Origin::root(), name));
+			let deposit = OwnershipDeposit::get() + NameByteDeposit::get() * 6;
+			let slash = ForcedRemovalSlash::get() * deposit;
+			assert_eq!(treasury_balance(), registration_fee + slash);
+		});
+	}
+
+	#[test]
+	fn scheduled_releases_are_dispatched_by_the_scheduler() {
+		new_test_ext().execute_with(|| {
+			let name = register(alice(), b"alice");
+			assert_ok!(VanityRegistry::schedule_action(
+				Origin::signed(alice()),
+				name.clone(),
+				vanity_registry::ScheduledAction::Unregister,
+				5
+			));
+
+			Scheduler::on_initialize(4);
+			assert_eq!(VanityRegistry::owner_of(&name), Some(alice()));
+			Scheduler::on_initialize(5);
+			assert_eq!(VanityRegistry::owner_of(&name), None);
+		});
+	}
+}
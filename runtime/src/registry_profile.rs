@@ -0,0 +1,63 @@
+//! The limits of the vanity registry that depend on where the runtime is deployed.
+//!
+//! By default the runtime is a solo chain, whose blocks are only bounded by their weight. With the
+//! `parachain` feature the limits also fit the proof of validity (PoV): every storage item a block
+//! reads or writes is sent to the relay chain validators to re-execute the block, so the work the
+//! registry may do in a single block is kept smaller.
+//!
+//! What else to keep in mind when running the registry on a cumulus parachain:
+//! - All hooks are bounded: expiries by `MaxExpiriesPerBlock`, reveals by `MaxRevealsPerBlock`, and
+//!   takedowns by the calls of the `AdminOrigin`. The weights of this tree are not PoV-aware, so
+//!   the limits are the only guard against blocks with an oversized proof.
+//! - The `prefix-index` feature writes up to `MaxPrefixLen` entries per registered name. Keep
+//!   `MaxPrefixLen` small, or leave the feature off.
+//! - The off-chain worker runs on the collators, which also sign the squatting reports. Scanning
+//!   less often keeps the reports out of most blocks.
+//! - `AdminOrigin` and `PriceFeed` may come from another consensus system over XCM, e.g.
+//!   `pallet_xcm::EnsureXcm` for a governance body of the relay chain, or an oracle on another
+//!   parachain queried over XCM. The runtime does not depend on `pallet-xcm` or cumulus yet, so it
+//!   keeps `EnsureRoot` and the fallback price until it does.
+
+#[cfg(not(feature = "parachain"))]
+mod profile {
+	use crate::{BlockNumber, MINUTES};
+	use frame_support::parameter_types;
+
+	parameter_types! {
+		pub const MaxRevealsPerBlock: u32 = 200;
+		pub const MaxBundleLen: u32 = 20;
+		pub const MaxExpiriesPerBlock: u32 = 100;
+		pub const MaxJointOwners: u32 = 16;
+		pub const MaxShareholders: u32 = 32;
+		pub const MaxPoolContributors: u32 = 32;
+		pub const MaxRoles: u32 = 16;
+		pub const MaxGuardians: u32 = 16;
+		pub const MaxNamesPerPrefix: u32 = 20;
+		pub const SquattingScanInterval: BlockNumber = 10 * MINUTES;
+	}
+}
+
+#[cfg(feature = "parachain")]
+mod profile {
+	use crate::{BlockNumber, HOURS};
+	use frame_support::parameter_types;
+
+	parameter_types! {
+		// Each reveal and expiry touches about ten storage items of the registry and balances.
+		pub const MaxRevealsPerBlock: u32 = 50;
+		pub const MaxBundleLen: u32 = 5;
+		pub const MaxExpiriesPerBlock: u32 = 25;
+		// Joint owners and shareholders are stored in a single item each, read whole.
+		pub const MaxJointOwners: u32 = 8;
+		pub const MaxShareholders: u32 = 16;
+		// Drawing a renewal fee from a pool touches the account of each contributor.
+		pub const MaxPoolContributors: u32 = 8;
+		// The roles and the guardians of a name are stored in a single item as well.
+		pub const MaxRoles: u32 = 8;
+		pub const MaxGuardians: u32 = 8;
+		pub const MaxNamesPerPrefix: u32 = 10;
+		pub const SquattingScanInterval: BlockNumber = HOURS;
+	}
+}
+
+pub use profile::*;
@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+# This script is meant to be run on Unix/Linux based systems
+set -e
+
+echo "*** Regenerating the weights of the vanity registry pallet ***"
+
+cd $(dirname ${BASH_SOURCE[0]})/..
+
+cargo build --release --features runtime-benchmarks
+
+./target/release/node-template benchmark \
+	--chain dev \
+	--pallet vanity-registry \
+	--extrinsic '*' \
+	--steps 50 \
+	--repeat 20 \
+	--execution wasm \
+	--wasm-execution compiled \
+	--output pallets/vanity-registry/src/weights.rs